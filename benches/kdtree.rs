@@ -0,0 +1,71 @@
+//Compares the k-d tree against a linear scan over the same points.
+//Run with `cargo bench --bench kdtree`.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use gamevecs::Vec3;
+use gamevecs::spatial::KdTree3;
+
+const POINTS: usize = 200_000;
+const QUERIES: usize = 2_000;
+
+//deterministic pseudo random points inside of a 1000 unit cube
+fn random_points(count: usize, mut state: u32) -> Vec<Vec3> {
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as f32 / u32::MAX as f32 * 1000.0
+    };
+    (0..count).map(|_| Vec3::new(next(), next(), next())).collect()
+}
+
+fn linear_nearest(points: &[Vec3], query: Vec3) -> usize {
+    let mut best = 0;
+    for (i, point) in points.iter().enumerate() {
+        if point.distance_to_squared(query) < points[best].distance_to_squared(query) {
+            best = i;
+        }
+    }
+    best
+}
+
+fn measure(name: &str, mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    f();
+    let elapsed = start.elapsed();
+    println!("{:<28} {:>10.3} ms", name, elapsed.as_secs_f64() * 1000.0);
+    elapsed
+}
+
+fn main() {
+    let points = random_points(POINTS, 0x9E3779B9);
+    let queries = random_points(QUERIES, 0x85EBCA6B);
+
+    let mut tree = None;
+    measure("build", || tree = Some(KdTree3::new(black_box(&points))));
+    let tree = tree.unwrap();
+
+    let linear = measure("nearest (linear scan)", || {
+        for query in &queries {
+            black_box(linear_nearest(&points, *query));
+        }
+    });
+    let kdtree = measure("nearest (k-d tree)", || {
+        for query in &queries {
+            black_box(tree.nearest(*query));
+        }
+    });
+    measure("k_nearest 8 (k-d tree)", || {
+        for query in &queries {
+            black_box(tree.k_nearest(*query, 8));
+        }
+    });
+    measure("query_radius 20 (k-d tree)", || {
+        for query in &queries {
+            black_box(tree.query_radius(*query, 20.0).count());
+        }
+    });
+
+    println!("k-d tree nearest is {:.0}x faster than the linear scan", linear.as_secs_f64() / kdtree.as_secs_f64());
+}
@@ -0,0 +1,4 @@
+pub mod easing;
+mod tween;
+
+pub use self::tween::Tween;
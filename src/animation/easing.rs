@@ -0,0 +1,220 @@
+//!Easing functions after Robert Penner.
+//! All of them map 0.0 to 0.0 and 1.0 to 1.0. Back and elastic overshoot in between.
+//!
+//! # Examples
+//! ```rust
+//! use gamevecs::Vec2;
+//! use gamevecs::animation::easing;
+//! let start = Vec2::new(0.0, 0.0);
+//! let end = Vec2::new(10.0, 0.0);
+//!
+//! assert_eq!(Vec2::new(2.5, 0.0), start.lerp(end, easing::quad_in(0.5)));
+//! ```
+
+use std::f32::consts::PI;
+
+
+const BACK: f32 = 1.70158;
+const BACK_IN_OUT: f32 = BACK * 1.525;
+const ELASTIC: f32 = 2.0 * PI / 3.0;
+const ELASTIC_IN_OUT: f32 = 2.0 * PI / 4.5;
+const BOUNCE: f32 = 7.5625;
+const BOUNCE_WIDTH: f32 = 2.75;
+
+//turns an ease out into the matching ease in
+fn reverse(t: f32, ease_out: fn(f32) -> f32) -> f32 {
+    1.0 - ease_out(1.0 - t)
+}
+
+//combines an ease in for the first half with the matching ease out for the second half
+fn in_out(t: f32, ease_in: fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(t * 2.0) * 0.5
+    } else {
+        1.0 - ease_in(2.0 - t * 2.0) * 0.5
+    }
+}
+
+///No easing.
+pub fn linear(t: f32) -> f32 {
+    t
+}
+
+//----------Sine----------
+
+///Starts slow and speeds up along a quarter sine wave.
+pub fn sine_in(t: f32) -> f32 {
+    1.0 - (t * PI * 0.5).cos()
+}
+///Starts fast and slows down along a quarter sine wave.
+pub fn sine_out(t: f32) -> f32 {
+    (t * PI * 0.5).sin()
+}
+///Speeds up and slows down again along half a cosine wave.
+pub fn sine_in_out(t: f32) -> f32 {
+    (1.0 - (t * PI).cos()) * 0.5
+}
+
+//----------Quad----------
+
+///Accelerates from zero velocity, t squared.
+pub fn quad_in(t: f32) -> f32 {
+    t * t
+}
+///Decelerates to zero velocity, the mirror of quad_in.
+pub fn quad_out(t: f32) -> f32 {
+    reverse(t, quad_in)
+}
+///Accelerates until halfway, then decelerates quadratically.
+pub fn quad_in_out(t: f32) -> f32 {
+    in_out(t, quad_in)
+}
+
+//----------Cubic----------
+
+///Accelerates from zero velocity, t cubed.
+pub fn cubic_in(t: f32) -> f32 {
+    t * t * t
+}
+///Decelerates to zero velocity, the mirror of cubic_in.
+pub fn cubic_out(t: f32) -> f32 {
+    reverse(t, cubic_in)
+}
+///Accelerates until halfway, then decelerates cubically.
+pub fn cubic_in_out(t: f32) -> f32 {
+    in_out(t, cubic_in)
+}
+
+//----------Quart----------
+
+///Accelerates from zero velocity with the fourth power of t.
+pub fn quart_in(t: f32) -> f32 {
+    t.powi(4)
+}
+///Decelerates to zero velocity, the mirror of quart_in.
+pub fn quart_out(t: f32) -> f32 {
+    reverse(t, quart_in)
+}
+///Accelerates until halfway, then decelerates with the fourth power.
+pub fn quart_in_out(t: f32) -> f32 {
+    in_out(t, quart_in)
+}
+
+//----------Quint----------
+
+///Accelerates from zero velocity with the fifth power of t.
+pub fn quint_in(t: f32) -> f32 {
+    t.powi(5)
+}
+///Decelerates to zero velocity, the mirror of quint_in.
+pub fn quint_out(t: f32) -> f32 {
+    reverse(t, quint_in)
+}
+///Accelerates until halfway, then decelerates with the fifth power.
+pub fn quint_in_out(t: f32) -> f32 {
+    in_out(t, quint_in)
+}
+
+//----------Expo----------
+
+///Barely moves at first and then shoots towards the end, doubling its value every tenth.
+pub fn expo_in(t: f32) -> f32 {
+    if t <= 0.0 { 0.0 } else { 2f32.powf(10.0 * t - 10.0) }
+}
+///Shoots off and then creeps towards the end, halving the remaining distance every tenth.
+pub fn expo_out(t: f32) -> f32 {
+    if t >= 1.0 { 1.0 } else { 1.0 - 2f32.powf(-10.0 * t) }
+}
+///Exponential ease in for the first half and ease out for the second.
+pub fn expo_in_out(t: f32) -> f32 {
+    in_out(t, expo_in)
+}
+
+//----------Circ----------
+
+///Accelerates along a quarter circle, very steep at the end.
+pub fn circ_in(t: f32) -> f32 {
+    1.0 - (1.0 - t * t).max(0.0).sqrt()
+}
+///Decelerates along a quarter circle, very steep at the start.
+pub fn circ_out(t: f32) -> f32 {
+    reverse(t, circ_in)
+}
+///Circular ease in for the first half and ease out for the second.
+pub fn circ_in_out(t: f32) -> f32 {
+    in_out(t, circ_in)
+}
+
+//----------Back----------
+
+///Pulls back before moving towards the end.
+pub fn back_in(t: f32) -> f32 {
+    (BACK + 1.0) * t * t * t - BACK * t * t
+}
+///Overshoots the end before settling.
+pub fn back_out(t: f32) -> f32 {
+    reverse(t, back_in)
+}
+///Pulls back at the start and overshoots the end.
+pub fn back_in_out(t: f32) -> f32 {
+    //uses a stronger overshoot than back_in
+    let t = t * 2.0;
+    if t < 1.0 {
+        t * t * ((BACK_IN_OUT + 1.0) * t - BACK_IN_OUT) * 0.5
+    } else {
+        let t = t - 2.0;
+        (t * t * ((BACK_IN_OUT + 1.0) * t + BACK_IN_OUT) + 2.0) * 0.5
+    }
+}
+
+//----------Elastic----------
+
+///Winds up like a spring before moving towards the end.
+pub fn elastic_in(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t.clamp(0.0, 1.0);
+    }
+    -2f32.powf(10.0 * t - 10.0) * ((t * 10.0 - 10.75) * ELASTIC).sin()
+}
+///Oscillates around the end like a spring.
+pub fn elastic_out(t: f32) -> f32 {
+    reverse(t, elastic_in)
+}
+///Winds up like a spring at the start and oscillates around the end.
+pub fn elastic_in_out(t: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t.clamp(0.0, 1.0);
+    }
+    let wave = ((20.0 * t - 11.125) * ELASTIC_IN_OUT).sin();
+    if t < 0.5 {
+        -2f32.powf(20.0 * t - 10.0) * wave * 0.5
+    } else {
+        2f32.powf(-20.0 * t + 10.0) * wave * 0.5 + 1.0
+    }
+}
+
+//----------Bounce----------
+
+///Bounces off the start with growing hops, the reverse of bounce_out.
+pub fn bounce_in(t: f32) -> f32 {
+    reverse(t, bounce_out)
+}
+///Bounces on the end like a dropped ball.
+pub fn bounce_out(t: f32) -> f32 {
+    if t < 1.0 / BOUNCE_WIDTH {
+        BOUNCE * t * t
+    } else if t < 2.0 / BOUNCE_WIDTH {
+        let t = t - 1.5 / BOUNCE_WIDTH;
+        BOUNCE * t * t + 0.75
+    } else if t < 2.5 / BOUNCE_WIDTH {
+        let t = t - 2.25 / BOUNCE_WIDTH;
+        BOUNCE * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / BOUNCE_WIDTH;
+        BOUNCE * t * t + 0.984375
+    }
+}
+///Bounces off the start and then onto the end.
+pub fn bounce_in_out(t: f32) -> f32 {
+    in_out(t, bounce_in)
+}
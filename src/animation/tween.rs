@@ -0,0 +1,113 @@
+use crate::Vector;
+use crate::animation::easing;
+
+
+///Animates a value from start to end over a duration, shaped by an easing function.
+/// Works with Vec2 and Vec3 and with anything else that implements Vector.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::animation::{easing, Tween};
+/// let mut tween = Tween::new(Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), 2.0, easing::linear);
+///
+/// assert_eq!(Vec2::new(5.0, 0.0), tween.step(1.0));
+/// assert_eq!(Vec2::new(10.0, 0.0), tween.step(5.0));
+/// assert!(tween.is_finished());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Tween<V: Vector> {
+    pub start: V,
+    pub end: V,
+    ///Duration of one pass from start to end, in the same unit as the time steps.
+    pub duration: f32,
+    pub easing: fn(f32) -> f32,
+    ///Plays back from end to start after every pass, retracing the same path.
+    pub yoyo: bool,
+    ///Starts over instead of finishing.
+    pub looping: bool,
+    elapsed: f32,
+}
+
+impl<V: Vector> Tween<V> {
+    ///Creates a tween that plays once. Panics if the duration is negative.
+    pub fn new(start: V, end: V, duration: f32, easing: fn(f32) -> f32) -> Tween<V> {
+        assert!(duration >= 0.0, "a tween can't have a negative duration");
+        Tween { start, end, duration, easing, yoyo: false, looping: false, elapsed: 0.0 }
+    }
+
+    ///Creates a tween without easing.
+    pub fn linear(start: V, end: V, duration: f32) -> Tween<V> {
+        Tween::new(start, end, duration, easing::linear)
+    }
+
+    ///Advances the tween by dt and returns the new value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::animation::Tween;
+    /// let mut tween = Tween::linear(Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), 1.0);
+    /// tween.yoyo = true;
+    /// tween.looping = true;
+    ///
+    /// assert_eq!(Vec2::new(5.0, 0.0), tween.step(0.5));
+    /// assert_eq!(Vec2::new(10.0, 0.0), tween.step(0.5));
+    /// assert_eq!(Vec2::new(5.0, 0.0), tween.step(0.5));
+    /// assert_eq!(Vec2::new(0.0, 0.0), tween.step(0.5));
+    /// assert_eq!(Vec2::new(5.0, 0.0), tween.step(0.5));
+    /// ```
+    pub fn step(&mut self, dt: f32) -> V {
+        self.elapsed += dt;
+        let cycle = self.cycle_duration();
+        if self.looping && cycle > 0.0 {
+            //keep the elapsed time small so it doesn't lose precision, but leave a full cycle reachable
+            if self.elapsed > cycle {
+                self.elapsed %= cycle;
+            }
+        } else {
+            self.elapsed = self.elapsed.min(cycle);
+        }
+
+        self.value()
+    }
+
+    ///Returns the current value.
+    pub fn value(&self) -> V {
+        self.start.lerp(self.end, (self.easing)(self.progress()))
+    }
+
+    ///Returns how far the current pass is from start (0.0) to end (1.0), before easing.
+    pub fn progress(&self) -> f32 {
+        if self.duration == 0.0 {
+            return if self.yoyo { 0.0 } else { 1.0 };
+        }
+
+        let position = self.elapsed / self.duration;
+        if self.yoyo && position > 1.0 {
+            (2.0 - position).max(0.0)
+        } else {
+            position.min(1.0)
+        }
+    }
+
+    ///Returns the time since the start of the current cycle.
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    ///Returns true if the tween reached its end. Looping tweens never finish.
+    pub fn is_finished(&self) -> bool {
+        !self.looping && self.elapsed >= self.cycle_duration()
+    }
+
+    ///Starts the tween over.
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+
+    //duration until the tween is back where it started
+    fn cycle_duration(&self) -> f32 {
+        if self.yoyo { self.duration * 2.0 } else { self.duration }
+    }
+}
@@ -0,0 +1,198 @@
+//!Flocking after Craig Reynolds' boids. Every boid steers by three rules applied to the boids it can see:
+//! separation (don't crowd), alignment (fly the same way) and cohesion (stay together).
+//!
+//! # Examples
+//! ```rust
+//! use gamevecs::Vec2;
+//! use gamevecs::boids::{Flock, FlockSettings};
+//! let mut birds = Flock::new(FlockSettings::new(5.0, 4.0, 8.0));
+//! for i in 0..100 {
+//!     birds.add(Vec2::new((i % 10) as f32, (i / 10) as f32), Vec2::new(1.0, 0.5));
+//! }
+//!
+//! for _ in 0..60 {
+//!     birds.step(1.0 / 60.0);
+//! }
+//!
+//! assert!(birds.boids().iter().all(|bird| bird.velocity.magnitude() <= 4.0 + 1e-4));
+//! ```
+
+use crate::Vector;
+use crate::spatial::{GridVector, ItemId, SpatialHash};
+use crate::steering::{Agent, blend_weighted};
+
+
+///Position and velocity of a member of a flock.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Boid<V: Vector> {
+    pub position: V,
+    pub velocity: V,
+}
+
+///Rules shared by all boids of a flock.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct FlockSettings {
+    ///Distance up to which a boid sees the others.
+    pub perception_radius: f32,
+    ///Distance below which boids push each other away.
+    pub separation_radius: f32,
+    pub separation_weight: f32,
+    pub alignment_weight: f32,
+    pub cohesion_weight: f32,
+    pub max_speed: f32,
+    pub max_force: f32,
+}
+
+impl FlockSettings {
+    ///Creates settings with a separation radius of half the perception radius,
+    /// a separation weight of 1.5 and alignment and cohesion weights of 1.0.
+    pub fn new(perception_radius: f32, max_speed: f32, max_force: f32) -> FlockSettings {
+        FlockSettings {
+            perception_radius,
+            separation_radius: perception_radius * 0.5,
+            separation_weight: 1.5,
+            alignment_weight: 1.0,
+            cohesion_weight: 1.0,
+            max_speed,
+            max_force,
+        }
+    }
+}
+
+///A group of boids that steer together. Works with Vec2 and Vec3.
+/// Neighbours are found with a spatial hash, so every step costs about the number of boids times the typical number of neighbours.
+#[derive(Debug, Clone)]
+pub struct Flock<V: GridVector> {
+    pub settings: FlockSettings,
+    boids: Vec<Boid<V>>,
+    //handle of every boid in the grid, which stores boid indices
+    handles: Vec<ItemId>,
+    grid: SpatialHash<V, u32>,
+    forces: Vec<V>,
+}
+
+impl<V: GridVector> Flock<V> {
+    ///Creates an empty flock. Panics if the perception radius isn't positive.
+    pub fn new(settings: FlockSettings) -> Flock<V> {
+        Flock { settings, boids: Vec::new(), handles: Vec::new(), grid: SpatialHash::new(settings.perception_radius), forces: Vec::new() }
+    }
+
+    ///Adds a boid and returns its index.
+    pub fn add(&mut self, position: V, velocity: V) -> usize {
+        let index = self.boids.len();
+        self.boids.push(Boid { position, velocity });
+        self.handles.push(self.grid.insert(position, index as u32));
+        index
+    }
+
+    ///Removes the boid with the given index. The last boid takes its index.
+    pub fn remove(&mut self, index: usize) -> Boid<V> {
+        self.grid.remove(self.handles[index]);
+        self.handles.swap_remove(index);
+        if let Some(handle) = self.handles.get(index) {
+            *self.grid.get_mut(*handle).unwrap() = index as u32;
+        }
+        self.boids.swap_remove(index)
+    }
+
+    ///Returns the number of boids in this flock.
+    pub fn len(&self) -> usize {
+        self.boids.len()
+    }
+
+    ///Returns true if this flock has no boids.
+    pub fn is_empty(&self) -> bool {
+        self.boids.is_empty()
+    }
+
+    ///Returns the boids of this flock, indexed like remove expects.
+    pub fn boids(&self) -> &[Boid<V>] {
+        &self.boids
+    }
+
+    ///Returns the boids as mutable. Moved boids are sorted into the grid again at the start of the next step.
+    pub fn boids_mut(&mut self) -> &mut [Boid<V>] {
+        &mut self.boids
+    }
+
+    ///Returns the indices of the boids within the perception radius of a point.
+    pub fn neighbours(&self, point: V) -> impl Iterator<Item = usize> + '_ {
+        self.grid.query_radius(point, self.settings.perception_radius).map(|(_, _, index)| *index as usize)
+    }
+
+    ///Advances the flock by dt.
+    pub fn step(&mut self, dt: f32) {
+        self.step_with(dt, |_, _| V::ZERO);
+    }
+
+    ///Advances the flock by dt, adding a force of the caller to the flocking force of every boid,
+    /// for goals, obstacle avoidance or predators. The callback receives the index and the boid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::boids::{Flock, FlockSettings};
+    /// use gamevecs::steering::Agent;
+    /// let settings = FlockSettings::new(3.0, 2.0, 4.0);
+    /// let mut fish = Flock::new(settings);
+    /// fish.add(Vec3::new(0.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 0.0));
+    /// let food = Vec3::new(10.0, 0.0, 0.0);
+    ///
+    /// for _ in 0..10 {
+    ///     fish.step_with(0.1, |_, boid| Agent::new(boid.position, boid.velocity, 2.0, 4.0).seek(food));
+    /// }
+    ///
+    /// assert!(fish.boids()[0].position.x > 0.0);
+    /// ```
+    pub fn step_with(&mut self, dt: f32, mut extra_force: impl FnMut(usize, &Boid<V>) -> V) {
+        let settings = self.settings;
+        for (boid, handle) in self.boids.iter().zip(&self.handles) {
+            self.grid.move_item(*handle, boid.position);
+        }
+
+        self.forces.clear();
+        for (index, boid) in self.boids.iter().enumerate() {
+            let agent = Agent::new(boid.position, boid.velocity, settings.max_speed, settings.max_force);
+            let mut push = V::ZERO;
+            let mut velocity_sum = V::ZERO;
+            let mut position_sum = V::ZERO;
+            let mut count = 0;
+            for (_, position, other) in self.grid.query_radius(boid.position, settings.perception_radius) {
+                if *other as usize == index {
+                    continue;
+                }
+
+                let offset = boid.position - position;
+                let distance_squared = offset.magnitude_squared();
+                //push away harder the closer the other boid is
+                if distance_squared < settings.separation_radius * settings.separation_radius && distance_squared > 0.0 {
+                    push += offset / distance_squared;
+                }
+                velocity_sum += self.boids[*other as usize].velocity;
+                position_sum += position;
+                count += 1;
+            }
+
+            let mut force = V::ZERO;
+            if count > 0 {
+                let separation = if push == V::ZERO { V::ZERO } else { agent.seek(boid.position + push) };
+                let alignment = if velocity_sum == V::ZERO { V::ZERO } else { agent.seek(boid.position + velocity_sum) };
+                let cohesion = agent.seek(position_sum / count as f32);
+                force = blend_weighted(&[
+                    (separation, settings.separation_weight),
+                    (alignment, settings.alignment_weight),
+                    (cohesion, settings.cohesion_weight),
+                ], settings.max_force);
+            }
+            self.forces.push(force + extra_force(index, boid));
+        }
+
+        for (index, boid) in self.boids.iter_mut().enumerate() {
+            let mut agent = Agent::new(boid.position, boid.velocity, settings.max_speed, settings.max_force);
+            agent.step(self.forces[index], dt);
+            boid.position = agent.position;
+            boid.velocity = agent.velocity;
+            self.grid.move_item(self.handles[index], boid.position);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod convex;
+mod gjk;
+mod epa;
+
+pub use self::convex::Convex;
+pub use self::gjk::{distance, intersects, closest_points};
+pub use self::epa::{Penetration, penetration2, penetration3};
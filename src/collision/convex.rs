@@ -0,0 +1,176 @@
+use crate::{Vec2, Vec3, Vector};
+use crate::shapes::{Aabb2, Aabb3, Circle, Sphere, Capsule2, Capsule3, ConvexHull2, ConvexHull3, Triangle3};
+
+
+///A convex shape described by its support function.
+/// Implementing this trait is all that is needed to use a shape with GJK and EPA.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::collision::Convex;
+/// use gamevecs::shapes::Circle;
+/// let circle = Circle::new(Vec2::new(1.0, 1.0), 2.0);
+///
+/// assert_eq!(Vec2::new(3.0, 1.0), circle.support(Vec2::RIGHT));
+/// ```
+pub trait Convex<V: Vector> {
+    ///Returns the point of the shape that is furthest in the given direction.
+    /// The direction does not need to be normalized.
+    fn support(&self, direction: V) -> V;
+
+    ///Returns any point inside of the shape. Used as the starting point of the queries.
+    fn center(&self) -> V;
+}
+
+//support point of a sphere or circle
+fn ball_support<V: Vector>(center: V, radius: f32, direction: V) -> V {
+    //avoid division by 0
+    if direction.magnitude_squared() == 0.0 {
+        return center;
+    }
+
+    center + direction.normalized() * radius
+}
+
+//support point of a set of points
+fn points_support<V: Vector>(points: &[V], direction: V) -> V {
+    let mut best = points[0];
+    let mut best_dot = best.dot(direction);
+    for point in &points[1..] {
+        let dot = point.dot(direction);
+        if dot > best_dot {
+            best = *point;
+            best_dot = dot;
+        }
+    }
+
+    best
+}
+
+fn points_center<V: Vector>(points: &[V]) -> V {
+    let mut sum = V::ZERO;
+    for point in points {
+        sum += *point;
+    }
+
+    sum / points.len() as f32
+}
+
+//----------Points----------
+
+impl Convex<Vec2> for Vec2 {
+    fn support(&self, _direction: Vec2) -> Vec2 {
+        *self
+    }
+    fn center(&self) -> Vec2 {
+        *self
+    }
+}
+
+impl Convex<Vec3> for Vec3 {
+    fn support(&self, _direction: Vec3) -> Vec3 {
+        *self
+    }
+    fn center(&self) -> Vec3 {
+        *self
+    }
+}
+
+//----------Circles and spheres----------
+
+impl Convex<Vec2> for Circle {
+    fn support(&self, direction: Vec2) -> Vec2 {
+        ball_support(self.center, self.radius, direction)
+    }
+    fn center(&self) -> Vec2 {
+        self.center
+    }
+}
+
+impl Convex<Vec3> for Sphere {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        ball_support(self.center, self.radius, direction)
+    }
+    fn center(&self) -> Vec3 {
+        self.center
+    }
+}
+
+//----------Boxes----------
+
+impl Convex<Vec2> for Aabb2 {
+    fn support(&self, direction: Vec2) -> Vec2 {
+        Vec2::new(
+            if direction.x >= 0.0 { self.max.x } else { self.min.x },
+            if direction.y >= 0.0 { self.max.y } else { self.min.y })
+    }
+    fn center(&self) -> Vec2 {
+        Aabb2::center(self)
+    }
+}
+
+impl Convex<Vec3> for Aabb3 {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        Vec3::new(
+            if direction.x >= 0.0 { self.max.x } else { self.min.x },
+            if direction.y >= 0.0 { self.max.y } else { self.min.y },
+            if direction.z >= 0.0 { self.max.z } else { self.min.z })
+    }
+    fn center(&self) -> Vec3 {
+        Aabb3::center(self)
+    }
+}
+
+//----------Capsules----------
+
+impl Convex<Vec2> for Capsule2 {
+    fn support(&self, direction: Vec2) -> Vec2 {
+        let end = points_support(&[self.a, self.b], direction);
+        ball_support(end, self.radius, direction)
+    }
+    fn center(&self) -> Vec2 {
+        self.a.lerp(self.b, 0.5)
+    }
+}
+
+impl Convex<Vec3> for Capsule3 {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        let end = points_support(&[self.a, self.b], direction);
+        ball_support(end, self.radius, direction)
+    }
+    fn center(&self) -> Vec3 {
+        self.a.lerp(self.b, 0.5)
+    }
+}
+
+//----------Convex hulls----------
+
+impl Convex<Vec2> for ConvexHull2 {
+    fn support(&self, direction: Vec2) -> Vec2 {
+        points_support(&self.points, direction)
+    }
+    fn center(&self) -> Vec2 {
+        points_center(&self.points)
+    }
+}
+
+impl Convex<Vec3> for ConvexHull3 {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        points_support(&self.points, direction)
+    }
+    fn center(&self) -> Vec3 {
+        points_center(&self.points)
+    }
+}
+
+//----------Triangles----------
+
+impl Convex<Vec3> for Triangle3 {
+    fn support(&self, direction: Vec3) -> Vec3 {
+        points_support(&[self.a, self.b, self.c], direction)
+    }
+    fn center(&self) -> Vec3 {
+        self.centroid()
+    }
+}
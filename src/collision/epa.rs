@@ -0,0 +1,268 @@
+use crate::{Vec2, Vec3};
+use crate::collision::Convex;
+use crate::collision::gjk::{gjk, support_point};
+
+
+const MAX_ITERATIONS: usize = 256;
+//the polytope is considered expanded once its closest face moves less than this
+const TOLERANCE: f32 = 1e-4;
+//support points closer than this to the current simplex don't expand it
+const DEGENERATE_TOLERANCE: f32 = 1e-8;
+
+
+///Penetration of two overlapping convex shapes
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Penetration<V> {
+    ///Direction from a to b with a magnitude of 1.
+    pub normal: V,
+    ///Distance b has to be moved along the normal to separate the shapes.
+    pub depth: f32,
+}
+
+
+///Returns the penetration depth and normal of two overlapping 2D shapes, or None if they don't overlap.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::collision;
+/// use gamevecs::shapes::Aabb2;
+/// let aabb1 = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+/// let aabb2 = Aabb2::new(Vec2::new(1.5, 0.0), Vec2::new(3.5, 2.0));
+///
+/// let penetration = collision::penetration2(&aabb1, &aabb2).unwrap();
+///
+/// assert!(penetration.normal.equals(Vec2::RIGHT, 1e-4));
+/// assert!((penetration.depth - 0.5).abs() < 1e-4);
+/// ```
+pub fn penetration2<A, B>(a: &A, b: &B) -> Option<Penetration<Vec2>>
+where
+    A: Convex<Vec2> + ?Sized,
+    B: Convex<Vec2> + ?Sized,
+{
+    let result = gjk(a, b);
+    if !result.intersecting {
+        return None;
+    }
+
+    let mut polytope: Vec<Vec2> = result.simplex.points[..result.simplex.len].iter().map(|p| p.point).collect();
+    polytope.truncate(3);
+
+    //blow the simplex up to a triangle
+    if polytope.len() == 1 {
+        for direction in [Vec2::RIGHT, Vec2::LEFT, Vec2::UP, Vec2::DOWN] {
+            let point = support_point(a, b, direction).point;
+            if point.distance_to_squared(polytope[0]) > DEGENERATE_TOLERANCE {
+                polytope.push(point);
+                break;
+            }
+        }
+    }
+    if polytope.len() == 2 {
+        let edge = polytope[1] - polytope[0];
+        let normal = Vec2::new(-edge.y, edge.x);
+        for direction in [normal, -normal] {
+            let point = support_point(a, b, direction).point;
+            if cross2(edge, point - polytope[0]).abs() > DEGENERATE_TOLERANCE {
+                polytope.push(point);
+                break;
+            }
+        }
+    }
+    if polytope.len() < 3 {
+        //a - b has no area, so the shapes only touch
+        let normal = match polytope.len() {
+            2 => { let edge = polytope[1] - polytope[0]; Vec2::new(edge.y, -edge.x).normalized() }
+            _ => Vec2::RIGHT,
+        };
+        return Some(Penetration { normal, depth: 0.0 });
+    }
+
+    //keep the polygon counterclockwise, so the edge normals point outwards
+    if cross2(polytope[1] - polytope[0], polytope[2] - polytope[0]) < 0.0 {
+        polytope.swap(1, 2);
+    }
+
+    let mut closest = Penetration { normal: Vec2::RIGHT, depth: f32::INFINITY };
+    for _ in 0..MAX_ITERATIONS {
+        let mut closest_index = 0;
+        closest.depth = f32::INFINITY;
+        for i in 0..polytope.len() {
+            let edge = polytope[(i + 1) % polytope.len()] - polytope[i];
+            if edge.magnitude_squared() == 0.0 {
+                continue;
+            }
+            let normal = Vec2::new(edge.y, -edge.x).normalized();
+            let distance = normal.dot(polytope[i]);
+            if distance < closest.depth {
+                closest = Penetration { normal, depth: distance };
+                closest_index = i;
+            }
+        }
+
+        let point = support_point(a, b, closest.normal).point;
+        if closest.normal.dot(point) - closest.depth <= TOLERANCE {
+            break;
+        }
+        polytope.insert(closest_index + 1, point);
+    }
+
+    closest.depth = closest.depth.max(0.0);
+    Some(closest)
+}
+
+fn cross2(a: Vec2, b: Vec2) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    indices: [usize; 3],
+    normal: Vec3,
+    distance: f32,
+}
+
+impl Face {
+    //creates a face whose normal points away from the interior point
+    fn new(vertices: &[Vec3], mut indices: [usize; 3], interior: Vec3) -> Face {
+        let [a, b, c] = indices.map(|i| vertices[i]);
+        let mut normal = (b - a).cross(c - a);
+        if normal.dot(a - interior) < 0.0 {
+            indices.swap(1, 2);
+            normal = -normal;
+        }
+
+        //degenerate faces are never the closest one
+        if normal.magnitude_squared() == 0.0 {
+            return Face { indices, normal, distance: f32::INFINITY };
+        }
+        let normal = normal.normalized();
+        Face { indices, normal, distance: normal.dot(a) }
+    }
+}
+
+///Returns the penetration depth and normal of two overlapping 3D shapes, or None if they don't overlap.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec3;
+/// use gamevecs::collision;
+/// use gamevecs::shapes::Aabb3;
+/// let aabb1 = Aabb3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(2.0, 2.0, 2.0));
+/// let aabb2 = Aabb3::new(Vec3::new(0.0, 1.75, 0.0), Vec3::new(2.0, 3.75, 2.0));
+///
+/// let penetration = collision::penetration3(&aabb1, &aabb2).unwrap();
+///
+/// assert!(penetration.normal.equals(Vec3::UP, 1e-4));
+/// assert!((penetration.depth - 0.25).abs() < 1e-4);
+/// ```
+pub fn penetration3<A, B>(a: &A, b: &B) -> Option<Penetration<Vec3>>
+where
+    A: Convex<Vec3> + ?Sized,
+    B: Convex<Vec3> + ?Sized,
+{
+    let result = gjk(a, b);
+    if !result.intersecting {
+        return None;
+    }
+
+    let mut vertices: Vec<Vec3> = result.simplex.points[..result.simplex.len].iter().map(|p| p.point).collect();
+
+    //blow the simplex up to a tetrahedron
+    if vertices.len() == 1 {
+        for direction in [Vec3::RIGHT, Vec3::LEFT, Vec3::UP, Vec3::DOWN, Vec3::FORWARD, Vec3::BACK] {
+            let point = support_point(a, b, direction).point;
+            if point.distance_to_squared(vertices[0]) > DEGENERATE_TOLERANCE {
+                vertices.push(point);
+                break;
+            }
+        }
+    }
+    if vertices.len() == 2 {
+        let line = vertices[1] - vertices[0];
+        //any axis that isn't parallel to the line gives a perpendicular
+        let axis = if line.x.abs() < line.y.abs() && line.x.abs() < line.z.abs() { Vec3::RIGHT }
+            else if line.y.abs() < line.z.abs() { Vec3::UP }
+            else { Vec3::FORWARD };
+        let perpendicular1 = line.cross(axis);
+        let perpendicular2 = line.cross(perpendicular1);
+        for direction in [perpendicular1, -perpendicular1, perpendicular2, -perpendicular2] {
+            let point = support_point(a, b, direction).point;
+            if line.cross(point - vertices[0]).magnitude_squared() > DEGENERATE_TOLERANCE {
+                vertices.push(point);
+                break;
+            }
+        }
+    }
+    if vertices.len() == 3 {
+        let normal = (vertices[1] - vertices[0]).cross(vertices[2] - vertices[0]);
+        for direction in [normal, -normal] {
+            let point = support_point(a, b, direction).point;
+            if normal.dot(point - vertices[0]).abs() > DEGENERATE_TOLERANCE {
+                vertices.push(point);
+                break;
+            }
+        }
+    }
+    if vertices.len() < 4 {
+        //a - b has no volume, so the shapes only touch
+        let normal = match vertices.len() {
+            3 => (vertices[1] - vertices[0]).cross(vertices[2] - vertices[0]).normalized(),
+            _ => Vec3::UP,
+        };
+        return Some(Penetration { normal, depth: 0.0 });
+    }
+
+    //the centroid of the tetrahedron stays inside of the polytope while it grows
+    let interior = (vertices[0] + vertices[1] + vertices[2] + vertices[3]) * 0.25;
+    let mut faces = vec![
+        Face::new(&vertices, [0, 1, 2], interior),
+        Face::new(&vertices, [0, 3, 1], interior),
+        Face::new(&vertices, [0, 2, 3], interior),
+        Face::new(&vertices, [1, 3, 2], interior),
+    ];
+
+    let mut closest = faces[0];
+    for _ in 0..MAX_ITERATIONS {
+        closest = faces[0];
+        for face in &faces[1..] {
+            if face.distance < closest.distance {
+                closest = *face;
+            }
+        }
+
+        let point = support_point(a, b, closest.normal).point;
+        if closest.normal.dot(point) - closest.distance <= TOLERANCE {
+            break;
+        }
+
+        //remove every face the new point can see and remember the edges of the hole
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        faces.retain(|face| {
+            if face.normal.dot(point - vertices[face.indices[0]]) <= 0.0 {
+                return true;
+            }
+            for i in 0..3 {
+                let edge = (face.indices[i], face.indices[(i + 1) % 3]);
+                if let Some(shared) = edges.iter().position(|e| *e == (edge.1, edge.0)) {
+                    edges.swap_remove(shared);
+                } else {
+                    edges.push(edge);
+                }
+            }
+            false
+        });
+        if edges.is_empty() {
+            break;
+        }
+
+        vertices.push(point);
+        let index = vertices.len() - 1;
+        for (from, to) in edges {
+            faces.push(Face::new(&vertices, [from, to, index], interior));
+        }
+    }
+
+    Some(Penetration { normal: closest.normal, depth: closest.distance.max(0.0) })
+}
@@ -0,0 +1,319 @@
+use crate::Vector;
+use crate::collision::Convex;
+
+
+const MAX_ITERATIONS: usize = 64;
+//relative tolerance used to detect that the distance doesn't shrink anymore
+const RELATIVE_TOLERANCE: f32 = 1e-6;
+//squared distances below this count as touching
+const ABSOLUTE_TOLERANCE: f32 = 1e-10;
+
+
+//point of the minkowski difference a - b together with the support points it was made of
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SupportPoint<V> {
+    pub(crate) point: V,
+    pub(crate) a: V,
+    pub(crate) b: V,
+}
+
+pub(crate) fn support_point<V, A, B>(a: &A, b: &B, direction: V) -> SupportPoint<V>
+where
+    V: Vector,
+    A: Convex<V> + ?Sized,
+    B: Convex<V> + ?Sized,
+{
+    let a = a.support(direction);
+    let b = b.support(-direction);
+    SupportPoint { point: a - b, a, b }
+}
+
+//up to 4 support points, enough for a tetrahedron
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Simplex<V> {
+    pub(crate) points: [SupportPoint<V>; 4],
+    pub(crate) weights: [f32; 4],
+    pub(crate) len: usize,
+}
+
+impl<V: Vector> Simplex<V> {
+    fn new(first: SupportPoint<V>) -> Simplex<V> {
+        Simplex { points: [first; 4], weights: [1.0, 0.0, 0.0, 0.0], len: 1 }
+    }
+
+    fn contains(&self, point: V) -> bool {
+        self.points[..self.len].iter().any(|p| p.point == point)
+    }
+
+    fn push(&mut self, point: SupportPoint<V>) {
+        self.points[self.len] = point;
+        self.len += 1;
+    }
+
+    //weighted sum of the support points
+    fn combine(&self, select: impl Fn(&SupportPoint<V>) -> V) -> V {
+        let mut sum = V::ZERO;
+        for i in 0..self.len {
+            sum += select(&self.points[i]) * self.weights[i];
+        }
+        sum
+    }
+
+    //Finds the point of the simplex that is closest to the origin and
+    //reduces the simplex to the smallest subset that still contains it.
+    //Every subset is tried by projecting the origin onto its affine hull,
+    //which only needs dot products and therefore works in 2D and 3D alike.
+    fn reduce(&mut self) -> V {
+        let mut best_mask = 1;
+        let mut best_weights = [1.0, 0.0, 0.0, 0.0];
+        let mut best_distance = f32::INFINITY;
+
+        //try small subsets first so they win ties against bigger ones
+        for count in 1..=self.len {
+            for mask in 1usize..(1 << self.len) {
+                if mask.count_ones() as usize != count {
+                    continue;
+                }
+                let Some(weights) = self.affine_weights(mask) else {
+                    continue;
+                };
+
+                let mut point = V::ZERO;
+                for (i, weight) in weights.iter().enumerate() {
+                    point += self.points[i].point * *weight;
+                }
+                let distance = point.magnitude_squared();
+                if distance < best_distance * (1.0 - RELATIVE_TOLERANCE) {
+                    best_mask = mask;
+                    best_weights = weights;
+                    best_distance = distance;
+                }
+            }
+        }
+
+        let mut len = 0;
+        for (i, weight) in best_weights.iter().enumerate().take(self.len) {
+            if best_mask & (1 << i) != 0 {
+                self.points[len] = self.points[i];
+                self.weights[len] = *weight;
+                len += 1;
+            }
+        }
+        self.len = len;
+
+        self.combine(|p| p.point)
+    }
+
+    //Returns the barycentric weights of the origin projected onto the affine hull of the
+    //selected points, or None if the projection lies outside of them or they are degenerate.
+    fn affine_weights(&self, mask: usize) -> Option<[f32; 4]> {
+        let mut indices = [0; 4];
+        let mut count = 0;
+        for i in 0..self.len {
+            if mask & (1 << i) != 0 {
+                indices[count] = i;
+                count += 1;
+            }
+        }
+
+        let mut weights = [0.0; 4];
+        if count == 1 {
+            weights[indices[0]] = 1.0;
+            return Some(weights);
+        }
+
+        //solve the normal equations for p0 + sum(mu_i * e_i) closest to the origin
+        let origin = self.points[indices[0]].point;
+        let mut edges = [V::ZERO; 3];
+        for i in 1..count {
+            edges[i - 1] = self.points[indices[i]].point - origin;
+        }
+        let n = count - 1;
+        let mut matrix = [[0.0f32; 4]; 3];
+        let mut max_diagonal = 0.0f32;
+        for row in 0..n {
+            for column in 0..n {
+                matrix[row][column] = edges[row].dot(edges[column]);
+            }
+            matrix[row][n] = -edges[row].dot(origin);
+            max_diagonal = max_diagonal.max(matrix[row][row]);
+        }
+
+        let mu = solve(&mut matrix, n, max_diagonal * 1e-6)?;
+        let mut first = 1.0;
+        for i in 0..n {
+            if mu[i] <= 0.0 {
+                return None;
+            }
+            weights[indices[i + 1]] = mu[i];
+            first -= mu[i];
+        }
+        if first <= 0.0 {
+            return None;
+        }
+        weights[indices[0]] = first;
+
+        Some(weights)
+    }
+}
+
+//gaussian elimination with partial pivoting on an augmented n x (n + 1) matrix
+fn solve(matrix: &mut [[f32; 4]; 3], n: usize, epsilon: f32) -> Option<[f32; 3]> {
+    for column in 0..n {
+        let mut pivot = column;
+        for row in column + 1..n {
+            if matrix[row][column].abs() > matrix[pivot][column].abs() {
+                pivot = row;
+            }
+        }
+        if matrix[pivot][column].abs() <= epsilon {
+            return None;
+        }
+        matrix.swap(column, pivot);
+
+        let pivot_row = matrix[column];
+        for row in matrix.iter_mut().take(n).skip(column + 1) {
+            let factor = row[column] / pivot_row[column];
+            for (value, pivot_value) in row[column..=n].iter_mut().zip(&pivot_row[column..=n]) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+
+    let mut result = [0.0; 3];
+    for row in (0..n).rev() {
+        let mut sum = matrix[row][n];
+        for k in row + 1..n {
+            sum -= matrix[row][k] * result[k];
+        }
+        result[row] = sum / matrix[row][row];
+    }
+
+    Some(result)
+}
+
+pub(crate) struct Gjk<V> {
+    pub(crate) simplex: Simplex<V>,
+    pub(crate) closest: V,
+    pub(crate) intersecting: bool,
+}
+
+//runs GJK on the minkowski difference a - b
+pub(crate) fn gjk<V, A, B>(a: &A, b: &B) -> Gjk<V>
+where
+    V: Vector,
+    A: Convex<V> + ?Sized,
+    B: Convex<V> + ?Sized,
+{
+    let mut direction = a.center() - b.center();
+    if direction.magnitude_squared() == 0.0 {
+        direction = V::ONE;
+    }
+
+    let mut simplex = Simplex::new(support_point(a, b, direction));
+    let mut closest = simplex.points[0].point;
+
+    for _ in 0..MAX_ITERATIONS {
+        let distance = closest.magnitude_squared();
+        if distance <= ABSOLUTE_TOLERANCE {
+            return Gjk { simplex, closest, intersecting: true };
+        }
+
+        let next = support_point(a, b, -closest);
+        //no point of a - b is closer to the origin than the current one
+        if distance - closest.dot(next.point) <= RELATIVE_TOLERANCE * distance || simplex.contains(next.point) {
+            break;
+        }
+
+        simplex.push(next);
+        closest = simplex.reduce();
+
+        //only a tetrahedron containing the origin can't be reduced
+        if simplex.len == 4 {
+            return Gjk { simplex, closest, intersecting: true };
+        }
+    }
+
+    let intersecting = closest.magnitude_squared() <= ABSOLUTE_TOLERANCE;
+    Gjk { simplex, closest, intersecting }
+}
+
+
+///Returns the distance between two convex shapes, or 0.0 if they overlap.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::collision;
+/// use gamevecs::shapes::Circle;
+/// let circle1 = Circle::new(Vec2::new(0.0, 0.0), 1.0);
+/// let circle2 = Circle::new(Vec2::new(5.0, 0.0), 1.0);
+///
+/// let distance = collision::distance(&circle1, &circle2);
+///
+/// assert!((distance - 3.0).abs() < 1e-4);
+/// ```
+pub fn distance<V, A, B>(a: &A, b: &B) -> f32
+where
+    V: Vector,
+    A: Convex<V> + ?Sized,
+    B: Convex<V> + ?Sized,
+{
+    let result = gjk(a, b);
+    if result.intersecting {
+        return 0.0;
+    }
+
+    result.closest.magnitude()
+}
+
+///Returns true if two convex shapes overlap or touch.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec3;
+/// use gamevecs::collision;
+/// use gamevecs::shapes::{Aabb3, Sphere};
+/// let aabb = Aabb3::new(Vec3::ZERO, Vec3::ONE);
+/// let sphere = Sphere::new(Vec3::new(1.5, 0.5, 0.5), 1.0);
+///
+/// assert!(collision::intersects(&aabb, &sphere));
+/// ```
+pub fn intersects<V, A, B>(a: &A, b: &B) -> bool
+where
+    V: Vector,
+    A: Convex<V> + ?Sized,
+    B: Convex<V> + ?Sized,
+{
+    gjk(a, b).intersecting
+}
+
+///Returns the closest points between two convex shapes as (point on a, point on b),
+/// or None if they overlap.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::collision;
+/// use gamevecs::shapes::Aabb2;
+/// let aabb1 = Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+/// let aabb2 = Aabb2::new(Vec2::new(3.0, 0.5), Vec2::new(4.0, 2.0));
+///
+/// let (on_a, on_b) = collision::closest_points(&aabb1, &aabb2).unwrap();
+///
+/// assert_eq!(1.0, on_a.x);
+/// assert_eq!(3.0, on_b.x);
+/// ```
+pub fn closest_points<V, A, B>(a: &A, b: &B) -> Option<(V, V)>
+where
+    V: Vector,
+    A: Convex<V> + ?Sized,
+    B: Convex<V> + ?Sized,
+{
+    let result = gjk(a, b);
+    if result.intersecting {
+        return None;
+    }
+
+    Some((result.simplex.combine(|p| p.a), result.simplex.combine(|p| p.b)))
+}
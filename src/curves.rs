@@ -0,0 +1,7 @@
+mod bezier;
+mod spline;
+mod arc_length;
+
+pub use self::bezier::{QuadraticBezier, CubicBezier};
+pub use self::spline::{Spline, Parameterization, CatmullRom, Hermite, BSpline};
+pub use self::arc_length::ArcLengthTable;
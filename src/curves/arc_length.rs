@@ -0,0 +1,84 @@
+use crate::Vector;
+use crate::curves::Spline;
+
+
+///Lookup table from distance along a curve to t, for moving along it at constant speed.
+/// Owns the curve it was built for.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::curves::{CatmullRom, Parameterization, Spline};
+/// let points = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(10.0, 0.0)];
+/// let path = CatmullRom::new(points, Parameterization::Centripetal).arc_length_table(32);
+///
+/// assert!((path.length() - 10.0).abs() < 1e-3);
+/// //halfway by t is the second point, halfway by distance is further along
+/// assert!(path.at(5.0).equals(Vec2::new(5.0, 0.0), 1e-2));
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct ArcLengthTable<S: Spline> {
+    spline: S,
+    //distance from the start to every evenly spaced sample of t
+    lengths: Vec<f32>,
+}
+
+impl<S: Spline> ArcLengthTable<S> {
+    ///Measures the curve by approximating every segment with the given number of straight pieces.
+    /// Panics if samples_per_segment is 0.
+    pub fn new(spline: S, samples_per_segment: usize) -> ArcLengthTable<S> {
+        assert!(samples_per_segment > 0, "an arc length table needs at least one sample per segment");
+        let samples = spline.segments() * samples_per_segment;
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut length = 0.0;
+        let mut previous = spline.at(0.0);
+        lengths.push(0.0);
+        for i in 1..=samples {
+            let point = spline.at(i as f32 / samples as f32);
+            length += point.distance_to(previous);
+            lengths.push(length);
+            previous = point;
+        }
+
+        ArcLengthTable { spline, lengths }
+    }
+
+    ///Returns the measured curve.
+    pub fn spline(&self) -> &S {
+        &self.spline
+    }
+
+    ///Returns the length of the curve.
+    pub fn length(&self) -> f32 {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    ///Returns the t at which the curve is the given distance away from its start, measured along the curve.
+    /// The distance gets clamped to the length of the curve.
+    pub fn t_at(&self, distance: f32) -> f32 {
+        let last = self.lengths.len() - 1;
+        if distance <= 0.0 {
+            return 0.0;
+        }
+        if distance >= self.length() {
+            return 1.0;
+        }
+
+        let index = self.lengths.partition_point(|length| *length <= distance).clamp(1, last);
+        let start = self.lengths[index - 1];
+        let piece = self.lengths[index] - start;
+        let fraction = if piece > 0.0 { (distance - start) / piece } else { 0.0 };
+
+        (index as f32 - 1.0 + fraction) / last as f32
+    }
+
+    ///Returns the point at the given distance along the curve.
+    pub fn at(&self, distance: f32) -> S::Vector {
+        self.spline.at(self.t_at(distance))
+    }
+
+    ///Returns the direction of the curve at the given distance along it.
+    pub fn tangent(&self, distance: f32) -> S::Vector {
+        self.spline.tangent(self.t_at(distance))
+    }
+}
@@ -0,0 +1,334 @@
+use crate::Vector;
+
+
+//number of evenly spaced samples used as starting points when searching the closest point
+const CLOSEST_SAMPLES: usize = 16;
+const NEWTON_ITERATIONS: usize = 8;
+
+
+///Quadratic Bezier curve through start and end, pulled towards the control point.
+/// Works with Vec2 and Vec3.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::curves::QuadraticBezier;
+/// let curve = QuadraticBezier::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(2.0, 0.0));
+///
+/// assert_eq!(Vec2::new(1.0, 1.0), curve.at(0.5));
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct QuadraticBezier<V: Vector> {
+    pub start: V,
+    pub control: V,
+    pub end: V,
+}
+
+impl<V: Vector> QuadraticBezier<V> {
+    pub fn new(start: V, control: V, end: V) -> QuadraticBezier<V> {
+        QuadraticBezier { start, control, end }
+    }
+
+    ///Returns the point of the curve at t (0.0 is the start, 1.0 the end).
+    pub fn at(&self, t: f32) -> V {
+        let s = 1.0 - t;
+        self.start * (s * s) + self.control * (2.0 * s * t) + self.end * (t * t)
+    }
+
+    ///Returns the first derivative (velocity) of the curve at t.
+    pub fn derivative(&self, t: f32) -> V {
+        ((self.control - self.start) * (1.0 - t) + (self.end - self.control) * t) * 2.0
+    }
+
+    ///Returns the second derivative (acceleration) of the curve, which is the same for every t.
+    pub fn second_derivative(&self) -> V {
+        (self.end - self.control * 2.0 + self.start) * 2.0
+    }
+
+    ///Returns the direction of the curve at t with a magnitude of 1.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::curves::QuadraticBezier;
+    /// let curve = QuadraticBezier::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0), Vec2::new(2.0, 0.0));
+    ///
+    /// assert_eq!(Vec2::RIGHT, curve.tangent(0.5));
+    /// ```
+    pub fn tangent(&self, t: f32) -> V {
+        tangent(self.derivative(t), self.end - self.start)
+    }
+
+    ///Splits the curve at t into two curves that together trace the same path.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::curves::QuadraticBezier;
+    /// let curve = QuadraticBezier::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(2.0, 0.0));
+    /// let (first, second) = curve.split(0.5);
+    ///
+    /// assert_eq!(curve.at(0.25), first.at(0.5));
+    /// assert_eq!(curve.at(0.75), second.at(0.5));
+    /// ```
+    pub fn split(&self, t: f32) -> (QuadraticBezier<V>, QuadraticBezier<V>) {
+        //de Casteljau
+        let a = self.start.lerp(self.control, t);
+        let b = self.control.lerp(self.end, t);
+        let middle = a.lerp(b, t);
+
+        (QuadraticBezier::new(self.start, a, middle), QuadraticBezier::new(middle, b, self.end))
+    }
+
+    ///Returns the smallest box containing the curve as (min, max).
+    /// Unlike the box around the control points this does not contain any empty space.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::curves::QuadraticBezier;
+    /// let curve = QuadraticBezier::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(2.0, 0.0));
+    ///
+    /// assert_eq!((Vec2::new(0.0, 0.0), Vec2::new(2.0, 1.0)), curve.bounds());
+    /// ```
+    pub fn bounds(&self) -> (V, V) {
+        let mut min = self.start.min(self.end);
+        let mut max = self.start.max(self.end);
+        let d0 = self.control - self.start;
+        let d1 = self.end - self.control;
+        for axis in 0..V::DIMENSIONS {
+            //the derivative of every axis is linear
+            let denominator = d0.component(axis) - d1.component(axis);
+            if denominator != 0.0 {
+                let t = d0.component(axis) / denominator;
+                if t > 0.0 && t < 1.0 {
+                    let point = self.at(t);
+                    min = min.min(point);
+                    max = max.max(point);
+                }
+            }
+        }
+
+        (min, max)
+    }
+
+    ///Returns the point of the curve closest to the given one as (t, point).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::curves::QuadraticBezier;
+    /// let curve = QuadraticBezier::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(2.0, 0.0));
+    /// let (t, point) = curve.closest_point(Vec2::new(1.0, 3.0));
+    ///
+    /// assert!((t - 0.5).abs() < 1e-5);
+    /// assert!(point.equals(Vec2::new(1.0, 1.0), 1e-5));
+    /// ```
+    pub fn closest_point(&self, point: V) -> (f32, V) {
+        let t = closest_t(point, |t| self.at(t), |t| self.derivative(t), |_| self.second_derivative());
+        (t, self.at(t))
+    }
+}
+
+
+///Cubic Bezier curve through start and end, shaped by two control points.
+/// Works with Vec2 and Vec3.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::curves::CubicBezier;
+/// let curve = CubicBezier::new(Vec2::new(0.0, 0.0), Vec2::new(0.0, 4.0), Vec2::new(4.0, 4.0), Vec2::new(4.0, 0.0));
+///
+/// assert_eq!(Vec2::new(2.0, 3.0), curve.at(0.5));
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct CubicBezier<V: Vector> {
+    pub start: V,
+    pub control1: V,
+    pub control2: V,
+    pub end: V,
+}
+
+impl<V: Vector> CubicBezier<V> {
+    pub fn new(start: V, control1: V, control2: V, end: V) -> CubicBezier<V> {
+        CubicBezier { start, control1, control2, end }
+    }
+
+    ///Returns the point of the curve at t (0.0 is the start, 1.0 the end).
+    pub fn at(&self, t: f32) -> V {
+        let s = 1.0 - t;
+        self.start * (s * s * s) + self.control1 * (3.0 * s * s * t) + self.control2 * (3.0 * s * t * t) + self.end * (t * t * t)
+    }
+
+    ///Returns the first derivative (velocity) of the curve at t.
+    pub fn derivative(&self, t: f32) -> V {
+        let s = 1.0 - t;
+        ((self.control1 - self.start) * (s * s) + (self.control2 - self.control1) * (2.0 * s * t) + (self.end - self.control2) * (t * t)) * 3.0
+    }
+
+    ///Returns the second derivative (acceleration) of the curve at t.
+    pub fn second_derivative(&self, t: f32) -> V {
+        let a = self.control2 - self.control1 * 2.0 + self.start;
+        let b = self.end - self.control2 * 2.0 + self.control1;
+        (a * (1.0 - t) + b * t) * 6.0
+    }
+
+    ///Returns the direction of the curve at t with a magnitude of 1.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::curves::CubicBezier;
+    /// let curve = CubicBezier::new(Vec2::new(0.0, 0.0), Vec2::new(0.0, 4.0), Vec2::new(4.0, 4.0), Vec2::new(4.0, 0.0));
+    ///
+    /// assert_eq!(Vec2::UP, curve.tangent(0.0));
+    /// assert_eq!(Vec2::RIGHT, curve.tangent(0.5));
+    /// ```
+    pub fn tangent(&self, t: f32) -> V {
+        let derivative = self.derivative(t);
+        //control points on top of the ends have no velocity there, the curve still heads towards the other control point
+        if derivative.magnitude_squared() == 0.0 {
+            let fallback = if t < 0.5 { self.control2 - self.start } else { self.end - self.control1 };
+            return tangent(fallback, self.end - self.start);
+        }
+
+        derivative.normalized()
+    }
+
+    ///Splits the curve at t into two curves that together trace the same path.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::curves::CubicBezier;
+    /// let curve = CubicBezier::new(Vec2::new(0.0, 0.0), Vec2::new(0.0, 4.0), Vec2::new(4.0, 4.0), Vec2::new(4.0, 0.0));
+    /// let (first, second) = curve.split(0.5);
+    ///
+    /// assert_eq!(curve.at(0.5), first.end);
+    /// assert_eq!(curve.at(0.75), second.at(0.5));
+    /// ```
+    pub fn split(&self, t: f32) -> (CubicBezier<V>, CubicBezier<V>) {
+        //de Casteljau
+        let a = self.start.lerp(self.control1, t);
+        let b = self.control1.lerp(self.control2, t);
+        let c = self.control2.lerp(self.end, t);
+        let ab = a.lerp(b, t);
+        let bc = b.lerp(c, t);
+        let middle = ab.lerp(bc, t);
+
+        (CubicBezier::new(self.start, a, ab, middle), CubicBezier::new(middle, bc, c, self.end))
+    }
+
+    ///Returns the smallest box containing the curve as (min, max).
+    /// Unlike the box around the control points this does not contain any empty space.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::curves::CubicBezier;
+    /// let curve = CubicBezier::new(Vec2::new(0.0, 0.0), Vec2::new(0.0, 4.0), Vec2::new(4.0, 4.0), Vec2::new(4.0, 0.0));
+    ///
+    /// assert_eq!((Vec2::new(0.0, 0.0), Vec2::new(4.0, 3.0)), curve.bounds());
+    /// ```
+    pub fn bounds(&self) -> (V, V) {
+        let mut min = self.start.min(self.end);
+        let mut max = self.start.max(self.end);
+        let d0 = self.control1 - self.start;
+        let d1 = self.control2 - self.control1;
+        let d2 = self.end - self.control2;
+        for axis in 0..V::DIMENSIONS {
+            //the derivative of every axis is a * t^2 + b * t + c
+            let a = d0.component(axis) - 2.0 * d1.component(axis) + d2.component(axis);
+            let b = 2.0 * (d1.component(axis) - d0.component(axis));
+            let c = d0.component(axis);
+            for t in quadratic_roots(a, b, c).into_iter().flatten() {
+                if t > 0.0 && t < 1.0 {
+                    let point = self.at(t);
+                    min = min.min(point);
+                    max = max.max(point);
+                }
+            }
+        }
+
+        (min, max)
+    }
+
+    ///Returns the point of the curve closest to the given one as (t, point).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::curves::CubicBezier;
+    /// let curve = CubicBezier::new(Vec2::new(0.0, 0.0), Vec2::new(0.0, 4.0), Vec2::new(4.0, 4.0), Vec2::new(4.0, 0.0));
+    /// let (t, point) = curve.closest_point(Vec2::new(-1.0, -1.0));
+    ///
+    /// assert_eq!(0.0, t);
+    /// assert_eq!(Vec2::new(0.0, 0.0), point);
+    /// ```
+    pub fn closest_point(&self, point: V) -> (f32, V) {
+        let t = closest_t(point, |t| self.at(t), |t| self.derivative(t), |t| self.second_derivative(t));
+        (t, self.at(t))
+    }
+}
+
+//normalizes the derivative, falling back to the chord for curves without velocity at t
+fn tangent<V: Vector>(derivative: V, chord: V) -> V {
+    if derivative.magnitude_squared() != 0.0 {
+        derivative.normalized()
+    } else if chord.magnitude_squared() != 0.0 {
+        chord.normalized()
+    } else {
+        V::ZERO
+    }
+}
+
+//real roots of a * t^2 + b * t + c
+fn quadratic_roots(a: f32, b: f32, c: f32) -> [Option<f32>; 2] {
+    if a.abs() < 1e-12 {
+        if b == 0.0 {
+            return [None, None];
+        }
+        return [Some(-c / b), None];
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return [None, None];
+    }
+    let root = discriminant.sqrt();
+    [Some((-b + root) / (2.0 * a)), Some((-b - root) / (2.0 * a))]
+}
+
+//finds the parameter of the point nearest to point by sampling the curve and refining the best sample with newton's method
+fn closest_t<V: Vector>(point: V, at: impl Fn(f32) -> V, derivative: impl Fn(f32) -> V, second_derivative: impl Fn(f32) -> V) -> f32 {
+    let mut best_t = 0.0;
+    let mut best_distance = f32::INFINITY;
+    for i in 0..=CLOSEST_SAMPLES {
+        let t = i as f32 / CLOSEST_SAMPLES as f32;
+        let distance = at(t).distance_to_squared(point);
+        if distance < best_distance {
+            best_t = t;
+            best_distance = distance;
+        }
+    }
+
+    //minimize the squared distance, whose derivative is 2 * (at(t) - point) . derivative(t)
+    let mut t = best_t;
+    for _ in 0..NEWTON_ITERATIONS {
+        let offset = at(t) - point;
+        let d1 = derivative(t);
+        let slope = offset.dot(d1);
+        let curvature = d1.dot(d1) + offset.dot(second_derivative(t));
+        if curvature <= 0.0 {
+            break;
+        }
+        t = (t - slope / curvature).clamp(0.0, 1.0);
+    }
+
+    if at(t).distance_to_squared(point) < best_distance {
+        t
+    } else {
+        best_t
+    }
+}
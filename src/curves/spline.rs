@@ -0,0 +1,303 @@
+use crate::Vector;
+use crate::curves::{ArcLengthTable, QuadraticBezier, CubicBezier};
+
+
+///A curve made of one or more segments, evaluated by t from 0.0 (start) to 1.0 (end).
+/// Every segment covers an equal part of t.
+/// Implemented by the Bezier curves, CatmullRom, Hermite and BSpline.
+pub trait Spline {
+    type Vector: Vector;
+
+    ///Returns the number of segments of this curve.
+    fn segments(&self) -> usize;
+    ///Returns the point of the curve at t.
+    fn at(&self, t: f32) -> Self::Vector;
+    ///Returns the first derivative (velocity) of the curve at t.
+    fn derivative(&self, t: f32) -> Self::Vector;
+
+    ///Returns the direction of the curve at t with a magnitude of 1, or zero if the curve stands still at t.
+    fn tangent(&self, t: f32) -> Self::Vector {
+        let derivative = self.derivative(t);
+        if derivative.magnitude_squared() == 0.0 {
+            return Self::Vector::ZERO;
+        }
+
+        derivative.normalized()
+    }
+
+    ///Builds a table for sampling this curve by distance, measuring every segment with the given number of samples.
+    fn arc_length_table(self, samples_per_segment: usize) -> ArcLengthTable<Self> where Self: Sized {
+        ArcLengthTable::new(self, samples_per_segment)
+    }
+}
+
+//returns the segment containing t and the position inside of it from 0.0 to 1.0
+fn locate(t: f32, segments: usize) -> (usize, f32) {
+    let scaled = t.clamp(0.0, 1.0) * segments as f32;
+    let segment = (scaled as usize).min(segments - 1);
+    (segment, scaled - segment as f32)
+}
+
+fn hermite_point<V: Vector>(p0: V, p1: V, m0: V, m1: V, t: f32) -> V {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    p0 * (2.0 * t3 - 3.0 * t2 + 1.0) + m0 * (t3 - 2.0 * t2 + t) + p1 * (-2.0 * t3 + 3.0 * t2) + m1 * (t3 - t2)
+}
+
+fn hermite_derivative<V: Vector>(p0: V, p1: V, m0: V, m1: V, t: f32) -> V {
+    let t2 = t * t;
+    (p1 - p0) * (6.0 * t - 6.0 * t2) + m0 * (3.0 * t2 - 4.0 * t + 1.0) + m1 * (3.0 * t2 - 2.0 * t)
+}
+
+//----------Catmull-Rom----------
+
+///How the knots of a Catmull-Rom spline are spaced.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Parameterization {
+    ///Equally spaced knots. Can overshoot and form loops when points are unevenly spaced.
+    Uniform,
+    ///Knots spaced by the square root of the distance. Never forms cusps or loops inside of a segment.
+    Centripetal,
+    ///Knots spaced by the distance. Follows the points most tightly.
+    Chordal,
+}
+
+impl Parameterization {
+    ///Returns the exponent applied to the distance between points.
+    pub fn alpha(&self) -> f32 {
+        match self {
+            Parameterization::Uniform => 0.0,
+            Parameterization::Centripetal => 0.5,
+            Parameterization::Chordal => 1.0,
+        }
+    }
+}
+
+///Catmull-Rom spline passing through all of its points.
+/// Works with Vec2 and Vec3.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::curves::{CatmullRom, Parameterization, Spline};
+/// let points = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0), Vec2::new(2.0, 0.0)];
+/// let spline = CatmullRom::new(points, Parameterization::Centripetal);
+///
+/// assert_eq!(2, spline.segments());
+/// assert_eq!(Vec2::new(1.0, 1.0), spline.at(0.5));
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct CatmullRom<V: Vector> {
+    points: Vec<V>,
+    //tangents at the start and end of every segment
+    tangents: Vec<(V, V)>,
+    parameterization: Parameterization,
+}
+
+impl<V: Vector> CatmullRom<V> {
+    ///Creates a spline through the points. Panics if there are less than 2 points.
+    /// The ends are extended by mirroring the neighbouring point.
+    pub fn new(points: Vec<V>, parameterization: Parameterization) -> CatmullRom<V> {
+        assert!(points.len() >= 2, "a Catmull-Rom spline needs at least two points");
+        let alpha = parameterization.alpha();
+        let last = points.len() - 1;
+        let before = points[0] * 2.0 - points[1];
+        let after = points[last] * 2.0 - points[last - 1];
+        let point = |i: usize| match i {
+            0 => before,
+            i if i == points.len() + 1 => after,
+            i => points[i - 1],
+        };
+        let knot_interval = |a: V, b: V| {
+            let interval = a.distance_to(b).powf(alpha);
+            //duplicate points
+            if interval < 1e-6 { 1.0 } else { interval }
+        };
+
+        let tangents = (0..last).map(|i| {
+            let (p0, p1, p2, p3) = (point(i), point(i + 1), point(i + 2), point(i + 3));
+            let t01 = knot_interval(p0, p1);
+            let t12 = knot_interval(p1, p2);
+            let t23 = knot_interval(p2, p3);
+            //tangents of the non uniform spline, scaled to a segment running from 0 to 1
+            let m1 = ((p1 - p0) / t01 - (p2 - p0) / (t01 + t12) + (p2 - p1) / t12) * t12;
+            let m2 = ((p2 - p1) / t12 - (p3 - p1) / (t12 + t23) + (p3 - p2) / t23) * t12;
+            (m1, m2)
+        }).collect();
+
+        CatmullRom { points, tangents, parameterization }
+    }
+
+    ///Returns the points this spline passes through.
+    pub fn points(&self) -> &[V] {
+        &self.points
+    }
+
+    ///Returns how the knots of this spline are spaced.
+    pub fn parameterization(&self) -> Parameterization {
+        self.parameterization
+    }
+}
+
+impl<V: Vector> Spline for CatmullRom<V> {
+    type Vector = V;
+
+    fn segments(&self) -> usize {
+        self.points.len() - 1
+    }
+    fn at(&self, t: f32) -> V {
+        let (i, t) = locate(t, self.segments());
+        let (m0, m1) = self.tangents[i];
+        hermite_point(self.points[i], self.points[i + 1], m0, m1, t)
+    }
+    fn derivative(&self, t: f32) -> V {
+        let (i, t) = locate(t, self.segments());
+        let (m0, m1) = self.tangents[i];
+        hermite_derivative(self.points[i], self.points[i + 1], m0, m1, t) * self.segments() as f32
+    }
+}
+
+//----------Hermite----------
+
+///Cubic Hermite spline passing through all of its points with the given tangents.
+/// Works with Vec2 and Vec3.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::curves::{Hermite, Spline};
+/// let points = vec![Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0)];
+/// let tangents = vec![Vec2::new(0.0, 2.0), Vec2::new(0.0, -2.0)];
+/// let spline = Hermite::new(points, tangents);
+///
+/// assert_eq!(Vec2::new(0.0, 2.0), spline.derivative(0.0));
+/// assert_eq!(Vec2::new(1.0, 0.5), spline.at(0.5));
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct Hermite<V: Vector> {
+    points: Vec<V>,
+    tangents: Vec<V>,
+}
+
+impl<V: Vector> Hermite<V> {
+    ///Creates a spline through the points with one tangent per point.
+    /// A tangent is the velocity of a segment running from t = 0.0 to 1.0.
+    /// Panics if there are less than 2 points or the number of tangents doesn't match.
+    pub fn new(points: Vec<V>, tangents: Vec<V>) -> Hermite<V> {
+        assert!(points.len() >= 2, "a Hermite spline needs at least two points");
+        assert_eq!(points.len(), tangents.len(), "a Hermite spline needs one tangent per point");
+        Hermite { points, tangents }
+    }
+
+    ///Returns the points this spline passes through.
+    pub fn points(&self) -> &[V] {
+        &self.points
+    }
+
+    ///Returns the tangents at the points.
+    pub fn tangents(&self) -> &[V] {
+        &self.tangents
+    }
+}
+
+impl<V: Vector> Spline for Hermite<V> {
+    type Vector = V;
+
+    fn segments(&self) -> usize {
+        self.points.len() - 1
+    }
+    fn at(&self, t: f32) -> V {
+        let (i, t) = locate(t, self.segments());
+        hermite_point(self.points[i], self.points[i + 1], self.tangents[i], self.tangents[i + 1], t)
+    }
+    fn derivative(&self, t: f32) -> V {
+        let (i, t) = locate(t, self.segments());
+        hermite_derivative(self.points[i], self.points[i + 1], self.tangents[i], self.tangents[i + 1], t) * self.segments() as f32
+    }
+}
+
+//----------B-spline----------
+
+///Uniform cubic B-spline. Smoother than the other splines but only approaches its points instead of passing through them.
+/// Works with Vec2 and Vec3.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::curves::{BSpline, Spline};
+/// let points = vec![Vec2::new(0.0, 0.0), Vec2::new(0.0, 6.0), Vec2::new(6.0, 6.0), Vec2::new(6.0, 0.0)];
+/// let spline = BSpline::new(points);
+///
+/// assert_eq!(1, spline.segments());
+/// assert_eq!(Vec2::new(1.0, 5.0), spline.at(0.0));
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct BSpline<V: Vector> {
+    points: Vec<V>,
+}
+
+impl<V: Vector> BSpline<V> {
+    ///Creates a spline from its control points. Panics if there are less than 4 points.
+    pub fn new(points: Vec<V>) -> BSpline<V> {
+        assert!(points.len() >= 4, "a cubic B-spline needs at least four points");
+        BSpline { points }
+    }
+
+    ///Returns the control points of this spline.
+    pub fn points(&self) -> &[V] {
+        &self.points
+    }
+}
+
+impl<V: Vector> Spline for BSpline<V> {
+    type Vector = V;
+
+    fn segments(&self) -> usize {
+        self.points.len() - 3
+    }
+    fn at(&self, t: f32) -> V {
+        let (i, t) = locate(t, self.segments());
+        let p = &self.points[i..i + 4];
+        let s = 1.0 - t;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        (p[0] * (s * s * s) + p[1] * (3.0 * t3 - 6.0 * t2 + 4.0) + p[2] * (-3.0 * t3 + 3.0 * t2 + 3.0 * t + 1.0) + p[3] * t3) / 6.0
+    }
+    fn derivative(&self, t: f32) -> V {
+        let (i, t) = locate(t, self.segments());
+        let p = &self.points[i..i + 4];
+        let s = 1.0 - t;
+        let t2 = t * t;
+        (p[0] * -(s * s) + p[1] * (3.0 * t2 - 4.0 * t) + p[2] * (-3.0 * t2 + 2.0 * t + 1.0) + p[3] * t2) * (0.5 * self.segments() as f32)
+    }
+}
+
+//----------Bezier curves----------
+
+impl<V: Vector> Spline for QuadraticBezier<V> {
+    type Vector = V;
+
+    fn segments(&self) -> usize {
+        1
+    }
+    fn at(&self, t: f32) -> V {
+        QuadraticBezier::at(self, t)
+    }
+    fn derivative(&self, t: f32) -> V {
+        QuadraticBezier::derivative(self, t)
+    }
+}
+
+impl<V: Vector> Spline for CubicBezier<V> {
+    type Vector = V;
+
+    fn segments(&self) -> usize {
+        1
+    }
+    fn at(&self, t: f32) -> V {
+        CubicBezier::at(self, t)
+    }
+    fn derivative(&self, t: f32) -> V {
+        CubicBezier::derivative(self, t)
+    }
+}
@@ -0,0 +1,7 @@
+mod scalar;
+mod vec2;
+mod vec3;
+
+pub use self::scalar::Fixed;
+pub use self::vec2::FixedVec2;
+pub use self::vec3::FixedVec3;
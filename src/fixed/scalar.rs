@@ -0,0 +1,372 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+
+const FRACTION_BITS: u32 = 16;
+
+//sin(i * PI / 512) for the first quarter turn in Q16.16
+const SINE_TABLE: [i32; 257] = [
+    0, 402, 804, 1206, 1608, 2010, 2412, 2814,
+    3216, 3617, 4019, 4420, 4821, 5222, 5623, 6023,
+    6424, 6824, 7224, 7623, 8022, 8421, 8820, 9218,
+    9616, 10014, 10411, 10808, 11204, 11600, 11996, 12391,
+    12785, 13180, 13573, 13966, 14359, 14751, 15143, 15534,
+    15924, 16314, 16703, 17091, 17479, 17867, 18253, 18639,
+    19024, 19409, 19792, 20175, 20557, 20939, 21320, 21699,
+    22078, 22457, 22834, 23210, 23586, 23961, 24335, 24708,
+    25080, 25451, 25821, 26190, 26558, 26925, 27291, 27656,
+    28020, 28383, 28745, 29106, 29466, 29824, 30182, 30538,
+    30893, 31248, 31600, 31952, 32303, 32652, 33000, 33347,
+    33692, 34037, 34380, 34721, 35062, 35401, 35738, 36075,
+    36410, 36744, 37076, 37407, 37736, 38064, 38391, 38716,
+    39040, 39362, 39683, 40002, 40320, 40636, 40951, 41264,
+    41576, 41886, 42194, 42501, 42806, 43110, 43412, 43713,
+    44011, 44308, 44604, 44898, 45190, 45480, 45769, 46056,
+    46341, 46624, 46906, 47186, 47464, 47741, 48015, 48288,
+    48559, 48828, 49095, 49361, 49624, 49886, 50146, 50404,
+    50660, 50914, 51166, 51417, 51665, 51911, 52156, 52398,
+    52639, 52878, 53114, 53349, 53581, 53812, 54040, 54267,
+    54491, 54714, 54934, 55152, 55368, 55582, 55794, 56004,
+    56212, 56418, 56621, 56823, 57022, 57219, 57414, 57607,
+    57798, 57986, 58172, 58356, 58538, 58718, 58896, 59071,
+    59244, 59415, 59583, 59750, 59914, 60075, 60235, 60392,
+    60547, 60700, 60851, 60999, 61145, 61288, 61429, 61568,
+    61705, 61839, 61971, 62101, 62228, 62353, 62476, 62596,
+    62714, 62830, 62943, 63054, 63162, 63268, 63372, 63473,
+    63572, 63668, 63763, 63854, 63944, 64031, 64115, 64197,
+    64277, 64354, 64429, 64501, 64571, 64639, 64704, 64766,
+    64827, 64884, 64940, 64993, 65043, 65091, 65137, 65180,
+    65220, 65259, 65294, 65328, 65358, 65387, 65413, 65436,
+    65457, 65476, 65492, 65505, 65516, 65525, 65531, 65535,
+    65536,
+];
+
+//atan(i / 256) for ratios from 0 to 1 in Q16.16
+const ARCTANGENT_TABLE: [i32; 257] = [
+    0, 256, 512, 768, 1024, 1280, 1536, 1792,
+    2047, 2303, 2559, 2814, 3070, 3325, 3580, 3836,
+    4091, 4346, 4600, 4855, 5110, 5364, 5618, 5872,
+    6126, 6380, 6633, 6887, 7140, 7392, 7645, 7898,
+    8150, 8402, 8653, 8905, 9156, 9407, 9657, 9908,
+    10158, 10408, 10657, 10906, 11155, 11403, 11652, 11899,
+    12147, 12394, 12641, 12887, 13133, 13379, 13624, 13869,
+    14114, 14358, 14601, 14845, 15088, 15330, 15572, 15814,
+    16055, 16296, 16536, 16776, 17015, 17254, 17492, 17730,
+    17968, 18205, 18441, 18677, 18913, 19148, 19382, 19616,
+    19850, 20083, 20315, 20547, 20779, 21009, 21240, 21469,
+    21699, 21927, 22156, 22383, 22610, 22836, 23062, 23288,
+    23512, 23737, 23960, 24183, 24406, 24627, 24849, 25069,
+    25289, 25509, 25727, 25946, 26163, 26380, 26597, 26813,
+    27028, 27242, 27456, 27670, 27882, 28094, 28306, 28517,
+    28727, 28936, 29145, 29354, 29561, 29768, 29975, 30180,
+    30386, 30590, 30794, 30997, 31200, 31402, 31603, 31803,
+    32003, 32203, 32401, 32600, 32797, 32994, 33190, 33385,
+    33580, 33774, 33968, 34160, 34353, 34544, 34735, 34925,
+    35115, 35304, 35492, 35680, 35867, 36053, 36239, 36424,
+    36608, 36792, 36975, 37158, 37340, 37521, 37701, 37881,
+    38060, 38239, 38417, 38594, 38771, 38947, 39123, 39297,
+    39472, 39645, 39818, 39990, 40162, 40333, 40503, 40673,
+    40842, 41010, 41178, 41346, 41512, 41678, 41844, 42008,
+    42172, 42336, 42499, 42661, 42823, 42984, 43145, 43304,
+    43464, 43622, 43780, 43938, 44095, 44251, 44407, 44562,
+    44716, 44870, 45024, 45176, 45328, 45480, 45631, 45781,
+    45931, 46080, 46229, 46377, 46525, 46672, 46818, 46964,
+    47109, 47254, 47398, 47542, 47685, 47827, 47969, 48111,
+    48251, 48392, 48531, 48671, 48809, 48947, 49085, 49222,
+    49359, 49495, 49630, 49765, 49899, 50033, 50167, 50299,
+    50432, 50563, 50695, 50826, 50956, 51086, 51215, 51344,
+    51472,
+];
+
+///Deterministic fixed point number in Q16.16 format: 16 integer bits including the sign and 16 fraction bits.
+/// All operations only use integer math, so they give the same result on every platform and compiler.
+/// The range is -32768 up to just below 32768 with a resolution of 1 / 65536.
+/// Every operation panics if its result is out of range, in debug and release builds alike, so all peers of a lockstep game fail the same way.
+/// Multiplication and division round to the nearest value, halves up.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::fixed::Fixed;
+/// let a = Fixed::from_f32(1.5);
+/// let b = Fixed::from_int(2);
+///
+/// assert_eq!(Fixed::from_int(3), a * b);
+/// assert_eq!(Fixed::from_f32(0.75), a / b);
+/// assert_eq!(1.5, a.to_f32());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy, Default)]
+pub struct Fixed(i32);
+
+impl Fixed {
+    ///Short for Fixed::from_int(0)
+    pub const ZERO: Fixed = Fixed(0);
+    ///Short for Fixed::from_int(1)
+    pub const ONE: Fixed = Fixed(1 << FRACTION_BITS);
+    ///The smallest positive value, 1 / 65536
+    pub const EPSILON: Fixed = Fixed(1);
+    pub const MIN: Fixed = Fixed(i32::MIN);
+    pub const MAX: Fixed = Fixed(i32::MAX);
+    ///Half a turn in radians
+    pub const PI: Fixed = Fixed(205887);
+    ///A full turn in radians
+    pub const TAU: Fixed = Fixed(411775);
+    ///A quarter turn in radians
+    pub const FRAC_PI_2: Fixed = Fixed(102944);
+
+
+    ///Creates a number from an integer. Panics if it is out of range.
+    pub const fn from_int(value: i32) -> Fixed {
+        assert!(value >= -(1 << 15) && value < (1 << 15), "integer out of fixed point range");
+        Fixed(value << FRACTION_BITS)
+    }
+
+    ///Creates a number from its raw Q16.16 representation.
+    pub const fn from_bits(bits: i32) -> Fixed {
+        Fixed(bits)
+    }
+
+    ///Converts a float to the closest fixed point number. Out of range values saturate and NaN becomes 0.
+    /// The conversion is exact arithmetic on the float, so it is deterministic as well.
+    pub fn from_f32(value: f32) -> Fixed {
+        //scaling by a power of 2 is exact
+        Fixed((value * (1 << FRACTION_BITS) as f32).round() as i32)
+    }
+
+
+    //----------Other functionality----------
+
+    ///Returns the raw Q16.16 representation.
+    pub const fn to_bits(&self) -> i32 {
+        self.0
+    }
+
+    ///Converts to the closest float.
+    pub fn to_f32(&self) -> f32 {
+        self.0 as f32 / (1 << FRACTION_BITS) as f32
+    }
+
+    ///Returns the integer part, rounded towards negative infinity.
+    pub const fn to_int(&self) -> i32 {
+        self.0 >> FRACTION_BITS
+    }
+
+    ///Returns the absolute value. Panics for MIN, whose absolute value is out of range.
+    pub const fn abs(&self) -> Fixed {
+        match self.0.checked_abs() {
+            Some(abs) => Fixed(abs),
+            None => panic!("fixed point abs overflowed"),
+        }
+    }
+
+    ///Returns the largest integer less than or equal to this number.
+    pub const fn floor(&self) -> Fixed {
+        Fixed(self.0 & !((1 << FRACTION_BITS) - 1))
+    }
+
+    ///Returns the smallest integer greater than or equal to this number. Panics above 32767.
+    pub const fn ceil(&self) -> Fixed {
+        match self.0.checked_add((1 << FRACTION_BITS) - 1) {
+            Some(raised) => Fixed(raised).floor(),
+            None => panic!("fixed point ceil overflowed"),
+        }
+    }
+
+    ///Returns the closest integer, rounding halves up.
+    pub const fn round(&self) -> Fixed {
+        match self.0.checked_add(1 << (FRACTION_BITS - 1)) {
+            Some(raised) => Fixed(raised).floor(),
+            None => panic!("fixed point round overflowed"),
+        }
+    }
+
+    ///Returns the square root, or 0 for negative numbers.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::fixed::Fixed;
+    ///
+    /// assert_eq!(Fixed::from_int(3), Fixed::from_int(9).sqrt());
+    /// assert_eq!(Fixed::from_f32(0.5), Fixed::from_f32(0.25).sqrt());
+    /// ```
+    pub fn sqrt(&self) -> Fixed {
+        if self.0 <= 0 {
+            return Fixed::ZERO;
+        }
+        //the root of a number with 32 fraction bits has 16
+        Fixed(isqrt((self.0 as u64) << FRACTION_BITS) as i32)
+    }
+
+    ///Returns the sine of this angle in radians, from a lookup table with linear interpolation.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::fixed::Fixed;
+    ///
+    /// assert_eq!(Fixed::ONE, Fixed::FRAC_PI_2.sin());
+    /// assert!((Fixed::from_f32(0.5).sin().to_f32() - 0.5f32.sin()).abs() < 1e-4);
+    /// ```
+    pub fn sin(&self) -> Fixed {
+        sin_bits(self.0 as i64)
+    }
+
+    ///Returns the cosine of this angle in radians, from a lookup table with linear interpolation.
+    pub fn cos(&self) -> Fixed {
+        //shifted in i64, so angles close to MAX don't overflow
+        sin_bits(self.0 as i64 + Fixed::FRAC_PI_2.0 as i64)
+    }
+
+    ///Returns the sine and cosine of this angle.
+    pub fn sin_cos(&self) -> (Fixed, Fixed) {
+        (self.sin(), self.cos())
+    }
+
+    ///Returns the angle of the point (x, self) in radians between -PI and PI, like f32::atan2, from a lookup table with linear interpolation.
+    /// Returns 0 if both are 0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::fixed::Fixed;
+    ///
+    /// assert_eq!(Fixed::FRAC_PI_2, Fixed::ONE.atan2(Fixed::ZERO));
+    /// assert!((Fixed::from_int(-1).atan2(Fixed::from_int(-2)).to_f32() - (-1f32).atan2(-2.0)).abs() < 1e-4);
+    /// ```
+    pub fn atan2(&self, x: Fixed) -> Fixed {
+        let (y, x) = (self.0 as i64, x.0 as i64);
+        let (small, large) = (y.abs().min(x.abs()), y.abs().max(x.abs()));
+        if large == 0 {
+            return Fixed::ZERO;
+        }
+
+        //the ratio of the smaller to the larger part is at most 1, so the table only covers the first eighth turn
+        let ratio = (small << 24) / large;
+        let step = (ratio >> 16).min(255);
+        let fraction = ratio - (step << 16);
+        let (from, to) = (ARCTANGENT_TABLE[step as usize], ARCTANGENT_TABLE[step as usize + 1]);
+        let mut angle = from + (((to - from) as i64 * fraction + (1 << (FRACTION_BITS - 1))) >> FRACTION_BITS) as i32;
+
+        //mirror the eighth turn into the right octant
+        if y.abs() > x.abs() {
+            angle = Fixed::FRAC_PI_2.0 - angle;
+        }
+        if x < 0 {
+            angle = Fixed::PI.0 - angle;
+        }
+        Fixed(if y < 0 { -angle } else { angle })
+    }
+}
+
+//sine of an angle given as raw Q16.16 bits
+fn sin_bits(angle: i64) -> Fixed {
+    //position on the circle in 1024 table steps with 16 fraction bits
+    let angle = angle.rem_euclid(Fixed::TAU.0 as i64);
+    let position = ((angle << (10 + FRACTION_BITS)) / Fixed::TAU.0 as i64) as i32;
+    let step = (position >> FRACTION_BITS) as usize;
+    let fraction = (position & ((1 << FRACTION_BITS) - 1)) as i64;
+
+    let (quadrant, index) = (step / 256, step % 256);
+    //the second quarter mirrors the first one
+    let (from, to) = match quadrant % 2 {
+        0 => (SINE_TABLE[index], SINE_TABLE[index + 1]),
+        _ => (SINE_TABLE[256 - index], SINE_TABLE[255 - index]),
+    };
+    let value = from + (((to - from) as i64 * fraction + (1 << (FRACTION_BITS - 1))) >> FRACTION_BITS) as i32;
+
+    //the second half turn is the first one upside down
+    Fixed(if quadrant < 2 { value } else { -value })
+}
+
+//quotient of two raw values with 16 fraction bits, rounded to the nearest value with halves up like multiplication
+pub(crate) fn div_bits(numerator: i64, divisor: i64) -> i64 {
+    let numerator = numerator << FRACTION_BITS;
+    let (numerator, divisor) = if divisor < 0 { (-numerator, -divisor) } else { (numerator, divisor) };
+    (2 * numerator + divisor).div_euclid(2 * divisor)
+}
+
+//bitwise integer square root, rounded down
+pub(crate) fn isqrt(value: u64) -> u64 {
+    let mut rest = value;
+    let mut root = 0;
+    let mut bit = 1u64 << 62;
+    while bit > value {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if rest >= root + bit {
+            rest -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
+
+//----------Operator overloading----------
+impl Add for Fixed {
+    type Output = Fixed;
+    fn add(self, rhs: Self) -> Self::Output {
+        Fixed(self.0.checked_add(rhs.0).expect("fixed point addition overflowed"))
+    }
+}
+
+impl AddAssign<Fixed> for Fixed {
+    fn add_assign(&mut self, rhs: Fixed) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Fixed(self.0.checked_sub(rhs.0).expect("fixed point subtraction overflowed"))
+    }
+}
+
+impl SubAssign<Fixed> for Fixed {
+    fn sub_assign(&mut self, rhs: Fixed) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+    fn mul(self, rhs: Self) -> Self::Output {
+        //rounds to the nearest value
+        let product = (self.0 as i64 * rhs.0 as i64 + (1 << (FRACTION_BITS - 1))) >> FRACTION_BITS;
+        Fixed(i32::try_from(product).expect("fixed point multiplication overflowed"))
+    }
+}
+
+impl MulAssign<Fixed> for Fixed {
+    fn mul_assign(&mut self, rhs: Fixed) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Fixed {
+    type Output = Fixed;
+    fn div(self, rhs: Self) -> Self::Output {
+        let quotient = div_bits(self.0 as i64, rhs.0 as i64);
+        Fixed(i32::try_from(quotient).expect("fixed point division overflowed"))
+    }
+}
+
+impl DivAssign<Fixed> for Fixed {
+    fn div_assign(&mut self, rhs: Fixed) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+    fn neg(self) -> Self::Output {
+        Fixed(self.0.checked_neg().expect("fixed point negation overflowed"))
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_f32())
+    }
+}
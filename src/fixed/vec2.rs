@@ -0,0 +1,295 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+use crate::Vec2;
+use crate::fixed::Fixed;
+use crate::fixed::scalar::{isqrt, div_bits};
+
+
+///2D fixed point vector for deterministic simulations, like lockstep multiplayer.
+/// Mirrors Vec2, but gives bit identical results everywhere. Convert to Vec2 for rendering.
+/// Not mirrored are angle_to, the polar coordinates and the Dir2 based project_onto and reflect, since they need float angle and direction types.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::fixed::{Fixed, FixedVec2};
+/// let vec = FixedVec2::new(Fixed::from_int(3), Fixed::from_int(4));
+///
+/// assert_eq!(Fixed::from_int(5), vec.magnitude());
+/// assert_eq!(FixedVec2::from_vec2(vec.to_vec2()), vec);
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+pub struct FixedVec2 {
+    pub x: Fixed,
+    pub y: Fixed,
+}
+
+impl FixedVec2 {
+    ///Short for FixedVec2::from(Vec2::DOWN)
+    pub const DOWN: FixedVec2 = FixedVec2 { x: Fixed::from_int(0), y: Fixed::from_int(-1) };
+    ///Short for FixedVec2::from(Vec2::UP)
+    pub const UP: FixedVec2 = FixedVec2 { x: Fixed::from_int(0), y: Fixed::from_int(1) };
+    ///Short for FixedVec2::from(Vec2::RIGHT)
+    pub const RIGHT: FixedVec2 = FixedVec2 { x: Fixed::from_int(1), y: Fixed::from_int(0) };
+    ///Short for FixedVec2::from(Vec2::LEFT)
+    pub const LEFT: FixedVec2 = FixedVec2 { x: Fixed::from_int(-1), y: Fixed::from_int(0) };
+    ///Short for FixedVec2::from(Vec2::ONE)
+    pub const ONE: FixedVec2 = FixedVec2 { x: Fixed::from_int(1), y: Fixed::from_int(1) };
+    ///Short for FixedVec2::from(Vec2::ZERO)
+    pub const ZERO: FixedVec2 = FixedVec2 { x: Fixed::from_int(0), y: Fixed::from_int(0) };
+
+
+    pub fn new(x: Fixed, y: Fixed) -> FixedVec2 {
+        FixedVec2 { x, y }
+    }
+
+    ///Converts a Vec2 to the closest fixed point vector. Deterministic on every platform.
+    pub fn from_vec2(vec: Vec2) -> FixedVec2 {
+        FixedVec2 { x: Fixed::from_f32(vec.x), y: Fixed::from_f32(vec.y) }
+    }
+
+
+    //----------Other functionality----------
+
+    ///Sets the values of this vector.
+    pub fn set(&mut self, x: Fixed, y: Fixed) {
+        self.x = x;
+        self.y = y;
+    }
+
+    ///Converts this vector to the closest Vec2.
+    pub fn to_vec2(&self) -> Vec2 {
+        Vec2::new(self.x.to_f32(), self.y.to_f32())
+    }
+
+    ///Returns the length of this vector, from an integer square root. Panics if it is out of range.
+    pub fn magnitude(&self) -> Fixed {
+        i32::try_from(self.magnitude_bits()).map(Fixed::from_bits).expect("fixed point magnitude overflowed")
+    }
+
+    //raw magnitude with 16 fraction bits, the sum of the squared raw values can't overflow a u64
+    fn magnitude_bits(&self) -> u64 {
+        isqrt(square(self.x) + square(self.y))
+    }
+
+    ///Returns the squared length of this vector.
+    pub fn magnitude_squared(&self) -> Fixed {
+        self.dot(*self)
+    }
+
+    ///Returns true if every component differs from the one of another vector by at most epsilon.
+    pub fn equals(&self, other: FixedVec2, epsilon: Fixed) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+
+    ///Returns this vector with a magnitude of 1, or ZERO if it has no length.
+    pub fn normalized(&self) -> FixedVec2 {
+        //divides by the raw magnitude, which also works for vectors longer than Fixed::MAX
+        let magnitude = self.magnitude_bits() as i64;
+        //avoid division by 0
+        if magnitude == 0 {
+            return FixedVec2::ZERO;
+        }
+        let unit = |value: Fixed| Fixed::from_bits(div_bits(value.to_bits() as i64, magnitude) as i32);
+        FixedVec2 { x: unit(self.x), y: unit(self.y) }
+    }
+
+    ///Returns the distance between this vector and another one.
+    pub fn distance_to(&self, other: FixedVec2) -> Fixed {
+        (other - *self).magnitude()
+    }
+
+    ///Returns the squared distance between this vector and another one.
+    pub fn distance_to_squared(&self, other: FixedVec2) -> Fixed {
+        (other - *self).magnitude_squared()
+    }
+
+    ///Returns the dot product, rounded once at the end.
+    pub fn dot(&self, other: FixedVec2) -> Fixed {
+        let sum = self.x.to_bits() as i64 * other.x.to_bits() as i64 + self.y.to_bits() as i64 * other.y.to_bits() as i64;
+        Fixed::from_bits(i32::try_from((sum + (1 << 15)) >> 16).expect("fixed point dot product overflowed"))
+    }
+
+    ///Returns the z component of the cross product of the two vectors extended to 3D.
+    pub fn cross(&self, other: FixedVec2) -> Fixed {
+        self.x * other.y - self.y * other.x
+    }
+
+    ///Returns the smallest components of the two vectors.
+    pub fn min(&self, other: FixedVec2) -> FixedVec2 {
+        FixedVec2 { x: self.x.min(other.x), y: self.y.min(other.y) }
+    }
+
+    ///Returns the largest components of the two vectors.
+    pub fn max(&self, other: FixedVec2) -> FixedVec2 {
+        FixedVec2 { x: self.x.max(other.x), y: self.y.max(other.y) }
+    }
+
+    ///Returns the linear interpolation by t between this and another vector. T should be between 0 and 1.
+    pub fn lerp(&self, other: FixedVec2, t: Fixed) -> FixedVec2 {
+        *self + (other - *self) * t
+    }
+
+    ///Returns the unsigned angle between this vector and another one in radians, between 0 and PI, or 0 if one of them has no length.
+    pub fn angle_between(&self, other: FixedVec2) -> Fixed {
+        //atan2 of the cross and dot product of the directions stays accurate for small and large angles, unlike acos
+        let (a, b) = (self.normalized(), other.normalized());
+        a.cross(b).abs().atan2(a.dot(b))
+    }
+
+    ///Returns this vector shortened to the given maximum magnitude, or unchanged if it is already shorter.
+    pub fn clamp_length(&self, max: Fixed) -> FixedVec2 {
+        if max >= Fixed::ZERO && self.magnitude_bits() <= max.to_bits() as u64 {
+            return *self;
+        }
+        self.normalized() * max
+    }
+
+    ///Projects a vector onto another, ZERO if the other has no length.
+    pub fn project(&self, onto: FixedVec2) -> FixedVec2 {
+        let onto_normalized = onto.normalized();
+        onto_normalized * self.dot(onto_normalized)
+    }
+
+    ///Adds the length in direction of the angle in radians to the vector, with lookup table trigonometry.
+    pub fn add_length_by_angle(&self, angle: Fixed, length: Fixed) -> FixedVec2 {
+        let (sin, cos) = angle.sin_cos();
+        FixedVec2 {
+            x: self.x + cos * length,
+            y: self.y + sin * length,
+        }
+    }
+
+    ///Rotates this vector counterclockwise by an angle in radians, with lookup table trigonometry.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::fixed::{Fixed, FixedVec2};
+    /// let vec = FixedVec2::new(Fixed::from_int(2), Fixed::ZERO);
+    ///
+    /// assert_eq!(FixedVec2::new(Fixed::ZERO, Fixed::from_int(2)), vec.rotate(Fixed::FRAC_PI_2));
+    /// ```
+    pub fn rotate(&self, angle: Fixed) -> FixedVec2 {
+        let (sin, cos) = angle.sin_cos();
+        FixedVec2 {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+        }
+    }
+}
+
+fn square(value: Fixed) -> u64 {
+    (value.to_bits() as i64 * value.to_bits() as i64) as u64
+}
+
+//----------Conversions----------
+impl From<Vec2> for FixedVec2 {
+    fn from(vec: Vec2) -> FixedVec2 {
+        FixedVec2::from_vec2(vec)
+    }
+}
+
+impl From<FixedVec2> for Vec2 {
+    fn from(vec: FixedVec2) -> Vec2 {
+        vec.to_vec2()
+    }
+}
+
+//----------Operator overloading----------
+impl Add for FixedVec2 {
+    type Output = FixedVec2;
+    fn add(self, rhs: Self) -> Self::Output {
+        FixedVec2 { x: self.x + rhs.x, y: self.y + rhs.y }
+    }
+}
+
+impl AddAssign<FixedVec2> for FixedVec2 {
+    fn add_assign(&mut self, rhs: FixedVec2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Sub for FixedVec2 {
+    type Output = FixedVec2;
+    fn sub(self, rhs: Self) -> Self::Output {
+        FixedVec2 { x: self.x - rhs.x, y: self.y - rhs.y }
+    }
+}
+
+impl SubAssign<FixedVec2> for FixedVec2 {
+    fn sub_assign(&mut self, rhs: FixedVec2) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl Mul for FixedVec2 {
+    type Output = FixedVec2;
+    fn mul(self, rhs: Self) -> Self::Output {
+        FixedVec2 { x: self.x * rhs.x, y: self.y * rhs.y }
+    }
+}
+
+impl MulAssign<FixedVec2> for FixedVec2 {
+    fn mul_assign(&mut self, rhs: FixedVec2) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+    }
+}
+
+impl Div for FixedVec2 {
+    type Output = FixedVec2;
+    fn div(self, rhs: Self) -> Self::Output {
+        FixedVec2 { x: self.x / rhs.x, y: self.y / rhs.y }
+    }
+}
+
+impl DivAssign<FixedVec2> for FixedVec2 {
+    fn div_assign(&mut self, rhs: FixedVec2) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+    }
+}
+
+impl Mul<Fixed> for FixedVec2 {
+    type Output = FixedVec2;
+    fn mul(self, rhs: Fixed) -> Self::Output {
+        FixedVec2 { x: self.x * rhs, y: self.y * rhs }
+    }
+}
+
+impl MulAssign<Fixed> for FixedVec2 {
+    fn mul_assign(&mut self, rhs: Fixed) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl Div<Fixed> for FixedVec2 {
+    type Output = FixedVec2;
+    fn div(self, rhs: Fixed) -> Self::Output {
+        FixedVec2 { x: self.x / rhs, y: self.y / rhs }
+    }
+}
+
+impl DivAssign<Fixed> for FixedVec2 {
+    fn div_assign(&mut self, rhs: Fixed) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
+impl Neg for FixedVec2 {
+    type Output = FixedVec2;
+    fn neg(self) -> Self::Output {
+        FixedVec2 { x: -self.x, y: -self.y }
+    }
+}
+
+impl fmt::Display for FixedVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
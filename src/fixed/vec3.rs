@@ -0,0 +1,285 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+use crate::Vec3;
+use crate::fixed::Fixed;
+use crate::fixed::scalar::{isqrt, div_bits};
+
+
+///3D fixed point vector for deterministic simulations, like lockstep multiplayer.
+/// Mirrors Vec3, but gives bit identical results everywhere. Convert to Vec3 for rendering.
+/// Not mirrored are angle_to, the spherical and cylindrical coordinates and the Dir3 based project_onto and reflect, since they need float angle and direction types.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec3;
+/// use gamevecs::fixed::{Fixed, FixedVec3};
+/// let vec = FixedVec3::new(Fixed::from_int(2), Fixed::from_int(3), Fixed::from_int(6));
+///
+/// assert_eq!(Fixed::from_int(7), vec.magnitude());
+/// assert_eq!(FixedVec3::from_vec3(vec.to_vec3()), vec);
+/// ```
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+pub struct FixedVec3 {
+    pub x: Fixed,
+    pub y: Fixed,
+    pub z: Fixed,
+}
+
+impl FixedVec3 {
+    ///Short for FixedVec3::from(Vec3::DOWN)
+    pub const DOWN: FixedVec3 = FixedVec3 { x: Fixed::from_int(0), y: Fixed::from_int(-1), z: Fixed::from_int(0) };
+    ///Short for FixedVec3::from(Vec3::UP)
+    pub const UP: FixedVec3 = FixedVec3 { x: Fixed::from_int(0), y: Fixed::from_int(1), z: Fixed::from_int(0) };
+    ///Short for FixedVec3::from(Vec3::RIGHT)
+    pub const RIGHT: FixedVec3 = FixedVec3 { x: Fixed::from_int(1), y: Fixed::from_int(0), z: Fixed::from_int(0) };
+    ///Short for FixedVec3::from(Vec3::LEFT)
+    pub const LEFT: FixedVec3 = FixedVec3 { x: Fixed::from_int(-1), y: Fixed::from_int(0), z: Fixed::from_int(0) };
+    ///Short for FixedVec3::from(Vec3::FORWARD)
+    pub const FORWARD: FixedVec3 = FixedVec3 { x: Fixed::from_int(0), y: Fixed::from_int(0), z: Fixed::from_int(1) };
+    ///Short for FixedVec3::from(Vec3::BACK)
+    pub const BACK: FixedVec3 = FixedVec3 { x: Fixed::from_int(0), y: Fixed::from_int(0), z: Fixed::from_int(-1) };
+    ///Short for FixedVec3::from(Vec3::ONE)
+    pub const ONE: FixedVec3 = FixedVec3 { x: Fixed::from_int(1), y: Fixed::from_int(1), z: Fixed::from_int(1) };
+    ///Short for FixedVec3::from(Vec3::ZERO)
+    pub const ZERO: FixedVec3 = FixedVec3 { x: Fixed::from_int(0), y: Fixed::from_int(0), z: Fixed::from_int(0) };
+
+
+    pub fn new(x: Fixed, y: Fixed, z: Fixed) -> FixedVec3 {
+        FixedVec3 { x, y, z }
+    }
+
+    ///Converts a Vec3 to the closest fixed point vector. Deterministic on every platform.
+    pub fn from_vec3(vec: Vec3) -> FixedVec3 {
+        FixedVec3 { x: Fixed::from_f32(vec.x), y: Fixed::from_f32(vec.y), z: Fixed::from_f32(vec.z) }
+    }
+
+
+    //----------Other functionality----------
+
+    ///Sets the values of this vector.
+    pub fn set(&mut self, x: Fixed, y: Fixed, z: Fixed) {
+        self.x = x;
+        self.y = y;
+        self.z = z;
+    }
+
+    ///Converts this vector to the closest Vec3.
+    pub fn to_vec3(&self) -> Vec3 {
+        Vec3::new(self.x.to_f32(), self.y.to_f32(), self.z.to_f32())
+    }
+
+    ///Returns the length of this vector, from an integer square root. Panics if it is out of range.
+    pub fn magnitude(&self) -> Fixed {
+        i32::try_from(self.magnitude_bits()).map(Fixed::from_bits).expect("fixed point magnitude overflowed")
+    }
+
+    //raw magnitude with 16 fraction bits, the sum of the squared raw values can't overflow a u64
+    fn magnitude_bits(&self) -> u64 {
+        isqrt(square(self.x) + square(self.y) + square(self.z))
+    }
+
+    ///Returns the squared length of this vector.
+    pub fn magnitude_squared(&self) -> Fixed {
+        self.dot(*self)
+    }
+
+    ///Returns true if every component differs from the one of another vector by at most epsilon.
+    pub fn equals(&self, other: FixedVec3, epsilon: Fixed) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon && (self.z - other.z).abs() <= epsilon
+    }
+
+    ///Returns this vector with a magnitude of 1, or ZERO if it has no length.
+    pub fn normalized(&self) -> FixedVec3 {
+        //divides by the raw magnitude, which also works for vectors longer than Fixed::MAX
+        let magnitude = self.magnitude_bits() as i64;
+        //avoid division by 0
+        if magnitude == 0 {
+            return FixedVec3::ZERO;
+        }
+        let unit = |value: Fixed| Fixed::from_bits(div_bits(value.to_bits() as i64, magnitude) as i32);
+        FixedVec3 { x: unit(self.x), y: unit(self.y), z: unit(self.z) }
+    }
+
+    ///Returns the distance between this vector and another one.
+    pub fn distance_to(&self, other: FixedVec3) -> Fixed {
+        (other - *self).magnitude()
+    }
+
+    ///Returns the squared distance between this vector and another one.
+    pub fn distance_to_squared(&self, other: FixedVec3) -> Fixed {
+        (other - *self).magnitude_squared()
+    }
+
+    ///Returns the dot product, rounded once at the end.
+    pub fn dot(&self, other: FixedVec3) -> Fixed {
+        let sum = self.x.to_bits() as i64 * other.x.to_bits() as i64 + self.y.to_bits() as i64 * other.y.to_bits() as i64 + self.z.to_bits() as i64 * other.z.to_bits() as i64;
+        Fixed::from_bits(i32::try_from((sum + (1 << 15)) >> 16).expect("fixed point dot product overflowed"))
+    }
+
+    ///Returns the cross product of the two vectors.
+    pub fn cross(&self, other: FixedVec3) -> FixedVec3 {
+        FixedVec3 {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    ///Returns the smallest components of the two vectors.
+    pub fn min(&self, other: FixedVec3) -> FixedVec3 {
+        FixedVec3 { x: self.x.min(other.x), y: self.y.min(other.y), z: self.z.min(other.z) }
+    }
+
+    ///Returns the largest components of the two vectors.
+    pub fn max(&self, other: FixedVec3) -> FixedVec3 {
+        FixedVec3 { x: self.x.max(other.x), y: self.y.max(other.y), z: self.z.max(other.z) }
+    }
+
+    ///Returns the linear interpolation by t between this and another vector. T should be between 0 and 1.
+    pub fn lerp(&self, other: FixedVec3, t: Fixed) -> FixedVec3 {
+        *self + (other - *self) * t
+    }
+
+    ///Returns the unsigned angle between this vector and another one in radians, between 0 and PI, or 0 if one of them has no length.
+    pub fn angle_between(&self, other: FixedVec3) -> Fixed {
+        //atan2 of the cross and dot product of the directions stays accurate for small and large angles, unlike acos
+        let (a, b) = (self.normalized(), other.normalized());
+        a.cross(b).magnitude().atan2(a.dot(b))
+    }
+
+    ///Returns this vector shortened to the given maximum magnitude, or unchanged if it is already shorter.
+    pub fn clamp_length(&self, max: Fixed) -> FixedVec3 {
+        if max >= Fixed::ZERO && self.magnitude_bits() <= max.to_bits() as u64 {
+            return *self;
+        }
+        self.normalized() * max
+    }
+
+    ///Projects a vector onto another, ZERO if the other has no length.
+    pub fn project(&self, onto: FixedVec3) -> FixedVec3 {
+        let onto_normalized = onto.normalized();
+        onto_normalized * self.dot(onto_normalized)
+    }
+}
+
+fn square(value: Fixed) -> u64 {
+    (value.to_bits() as i64 * value.to_bits() as i64) as u64
+}
+
+//----------Conversions----------
+impl From<Vec3> for FixedVec3 {
+    fn from(vec: Vec3) -> FixedVec3 {
+        FixedVec3::from_vec3(vec)
+    }
+}
+
+impl From<FixedVec3> for Vec3 {
+    fn from(vec: FixedVec3) -> Vec3 {
+        vec.to_vec3()
+    }
+}
+
+//----------Operator overloading----------
+impl Add for FixedVec3 {
+    type Output = FixedVec3;
+    fn add(self, rhs: Self) -> Self::Output {
+        FixedVec3 { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+    }
+}
+
+impl AddAssign<FixedVec3> for FixedVec3 {
+    fn add_assign(&mut self, rhs: FixedVec3) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl Sub for FixedVec3 {
+    type Output = FixedVec3;
+    fn sub(self, rhs: Self) -> Self::Output {
+        FixedVec3 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+    }
+}
+
+impl SubAssign<FixedVec3> for FixedVec3 {
+    fn sub_assign(&mut self, rhs: FixedVec3) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl Mul for FixedVec3 {
+    type Output = FixedVec3;
+    fn mul(self, rhs: Self) -> Self::Output {
+        FixedVec3 { x: self.x * rhs.x, y: self.y * rhs.y, z: self.z * rhs.z }
+    }
+}
+
+impl MulAssign<FixedVec3> for FixedVec3 {
+    fn mul_assign(&mut self, rhs: FixedVec3) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+    }
+}
+
+impl Div for FixedVec3 {
+    type Output = FixedVec3;
+    fn div(self, rhs: Self) -> Self::Output {
+        FixedVec3 { x: self.x / rhs.x, y: self.y / rhs.y, z: self.z / rhs.z }
+    }
+}
+
+impl DivAssign<FixedVec3> for FixedVec3 {
+    fn div_assign(&mut self, rhs: FixedVec3) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+    }
+}
+
+impl Mul<Fixed> for FixedVec3 {
+    type Output = FixedVec3;
+    fn mul(self, rhs: Fixed) -> Self::Output {
+        FixedVec3 { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs }
+    }
+}
+
+impl MulAssign<Fixed> for FixedVec3 {
+    fn mul_assign(&mut self, rhs: Fixed) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+impl Div<Fixed> for FixedVec3 {
+    type Output = FixedVec3;
+    fn div(self, rhs: Fixed) -> Self::Output {
+        FixedVec3 { x: self.x / rhs, y: self.y / rhs, z: self.z / rhs }
+    }
+}
+
+impl DivAssign<Fixed> for FixedVec3 {
+    fn div_assign(&mut self, rhs: Fixed) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+impl Neg for FixedVec3 {
+    type Output = FixedVec3;
+    fn neg(self) -> Self::Output {
+        FixedVec3 { x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+impl fmt::Display for FixedVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
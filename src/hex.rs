@@ -0,0 +1,5 @@
+mod coord;
+mod layout;
+
+pub use self::coord::Hex;
+pub use self::layout::{HexOrientation, HexLayout};
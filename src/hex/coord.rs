@@ -0,0 +1,251 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Neg};
+
+use crate::Vec2;
+
+
+///Cell of a hexagonal grid in axial coordinates.
+/// The cube form adds the third coordinate s so that q + r + s is always 0.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    ///Short for Hex::new(0, 0)
+    pub const ZERO: Hex = Hex { q: 0, r: 0 };
+    ///Offsets to the 6 neighbours, counter clockwise with y pointing up, starting at +q.
+    pub const DIRECTIONS: [Hex; 6] = [
+        Hex { q: 1, r: 0 }, Hex { q: 0, r: 1 }, Hex { q: -1, r: 1 },
+        Hex { q: -1, r: 0 }, Hex { q: 0, r: -1 }, Hex { q: 1, r: -1 },
+    ];
+    ///Offsets to the 6 hexes touching only at a corner, counter clockwise starting between the first two directions.
+    pub const DIAGONALS: [Hex; 6] = [
+        Hex { q: 1, r: 1 }, Hex { q: -1, r: 2 }, Hex { q: -2, r: 1 },
+        Hex { q: -1, r: -1 }, Hex { q: 1, r: -2 }, Hex { q: 2, r: -1 },
+    ];
+
+
+    pub fn new(q: i32, r: i32) -> Hex {
+        Hex { q, r }
+    }
+
+    ///Creates a hex from cube coordinates. Panics in debug builds if q + r + s isn't 0.
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Hex {
+        debug_assert!(q + r + s == 0, "cube coordinates have to add up to 0");
+        Hex { q, r }
+    }
+
+
+    //----------Other functionality----------
+
+    ///Returns the third cube coordinate.
+    pub fn s(&self) -> i32 {
+        -self.q - self.r
+    }
+
+    ///Returns the cube coordinates (q, r, s) of this hex.
+    pub fn cube(&self) -> (i32, i32, i32) {
+        (self.q, self.r, self.s())
+    }
+
+    ///Returns the hex closest to fractional axial coordinates, for example from pixel conversions or interpolation.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::hex::Hex;
+    ///
+    /// assert_eq!(Hex::new(1, 0), Hex::round(Vec2::new(0.9, 0.2)));
+    /// assert_eq!(Hex::new(1, -1), Hex::round(Vec2::new(0.6, -0.5)));
+    /// ```
+    pub fn round(axial: Vec2) -> Hex {
+        let (q, r, s) = (axial.x, axial.y, -axial.x - axial.y);
+        let (mut rounded_q, mut rounded_r, rounded_s) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rounded_q - q).abs(), (rounded_r - r).abs(), (rounded_s - s).abs());
+
+        //the coordinate that was rounded the most gets recomputed from the others to keep the sum at 0
+        if dq > dr && dq > ds {
+            rounded_q = -rounded_r - rounded_s;
+        } else if dr > ds {
+            rounded_r = -rounded_q - rounded_s;
+        }
+
+        Hex { q: rounded_q as i32, r: rounded_r as i32 }
+    }
+
+    ///Returns the axial coordinates of this hex as a Vec2.
+    pub fn to_vec2(&self) -> Vec2 {
+        Vec2::new(self.q as f32, self.r as f32)
+    }
+
+    ///Returns the number of steps from this hex to ZERO.
+    pub fn length(&self) -> i32 {
+        (self.q.abs() + self.r.abs() + self.s().abs()) / 2
+    }
+
+    ///Returns the number of steps between this hex and another.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::hex::Hex;
+    ///
+    /// assert_eq!(2, Hex::new(1, -3).distance_to(Hex::new(-1, -2)));
+    /// ```
+    pub fn distance_to(&self, other: Hex) -> i32 {
+        (*self - other).length()
+    }
+
+    ///Returns the neighbour in one of the 6 directions, counting counter clockwise from +q. The direction wraps around.
+    pub fn neighbour(&self, direction: usize) -> Hex {
+        *self + Hex::DIRECTIONS[direction % 6]
+    }
+
+    ///Returns the 6 neighbours of this hex.
+    pub fn neighbours(&self) -> [Hex; 6] {
+        Hex::DIRECTIONS.map(|direction| *self + direction)
+    }
+
+    ///Returns the 6 hexes touching this hex only at a corner.
+    pub fn diagonals(&self) -> [Hex; 6] {
+        Hex::DIAGONALS.map(|diagonal| *self + diagonal)
+    }
+
+    ///Returns this hex rotated by 60 degrees counter clockwise around ZERO.
+    pub fn rotate_left(&self) -> Hex {
+        Hex { q: -self.r, r: -self.s() }
+    }
+
+    ///Returns this hex rotated by 60 degrees clockwise around ZERO.
+    pub fn rotate_right(&self) -> Hex {
+        Hex { q: -self.s(), r: -self.q }
+    }
+
+    ///Returns this hex rotated around a center by a number of 60 degree steps, counter clockwise if positive.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::hex::Hex;
+    /// let center = Hex::new(2, 2);
+    ///
+    /// assert_eq!(Hex::new(2, 3), Hex::new(3, 2).rotate_around(center, 1));
+    /// assert_eq!(Hex::new(1, 2), Hex::new(3, 2).rotate_around(center, 3));
+    /// assert_eq!(Hex::new(3, 2), Hex::new(3, 2).rotate_around(center, -6));
+    /// ```
+    pub fn rotate_around(&self, center: Hex, steps: i32) -> Hex {
+        let mut offset = *self - center;
+        for _ in 0..steps.rem_euclid(6) {
+            offset = offset.rotate_left();
+        }
+        center + offset
+    }
+
+    ///Returns the hexes at exactly radius steps from this hex, counter clockwise.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::hex::Hex;
+    /// let ring = Hex::new(4, -2).ring(2);
+    ///
+    /// assert_eq!(12, ring.len());
+    /// assert!(ring.iter().all(|hex| hex.distance_to(Hex::new(4, -2)) == 2));
+    /// assert_eq!(vec![Hex::new(4, -2)], Hex::new(4, -2).ring(0));
+    /// ```
+    pub fn ring(&self, radius: u32) -> Vec<Hex> {
+        if radius == 0 {
+            return vec![*self];
+        }
+
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        let mut hex = *self + Hex::DIRECTIONS[4] * radius as i32;
+        for direction in Hex::DIRECTIONS {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex += direction;
+            }
+        }
+        ring
+    }
+
+    ///Returns the hexes up to radius steps from this hex, starting at this hex and going ring by ring outwards.
+    pub fn spiral(&self, radius: u32) -> Vec<Hex> {
+        (0..=radius).flat_map(|ring| self.ring(ring)).collect()
+    }
+
+    ///Returns the hexes on a straight line from this hex to another, including both.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::hex::Hex;
+    /// let line = Hex::new(0, 0).line_to(Hex::new(3, -1));
+    ///
+    /// assert_eq!(vec![Hex::new(0, 0), Hex::new(1, 0), Hex::new(2, -1), Hex::new(3, -1)], line);
+    /// ```
+    pub fn line_to(&self, other: Hex) -> Vec<Hex> {
+        let steps = self.distance_to(other);
+        //nudges points on edges between hexes to the same side
+        let start = self.to_vec2() + Vec2::new(1e-6, 2e-6);
+        let end = other.to_vec2() + Vec2::new(1e-6, 2e-6);
+        (0..=steps).map(|step| {
+            let t = if steps == 0 { 0.0 } else { step as f32 / steps as f32 };
+            Hex::round(start.lerp(end, t))
+        }).collect()
+    }
+}
+
+//----------Operator overloading----------
+impl Add for Hex {
+    type Output = Hex;
+    fn add(self, rhs: Self) -> Self::Output {
+        Hex { q: self.q + rhs.q, r: self.r + rhs.r }
+    }
+}
+
+impl AddAssign<Hex> for Hex {
+    fn add_assign(&mut self, rhs: Hex) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Hex { q: self.q - rhs.q, r: self.r - rhs.r }
+    }
+}
+
+impl SubAssign<Hex> for Hex {
+    fn sub_assign(&mut self, rhs: Hex) {
+        self.q -= rhs.q;
+        self.r -= rhs.r;
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Hex;
+    fn mul(self, rhs: i32) -> Self::Output {
+        Hex { q: self.q * rhs, r: self.r * rhs }
+    }
+}
+
+impl MulAssign<i32> for Hex {
+    fn mul_assign(&mut self, rhs: i32) {
+        self.q *= rhs;
+        self.r *= rhs;
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+    fn neg(self) -> Self::Output {
+        Hex { q: -self.q, r: -self.r }
+    }
+}
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.q, self.r, self.s())
+    }
+}
@@ -0,0 +1,97 @@
+use crate::Vec2;
+use crate::hex::Hex;
+
+
+///Which way the hexes of a layout are turned.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum HexOrientation {
+    ///Corners point along +y and -y, rows of hexes run along x.
+    PointyTop,
+    ///Edges face +y and -y, columns of hexes run along y.
+    FlatTop,
+}
+
+impl HexOrientation {
+    //maps axial coordinates to positions, as the rows of a 2x2 matrix
+    fn forward(&self) -> [[f32; 2]; 2] {
+        let root3 = 3f32.sqrt();
+        match self {
+            HexOrientation::PointyTop => [[root3, root3 / 2.0], [0.0, 1.5]],
+            HexOrientation::FlatTop => [[1.5, 0.0], [root3 / 2.0, root3]],
+        }
+    }
+
+    //angle of the first corner in units of 60 degrees
+    fn start_angle(&self) -> f32 {
+        match self {
+            HexOrientation::PointyTop => 0.5,
+            HexOrientation::FlatTop => 0.0,
+        }
+    }
+}
+
+///Maps the hexes of a grid to 2D positions and back.
+/// With y pointing up, r grows upwards. Flip the sign of size.y for screens with y pointing down.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::hex::{Hex, HexLayout, HexOrientation};
+/// let layout = HexLayout::new(HexOrientation::PointyTop, Vec2::new(10.0, 10.0), Vec2::new(100.0, 100.0));
+///
+/// let pixel = layout.to_pixel(Hex::new(2, -1));
+///
+/// assert!(pixel.equals(Vec2::new(100.0 + 1.5 * 3f32.sqrt() * 10.0, 85.0), 1e-4));
+/// assert_eq!(Hex::new(2, -1), layout.to_hex(pixel + Vec2::new(4.0, -3.0)));
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct HexLayout {
+    pub orientation: HexOrientation,
+    ///Distance from the center of a hex to its corners, along x and y.
+    pub size: Vec2,
+    ///Position of the center of Hex::ZERO.
+    pub origin: Vec2,
+}
+
+impl HexLayout {
+    pub fn new(orientation: HexOrientation, size: Vec2, origin: Vec2) -> HexLayout {
+        HexLayout { orientation, size, origin }
+    }
+
+    ///Returns the center of a hex.
+    pub fn to_pixel(&self, hex: Hex) -> Vec2 {
+        let [a, b] = self.orientation.forward();
+        let (q, r) = (hex.q as f32, hex.r as f32);
+        Vec2::new(
+            (a[0] * q + a[1] * r) * self.size.x,
+            (b[0] * q + b[1] * r) * self.size.y,
+        ) + self.origin
+    }
+
+    ///Returns the fractional axial coordinates of a position. Round them with Hex::round.
+    pub fn to_fractional(&self, pixel: Vec2) -> Vec2 {
+        let [a, b] = self.orientation.forward();
+        let point = (pixel - self.origin) / self.size;
+        //inverse of the 2x2 matrix
+        let determinant = a[0] * b[1] - a[1] * b[0];
+        Vec2::new(
+            (b[1] * point.x - a[1] * point.y) / determinant,
+            (a[0] * point.y - b[0] * point.x) / determinant,
+        )
+    }
+
+    ///Returns the hex containing a position.
+    pub fn to_hex(&self, pixel: Vec2) -> Hex {
+        Hex::round(self.to_fractional(pixel))
+    }
+
+    ///Returns the 6 corners of a hex, counter clockwise.
+    pub fn corners(&self, hex: Hex) -> [Vec2; 6] {
+        let center = self.to_pixel(hex);
+        let start = self.orientation.start_angle();
+        std::array::from_fn(|corner| {
+            let angle = (start + corner as f32) * std::f32::consts::FRAC_PI_3;
+            center + Vec2::new(angle.cos(), angle.sin()) * self.size
+        })
+    }
+}
@@ -0,0 +1,87 @@
+//!Numerical integrators that advance a particle by a time step.
+//! The acceleration callback receives the position and velocity the acceleration should be evaluated at.
+//!
+//! Semi-implicit Euler and the Verlet integrators keep the energy of oscillating systems bounded,
+//! even with large time steps. RK4 is the most accurate per step but slowly loses energy.
+//! Explicit Euler is only included for comparison, it gains energy every step and blows up.
+//!
+//! # Examples
+//! ```rust
+//! use gamevecs::Vec2;
+//! use gamevecs::integrate::{self, Particle};
+//! let gravity = Vec2::new(0.0, -9.81);
+//! let mut ball = Particle::new(Vec2::new(0.0, 10.0), Vec2::new(2.0, 0.0));
+//!
+//! for _ in 0..10 {
+//!     integrate::velocity_verlet(&mut ball, 0.1, |_, _| gravity);
+//! }
+//!
+//! //exact for constant accelerations
+//! assert!(ball.position.equals(Vec2::new(2.0, 10.0 - 0.5 * 9.81), 1e-4));
+//! ```
+
+use crate::Vector;
+
+
+///Position and velocity of a point mass. Works with Vec2 and Vec3.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Particle<V: Vector> {
+    pub position: V,
+    pub velocity: V,
+}
+
+impl<V: Vector> Particle<V> {
+    pub fn new(position: V, velocity: V) -> Particle<V> {
+        Particle { position, velocity }
+    }
+}
+
+///Updates the position with the old velocity, then the velocity. Unstable for oscillations, use semi_implicit_euler instead.
+pub fn explicit_euler<V: Vector>(particle: &mut Particle<V>, dt: f32, acceleration: impl Fn(V, V) -> V) {
+    let current = acceleration(particle.position, particle.velocity);
+    particle.position += particle.velocity * dt;
+    particle.velocity += current * dt;
+}
+
+///Updates the velocity, then the position with the new velocity.
+/// As cheap as explicit Euler but keeps the energy bounded.
+pub fn semi_implicit_euler<V: Vector>(particle: &mut Particle<V>, dt: f32, acceleration: impl Fn(V, V) -> V) {
+    particle.velocity += acceleration(particle.position, particle.velocity) * dt;
+    particle.position += particle.velocity * dt;
+}
+
+///Velocity Verlet. Second order accurate and exact for constant accelerations.
+/// Velocity dependent accelerations like drag are evaluated with a predicted velocity.
+pub fn velocity_verlet<V: Vector>(particle: &mut Particle<V>, dt: f32, acceleration: impl Fn(V, V) -> V) {
+    let current = acceleration(particle.position, particle.velocity);
+    particle.position += particle.velocity * dt + current * (0.5 * dt * dt);
+    let next = acceleration(particle.position, particle.velocity + current * dt);
+    particle.velocity += (current + next) * (0.5 * dt);
+}
+
+///Position Verlet in its drift-kick-drift form. Second order accurate
+/// and evaluates the acceleration only once per step, in the middle of it.
+pub fn position_verlet<V: Vector>(particle: &mut Particle<V>, dt: f32, acceleration: impl Fn(V, V) -> V) {
+    let half = dt * 0.5;
+    let middle = particle.position + particle.velocity * half;
+    let velocity = particle.velocity + acceleration(middle, particle.velocity) * dt;
+    particle.position = middle + velocity * half;
+    particle.velocity = velocity;
+}
+
+///Classic fourth order Runge-Kutta. The most accurate per step, at the cost of four acceleration evaluations.
+pub fn rk4<V: Vector>(particle: &mut Particle<V>, dt: f32, acceleration: impl Fn(V, V) -> V) {
+    let (x, v) = (particle.position, particle.velocity);
+    let half = dt * 0.5;
+
+    let a1 = acceleration(x, v);
+    let v2 = v + a1 * half;
+    let a2 = acceleration(x + v * half, v2);
+    let v3 = v + a2 * half;
+    let a3 = acceleration(x + v2 * half, v3);
+    let v4 = v + a3 * dt;
+    let a4 = acceleration(x + v3 * dt, v4);
+
+    particle.position += (v + (v2 + v3) * 2.0 + v4) * (dt / 6.0);
+    particle.velocity += (a1 + (a2 + a3) * 2.0 + a4) * (dt / 6.0);
+}
@@ -1,10 +1,29 @@
 mod vecs;
+pub mod shapes;
+pub mod collision;
+pub mod spatial;
+pub mod curves;
+pub mod animation;
+pub mod noise;
+pub mod sampling;
+pub mod integrate;
+pub mod steering;
+pub mod boids;
+pub mod pathfinding;
+pub mod hex;
+pub mod fixed;
+#[cfg(feature = "rand")]
+pub mod random;
 pub use crate::vecs::vec2::Vec2;
 pub use crate::vecs::vec3::Vec3;
+pub use crate::vecs::vector::Vector;
+pub use crate::vecs::angle::{Radians, Degrees};
+pub use crate::vecs::dir::{Dir2, Dir3};
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shapes::*;
 
     //---Vec2----
     #[test]
@@ -73,8 +92,35 @@ mod tests {
         let res = Vec2::new(-69.5, 3.);
         assert_eq!(res, -vec);
     }
+    #[test]
+    fn v2_mul_f32() {
+        let vec = Vec2::new(5., 6.);
+        let res = Vec2::new(10., 12.);
+        assert_eq!(res, vec * 2.);
+    }
+    #[test]
+    fn v2_div_f32() {
+        let mut vec = Vec2::new(10., 12.);
+        vec /= 2.;
+        let res = Vec2::new(5., 6.);
+        assert_eq!(res, vec);
+    }
 
 
+    #[test]
+    fn v2_polar_round_trip() {
+        for point in random_points2(100, 20.) {
+            let vec = point - Vec2::new(10., 10.);
+            let (radius, angle) = vec.to_polar();
+            assert!((-std::f32::consts::PI..=std::f32::consts::PI).contains(&angle.0));
+            assert!(Vec2::from_polar(radius, angle).equals(vec, 1e-4));
+        }
+        assert_eq!((0., Radians::ZERO), Vec2::ZERO.to_polar());
+        assert_eq!((1., Radians::PI), Vec2::LEFT.to_polar());
+        assert!(Vec2::from_polar(2., 1.).equals(Vec2::ZERO.add_length_by_angle(1., 2.), 1e-6));
+        assert!(Vec2::from_polar(1., 0.5).equals(Vec2::RIGHT.rotate(0.5), 1e-6));
+    }
+
     //---Vec3----
     #[test]
     fn v3_add() {
@@ -142,5 +188,1652 @@ mod tests {
         let res = Vec3::new(-69.5, 3., 4.);
         assert_eq!(res, -vec);
     }
+    #[test]
+    fn v3_mul_f32() {
+        let mut vec = Vec3::new(5., 6., 2.);
+        vec *= 2.;
+        let res = Vec3::new(10., 12., 4.);
+        assert_eq!(res, vec);
+    }
+    #[test]
+    fn v3_div_f32() {
+        let vec = Vec3::new(10., 12., 4.);
+        let res = Vec3::new(5., 6., 2.);
+        assert_eq!(res, vec / 2.);
+    }
+
+
+    #[test]
+    fn v3_spherical_round_trip() {
+        use std::f32::consts::{PI, FRAC_PI_2};
+        for point in random_points3(100, 20.) {
+            let vec = point - Vec3::new(10., 10., 10.);
+            let (radius, polar, azimuth) = vec.to_spherical();
+            assert!((0.0..=PI).contains(&polar.0) && (-PI..=PI).contains(&azimuth.0));
+            assert!(Vec3::from_spherical(radius, polar, azimuth).equals(vec, 1e-4));
+            let (radius, azimuth, height) = vec.to_cylindrical();
+            assert!(Vec3::from_cylindrical(radius, azimuth, height).equals(vec, 1e-4));
+        }
+
+        //the poles
+        assert_eq!((3., Radians(0.), Radians(0.)), (Vec3::UP * 3.).to_spherical());
+        assert_eq!((3., Radians(PI), Radians(0.)), (Vec3::DOWN * 3.).to_spherical());
+        assert!(Vec3::from_spherical(3., PI, 2.).equals(Vec3::DOWN * 3., 1e-6));
+        assert!(Vec3::from_spherical(3., 1e-4, 2.).equals(Vec3::UP * 3., 1e-3));
+        assert_eq!((0., Radians(0.), -2.), (Vec3::DOWN * 2.).to_cylindrical());
+        assert_eq!((3., Radians(PI), Radians(0.)), (Vec3::UP * -3.).to_spherical());
+        assert_eq!((1., Radians(PI), Radians(0.)), (-Vec3::UP).to_spherical());
+        assert_eq!((0., Radians(0.), -1.), (-Vec3::UP).to_cylindrical());
+        assert_eq!((0., Radians(0.), Radians(0.)), Vec3::ZERO.to_spherical());
+
+        //the axis constants
+        assert_eq!((1., Radians(FRAC_PI_2), Radians(0.)), Vec3::FORWARD.to_spherical());
+        assert_eq!((1., Radians(FRAC_PI_2), Radians(FRAC_PI_2)), Vec3::RIGHT.to_spherical());
+        assert_eq!((1., Radians(FRAC_PI_2), Radians(-FRAC_PI_2)), Vec3::LEFT.to_spherical());
+        assert_eq!((1., Radians(FRAC_PI_2), Radians(PI)), Vec3::BACK.to_spherical());
+    }
+
+    //---Angles----
+    #[test]
+    fn angle_wrapping() {
+        use std::f32::consts::PI;
+        for value in random_values(200) {
+            let angle = Radians((value - 0.5) * 40.);
+            let wrapped = angle.wrapped();
+            assert!(wrapped.0 > -PI && wrapped.0 <= PI);
+            assert!(angle.difference_to(wrapped).0.abs() < 1e-4);
+            assert!((angle.to_degrees().wrapped().to_radians().0 - wrapped.0).abs() < 1e-4);
+
+            let other = Radians(value * 7.);
+            let difference = angle.difference_to(other);
+            assert!(difference.0.abs() <= PI);
+            assert!((angle + difference).difference_to(other).0.abs() < 1e-4);
+        }
+        assert_eq!(Degrees(180.), Degrees(-180.).wrapped());
+        assert_eq!(Degrees(-20.), Degrees(350.).difference_to(Degrees(330.)));
+        assert_eq!(Degrees(90.), Degrees::from(Radians(PI / 2.)));
+        assert_eq!(Radians(PI), Radians::from(Degrees(180.)));
+        assert_eq!(Radians(1.5), Radians(1.) + Radians(1.) * 0.5);
+        assert_eq!(Degrees(-45.), -(Degrees(90.) - Degrees(45.)));
+    }
+    #[test]
+    fn angle_vector_apis() {
+        let vec = Vec2::new(3., 4.);
+        assert_eq!(vec.rotate(Radians::from(Degrees(30.))), vec.rotate(Degrees(30.)));
+        assert!(vec.rotate(Degrees(30.)).equals(vec.rotate(30f32.to_radians()), 1e-6));
+        assert_eq!(vec.add_length_by_angle(0.5, 2.), vec.add_length_by_angle(Radians(0.5), 2.));
+        assert!(Vec3::from_spherical(1., Degrees(90.), Degrees(90.)).equals(Vec3::RIGHT, 1e-6));
+
+        for pair in random_points2(100, 2.).chunks(2) {
+            let [a, b] = [pair[0] - Vec2::ONE, pair[1] - Vec2::ONE];
+            let angle = a.angle_to(b);
+            assert!((angle.0.abs() - a.angle_between(b)).abs() < 1e-3);
+            assert!(a.rotate(angle).normalized().equals(b.normalized(), 1e-3));
+        }
+        assert_eq!(Radians::ZERO, Vec2::ZERO.angle_to(Vec2::ONE));
+
+        for pair in random_points3(100, 2.).chunks(2) {
+            let [a, b] = [pair[0] - Vec3::ONE, pair[1] - Vec3::ONE];
+            let axis = Dir3::new(a.cross(b)).unwrap();
+            assert!((a.angle_to(b, axis).0 - a.angle_between(b)).abs() < 1e-6);
+            assert!((b.angle_to(a, axis).0 + a.angle_between(b)).abs() < 1e-6);
+        }
+        assert_eq!(Radians::ZERO, Vec3::ZERO.angle_to(Vec3::ONE, Dir3::UP));
+    }
+
+    //---Directions----
+    #[test]
+    fn dir_normalization() {
+        for point in random_points3(100, 2.) {
+            let vec = point - Vec3::ONE;
+            let dir = Dir3::new(vec).unwrap();
+            assert!((dir.magnitude() - 1.).abs() < 1e-6);
+            assert!(vec.project_onto(dir).equals(vec.project(vec), 1e-4));
+            let bounced = Vec3::new(1., -2., 0.5).reflect(dir);
+            assert!((bounced.magnitude() - Vec3::new(1., -2., 0.5).magnitude()).abs() < 1e-4);
+            assert!((bounced.dot(*dir) + Vec3::new(1., -2., 0.5).dot(*dir)).abs() < 1e-4);
+
+            let flat = Vec2::new(vec.x, vec.y);
+            let dir = Dir2::new(flat).unwrap();
+            assert!(Vec2::new(3., 1.).project_onto(dir).equals(Vec2::new(3., 1.).project(flat), 1e-4));
+        }
+        assert_eq!(None, Dir2::new(Vec2::new(f32::INFINITY, 0.)));
+        assert_eq!(None, Dir3::new(Vec3::new(f32::NAN, 0., 0.)));
+        //subnormal and overflowing squares
+        assert_eq!(Some(Dir2::RIGHT), Dir2::new(Vec2::new(1e-22, 0.)));
+        assert!((Dir3::new(Vec3::new(1e-30, -2e-30, 1e-30)).unwrap().magnitude() - 1.).abs() < 1e-6);
+        assert!(Dir2::new(Vec2::new(1e20, 1e20)).unwrap().equals(Vec2::new(1., 1.).normalized(), 1e-6));
+        assert!(Dir3::new(Vec3::new(-1e20, 3e38, 0.)).unwrap().equals(Vec3::UP, 1e-6));
+        assert_eq!(None, Dir3::new(Vec3::ZERO));
+        assert_eq!(Dir3::LEFT, -Dir3::RIGHT);
+        assert_eq!(Some(Dir3::UP), Dir3::FORWARD.cross(Dir3::RIGHT));
+        assert_eq!(None, Dir3::UP.cross(Dir3::DOWN));
+        assert_eq!(Vec2::new(0., 3.), Dir2::UP * 3.);
+        assert_eq!(Vec3::BACK, Vec3::from(Dir3::BACK));
+        assert!(Dir2::RIGHT.rotate(Degrees(180.)).equals(Vec2::LEFT, 1e-6));
+        assert!(Dir3::from_spherical(Degrees(90.), Degrees(90.)).equals(Vec3::RIGHT, 1e-6));
+        assert_eq!("(1, 0)", Dir2::RIGHT.to_string());
+    }
+
+    //---Collision----
+    #[test]
+    fn gjk_distance_circles() {
+        let circle1 = Circle::new(Vec2::new(0., 0.), 1.);
+        let circle2 = Circle::new(Vec2::new(3., 4.), 2.);
+        assert!((collision::distance(&circle1, &circle2) - 2.).abs() < 1e-4);
+    }
+    #[test]
+    fn gjk_distance_aabb_sphere() {
+        let aabb = Aabb3::new(Vec3::new(-1., -1., -1.), Vec3::new(1., 1., 1.));
+        let sphere = Sphere::new(Vec3::new(0.5, 4., 0.), 1.);
+        assert!((collision::distance(&aabb, &sphere) - 2.).abs() < 1e-4);
+    }
+    #[test]
+    fn gjk_distance_capsule_point() {
+        let capsule = Capsule3::new(Vec3::new(0., 0., 0.), Vec3::new(0., 4., 0.), 0.5);
+        let point = Vec3::new(2., 2., 0.);
+        assert!((collision::distance(&capsule, &point) - 1.5).abs() < 1e-4);
+    }
+    #[test]
+    fn gjk_distance_hulls() {
+        let triangle = ConvexHull2::new(vec![Vec2::new(0., 0.), Vec2::new(2., 0.), Vec2::new(0., 2.)]);
+        let square = ConvexHull2::new(vec![Vec2::new(3., 3.), Vec2::new(4., 3.), Vec2::new(4., 4.), Vec2::new(3., 4.)]);
+        assert!((collision::distance(&triangle, &square) - 8f32.sqrt()).abs() < 1e-4);
+    }
+    #[test]
+    fn gjk_closest_points() {
+        let tetrahedron = ConvexHull3::new(vec![Vec3::new(0., 0., 0.), Vec3::new(1., 0., 0.), Vec3::new(0., 1., 0.), Vec3::new(0., 0., 1.)]);
+        let point = Vec3::new(1., 1., 1.);
+        let (on_a, on_b) = collision::closest_points(&tetrahedron, &point).unwrap();
+        assert!(on_a.equals(Vec3::new(1. / 3., 1. / 3., 1. / 3.), 1e-4));
+        assert_eq!(point, on_b);
+    }
+    #[test]
+    fn gjk_intersects() {
+        let aabb = Aabb2::new(Vec2::new(0., 0.), Vec2::new(2., 2.));
+        let capsule = Capsule2::new(Vec2::new(-1., 1.), Vec2::new(3., 1.), 0.1);
+        assert!(collision::intersects(&aabb, &capsule));
+        assert!(!collision::intersects(&aabb, &Vec2::new(3., 3.)));
+        assert_eq!(None, collision::closest_points(&aabb, &capsule));
+    }
+    #[test]
+    fn gjk_intersects_concentric() {
+        let aabb = Aabb3::new(Vec3::new(-1., -1., -1.), Vec3::new(1., 1., 1.));
+        let sphere = Sphere::new(Vec3::ZERO, 0.5);
+        assert!(collision::intersects(&aabb, &sphere));
+        assert_eq!(0., collision::distance(&sphere, &aabb));
+    }
+    #[test]
+    fn epa_circles() {
+        let circle1 = Circle::new(Vec2::new(0., 0.), 1.);
+        let circle2 = Circle::new(Vec2::new(1.5, 0.), 1.);
+        let penetration = collision::penetration2(&circle1, &circle2).unwrap();
+        assert!(penetration.normal.equals(Vec2::RIGHT, 1e-2));
+        assert!((penetration.depth - 0.5).abs() < 1e-2);
+    }
+    #[test]
+    fn epa_concentric_boxes() {
+        let aabb1 = Aabb2::new(Vec2::new(-1., -1.), Vec2::new(1., 1.));
+        let aabb2 = Aabb2::new(Vec2::new(-2., -0.5), Vec2::new(2., 0.5));
+        let penetration = collision::penetration2(&aabb1, &aabb2).unwrap();
+        assert!((penetration.depth - 1.5).abs() < 1e-4);
+        assert!(penetration.normal.x.abs() < 1e-4);
+    }
+    #[test]
+    fn epa_none() {
+        let sphere1 = Sphere::new(Vec3::new(0., 0., 0.), 1.);
+        let sphere2 = Sphere::new(Vec3::new(0., 0., 3.), 1.);
+        assert_eq!(None, collision::penetration3(&sphere1, &sphere2));
+    }
+    #[test]
+    fn epa_sphere_box() {
+        let aabb = Aabb3::new(Vec3::new(-5., -1., -5.), Vec3::new(5., 0., 5.));
+        let sphere = Sphere::new(Vec3::new(1., 0.5, 2.), 1.);
+        let penetration = collision::penetration3(&aabb, &sphere).unwrap();
+        assert!(penetration.normal.equals(Vec3::UP, 1e-2));
+        assert!((penetration.depth - 0.5).abs() < 1e-2);
+    }
+    #[test]
+    fn epa_concentric_cubes() {
+        let aabb1 = Aabb3::new(Vec3::new(-1., -1., -1.), Vec3::new(1., 1., 1.));
+        let aabb2 = Aabb3::new(Vec3::new(-3., -0.5, -3.), Vec3::new(3., 0.5, 3.));
+        let penetration = collision::penetration3(&aabb1, &aabb2).unwrap();
+        assert!((penetration.depth - 1.5).abs() < 1e-4);
+        assert!(penetration.normal.equals(Vec3::UP, 1e-4) || penetration.normal.equals(Vec3::DOWN, 1e-4));
+    }
+
+
+    //---Spatial----
+    //deterministic pseudo random numbers between 0 and 1
+    fn random_values(count: usize) -> Vec<f32> {
+        let mut state: u32 = 0x9E3779B9;
+        (0..count).map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as f32 / u32::MAX as f32
+        }).collect()
+    }
+    fn random_points2(count: usize, size: f32) -> Vec<Vec2> {
+        random_values(count * 2).chunks(2).map(|v| Vec2::new(v[0] * size, v[1] * size)).collect()
+    }
+    fn random_points3(count: usize, size: f32) -> Vec<Vec3> {
+        random_values(count * 3).chunks(3).map(|v| Vec3::new(v[0] * size, v[1] * size, v[2] * size)).collect()
+    }
+
+    #[test]
+    fn quadtree_query_radius() {
+        let points = random_points2(1000, 100.);
+        let mut tree = spatial::Quadtree::new(Aabb2::new(Vec2::ZERO, Vec2::new(100., 100.)));
+        for (i, point) in points.iter().enumerate() {
+            tree.insert(*point, i);
+        }
+        let center = Vec2::new(40., 60.);
+        let mut found: Vec<usize> = tree.query_radius(center, 10.).map(|(_, _, i)| *i).collect();
+        found.sort();
+        let expected: Vec<usize> = (0..points.len()).filter(|i| points[*i].distance_to(center) <= 10.).collect();
+        assert_eq!(expected, found);
+    }
+    #[test]
+    fn quadtree_query_aabb() {
+        let points = random_points2(1000, 100.);
+        let mut tree = spatial::Quadtree::new(Aabb2::new(Vec2::ZERO, Vec2::new(100., 100.)));
+        for (i, point) in points.iter().enumerate() {
+            tree.insert(*point, i);
+        }
+        let area = Aabb2::new(Vec2::new(20., 30.), Vec2::new(45., 50.));
+        let mut found: Vec<usize> = tree.query_aabb(area).map(|(_, _, i)| *i).collect();
+        found.sort();
+        let expected: Vec<usize> = (0..points.len()).filter(|i| area.contains(points[*i])).collect();
+        assert_eq!(expected, found);
+    }
+    #[test]
+    fn quadtree_remove_move() {
+        let points = random_points2(500, 100.);
+        let mut tree = spatial::Quadtree::new(Aabb2::new(Vec2::ZERO, Vec2::new(100., 100.)));
+        let ids: Vec<spatial::ItemId> = points.iter().map(|point| tree.insert(*point, ())).collect();
+        for id in &ids[..250] {
+            assert_eq!(Some(()), tree.remove(*id));
+        }
+        assert_eq!(None, tree.remove(ids[0]));
+        for id in &ids[250..] {
+            assert!(tree.move_item(*id, Vec2::new(150., 150.)));
+        }
+        assert_eq!(250, tree.len());
+        assert_eq!(0, tree.query_aabb(tree.bounds()).count());
+        assert_eq!(250, tree.query_radius(Vec2::new(150., 150.), 1.).count());
+    }
+    #[test]
+    fn octree_k_nearest() {
+        let points = random_points3(1000, 100.);
+        let mut tree = spatial::Octree::new(Aabb3::new(Vec3::ZERO, Vec3::new(100., 100., 100.)));
+        for (i, point) in points.iter().enumerate() {
+            tree.insert(*point, i);
+        }
+        let center = Vec3::new(30., 50., 70.);
+        let found: Vec<usize> = tree.k_nearest(center, 10).map(|(_, _, i)| *i).collect();
+        let mut expected: Vec<usize> = (0..points.len()).collect();
+        expected.sort_by(|a, b| points[*a].distance_to_squared(center).total_cmp(&points[*b].distance_to_squared(center)));
+        assert_eq!(expected[..10], found[..]);
+        //spans several batches of the iterator
+        let found: Vec<usize> = tree.k_nearest(center, 100).map(|(_, _, i)| *i).collect();
+        assert_eq!(expected[..100], found[..]);
+        assert_eq!(1000, tree.nearest(center).count());
+
+        //equally distant items across batch borders are all returned once
+        let mut tree = spatial::Octree::new(Aabb3::new(Vec3::ZERO, Vec3::new(100., 100., 100.)));
+        for i in 0..40 {
+            tree.insert(Vec3::new(10., 10., 10.), i);
+        }
+        let mut found: Vec<usize> = tree.nearest(Vec3::ZERO).map(|(_, _, i)| *i).collect();
+        found.sort();
+        assert_eq!((0..40).collect::<Vec<usize>>(), found);
+    }
+    #[test]
+    fn octree_query_radius() {
+        let points = random_points3(1000, 100.);
+        let mut tree = spatial::Octree::new(Aabb3::new(Vec3::ZERO, Vec3::new(100., 100., 100.)));
+        for (i, point) in points.iter().enumerate() {
+            tree.insert(*point, i);
+        }
+        let center = Vec3::new(50., 50., 50.);
+        let mut found: Vec<usize> = tree.query_radius(center, 20.).map(|(_, _, i)| *i).collect();
+        found.sort();
+        let expected: Vec<usize> = (0..points.len()).filter(|i| points[*i].distance_to(center) <= 20.).collect();
+        assert_eq!(expected, found);
+    }
+    #[test]
+    fn spatial_hash_query_radius() {
+        let points = random_points2(1000, 100.);
+        let mut grid = spatial::SpatialHash2::new(5.);
+        for (i, point) in points.iter().enumerate() {
+            grid.insert(*point, i);
+        }
+        let center = Vec2::new(40., 60.);
+        let mut found: Vec<usize> = grid.query_radius(center, 12.).map(|(_, _, i)| *i).collect();
+        found.sort();
+        let expected: Vec<usize> = (0..points.len()).filter(|i| points[*i].distance_to(center) <= 12.).collect();
+        assert_eq!(expected, found);
+    }
+    #[test]
+    fn spatial_hash_pairs() {
+        let points = random_points3(500, 30.);
+        let mut grid = spatial::SpatialHash3::new(2.);
+        let ids: Vec<spatial::ItemId> = points.iter().map(|point| grid.insert(*point, ())).collect();
+        //move some items around and remove others before looking for pairs
+        for i in 0..100 {
+            grid.move_item(ids[i], points[i] + Vec3::new(3., 0., 0.));
+        }
+        for id in &ids[400..] {
+            grid.remove(*id);
+        }
+        let positions: Vec<Vec3> = (0..400).map(|i| grid.position(ids[i]).unwrap()).collect();
+
+        let mut found: Vec<(spatial::ItemId, spatial::ItemId)> = grid.pairs(3.).map(|(a, b)| (a.min(b), a.max(b))).collect();
+        found.sort();
+        let mut expected = Vec::new();
+        for a in 0..400 {
+            for b in a + 1..400 {
+                if positions[a].distance_to_squared(positions[b]) <= 9. {
+                    expected.push((ids[a], ids[b]));
+                }
+            }
+        }
+        assert_eq!(expected, found);
+    }
+    #[test]
+    fn kdtree_nearest() {
+        let points = random_points3(2000, 100.);
+        let tree = spatial::KdTree3::new(&points);
+        for query in random_points3(50, 120.) {
+            let (index, point) = tree.nearest(query).unwrap();
+            let expected = (0..points.len()).min_by(|a, b| points[*a].distance_to_squared(query).total_cmp(&points[*b].distance_to_squared(query))).unwrap();
+            assert_eq!(points[expected].distance_to_squared(query), point.distance_to_squared(query));
+            assert_eq!(points[index], point);
+        }
+        assert_eq!(None, spatial::KdTree3::new(&[]).nearest(Vec3::ZERO));
+    }
+    #[test]
+    fn kdtree_k_nearest() {
+        let points = random_points2(2000, 100.);
+        let tree = spatial::KdTree2::new(&points);
+        let query = Vec2::new(25., 75.);
+        let found: Vec<usize> = tree.k_nearest(query, 20).iter().map(|(index, _)| *index).collect();
+        let mut expected: Vec<usize> = (0..points.len()).collect();
+        expected.sort_by(|a, b| points[*a].distance_to_squared(query).total_cmp(&points[*b].distance_to_squared(query)));
+        assert_eq!(expected[..20], found[..]);
+    }
+    #[test]
+    fn kdtree_query_radius() {
+        let points = random_points3(2000, 100.);
+        let tree = spatial::KdTree3::new(&points);
+        let center = Vec3::new(50., 20., 80.);
+        let mut found: Vec<usize> = tree.query_radius(center, 15.).map(|(index, _)| index).collect();
+        found.sort();
+        let expected: Vec<usize> = (0..points.len()).filter(|i| points[*i].distance_to(center) <= 15.).collect();
+        assert_eq!(expected, found);
+    }
+    fn random_triangles(count: usize) -> Vec<Triangle3> {
+        let corners = random_points3(count, 100.);
+        let offsets = random_points3(count * 2, 6.);
+        (0..count).map(|i| {
+            let a = corners[i];
+            Triangle3::new(a, a + offsets[i * 2] - Vec3::ONE * 3., a + offsets[i * 2 + 1] - Vec3::ONE * 3.)
+        }).collect()
+    }
+    fn brute_force_raycast(triangles: &[Triangle3], ray: &Ray3) -> Option<(usize, f32)> {
+        triangles.iter().enumerate()
+            .filter_map(|(i, triangle)| ray.intersect_triangle(triangle).map(|distance| (i, distance)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+    #[test]
+    fn bvh_raycast() {
+        let triangles = random_triangles(3000);
+        let bvh = spatial::Bvh::new(&triangles);
+        let origins = random_points3(200, 100.);
+        let targets = random_points3(200, 100.);
+        for (origin, target) in origins.iter().zip(&targets) {
+            let ray = Ray3::new(*origin, *target - *origin);
+            let expected = brute_force_raycast(&triangles, &ray);
+            let hit = bvh.raycast(&ray, f32::INFINITY);
+            assert_eq!(expected.map(|e| e.1), hit.map(|h| h.distance));
+            assert_eq!(expected.is_some(), bvh.raycast_any(&ray, f32::INFINITY));
+            if let Some((_, distance)) = expected {
+                assert!(!bvh.raycast_any(&ray, distance * 0.99));
+            }
+        }
+    }
+    #[test]
+    fn bvh_query_aabb() {
+        let triangles = random_triangles(3000);
+        let bvh = spatial::Bvh::new(&triangles);
+        let area = Aabb3::new(Vec3::new(20., 30., 40.), Vec3::new(35., 50., 45.));
+        let mut found: Vec<usize> = bvh.query_aabb(area).collect();
+        found.sort();
+        let expected: Vec<usize> = (0..triangles.len()).filter(|i| triangles[*i].aabb().intersects(&area)).collect();
+        assert_eq!(expected, found);
+    }
+    #[test]
+    fn bvh_refit() {
+        let mut triangles = random_triangles(1000);
+        let mut bvh = spatial::Bvh::new(&triangles);
+        //deform the mesh
+        for (i, triangle) in triangles.iter_mut().enumerate() {
+            let offset = Vec3::new((i % 7) as f32, (i % 5) as f32 * -2., (i % 3) as f32);
+            *triangle = Triangle3::new(triangle.a + offset, triangle.b + offset, triangle.c + offset);
+        }
+        bvh.refit(&triangles);
+        let rebuilt = spatial::Bvh::new(&triangles);
+        for origin in random_points3(100, 100.) {
+            let ray = Ray3::new(origin, Vec3::new(50., 50., 50.) - origin);
+            let expected = brute_force_raycast(&triangles, &ray).map(|e| e.1);
+            assert_eq!(expected, bvh.raycast(&ray, f32::INFINITY).map(|h| h.distance));
+            assert_eq!(expected, rebuilt.raycast(&ray, f32::INFINITY).map(|h| h.distance));
+        }
+    }
     
+
+    //---Curves----
+    #[test]
+    fn bezier_derivative() {
+        let curve = curves::CubicBezier::new(Vec3::new(0., 0., 0.), Vec3::new(1., 3., -2.), Vec3::new(4., -1., 2.), Vec3::new(5., 2., 1.));
+        for i in 1..10 {
+            let t = i as f32 / 10.;
+            let difference = (curve.at(t + 1e-3) - curve.at(t - 1e-3)) / 2e-3;
+            assert!(difference.equals(curve.derivative(t), 1e-2));
+            let difference = (curve.derivative(t + 1e-3) - curve.derivative(t - 1e-3)) / 2e-3;
+            assert!(difference.equals(curve.second_derivative(t), 1e-1));
+        }
+    }
+    #[test]
+    fn bezier_split() {
+        let curve = curves::CubicBezier::new(Vec2::new(0., 0.), Vec2::new(1., 3.), Vec2::new(4., -1.), Vec2::new(5., 2.));
+        let (first, second) = curve.split(0.3);
+        for i in 0..=10 {
+            let t = i as f32 / 10.;
+            assert!(first.at(t).equals(curve.at(t * 0.3), 1e-5));
+            assert!(second.at(t).equals(curve.at(0.3 + t * 0.7), 1e-5));
+        }
+    }
+    #[test]
+    fn bezier_bounds() {
+        let quadratic = curves::QuadraticBezier::new(Vec3::new(0., 0., 0.), Vec3::new(3., -4., 2.), Vec3::new(1., 1., 1.));
+        let cubic = curves::CubicBezier::new(Vec3::new(0., 0., 0.), Vec3::new(1., 3., -2.), Vec3::new(4., -1., 2.), Vec3::new(-1., 2., 1.));
+        let (mut quadratic_min, mut quadratic_max) = (quadratic.start, quadratic.start);
+        let (mut cubic_min, mut cubic_max) = (cubic.start, cubic.start);
+        for i in 0..=10000 {
+            let t = i as f32 / 10000.;
+            quadratic_min = quadratic_min.min(quadratic.at(t));
+            quadratic_max = quadratic_max.max(quadratic.at(t));
+            cubic_min = cubic_min.min(cubic.at(t));
+            cubic_max = cubic_max.max(cubic.at(t));
+        }
+        let (min, max) = quadratic.bounds();
+        assert!(min.equals(quadratic_min, 1e-4) && max.equals(quadratic_max, 1e-4));
+        let (min, max) = cubic.bounds();
+        assert!(min.equals(cubic_min, 1e-4) && max.equals(cubic_max, 1e-4));
+    }
+    #[test]
+    fn bezier_closest_point() {
+        let curve = curves::CubicBezier::new(Vec2::new(0., 0.), Vec2::new(10., 30.), Vec2::new(40., -10.), Vec2::new(50., 20.));
+        for point in random_points2(100, 60.) {
+            let mut expected = f32::INFINITY;
+            for i in 0..=10000 {
+                expected = expected.min(curve.at(i as f32 / 10000.).distance_to(point));
+            }
+            let (t, closest) = curve.closest_point(point);
+            assert_eq!(curve.at(t), closest);
+            assert!(closest.distance_to(point) <= expected + 1e-3);
+        }
+    }
+    fn spline_points() -> Vec<Vec3> {
+        vec![Vec3::new(0., 0., 0.), Vec3::new(1., 4., 0.), Vec3::new(1.5, 4.2, 2.), Vec3::new(8., 0., 1.), Vec3::new(9., -3., -1.), Vec3::new(12., 0., 0.)]
+    }
+    fn assert_derivative<S: curves::Spline>(spline: &S) {
+        //avoid sampling across segment borders
+        for i in 0..spline.segments() {
+            for j in 1..10 {
+                let t = (i as f32 + j as f32 / 10.) / spline.segments() as f32;
+                let difference = (spline.at(t + 1e-4) - spline.at(t - 1e-4)) / 2e-4;
+                assert!(difference.distance_to(spline.derivative(t)) < 1e-2 * spline.derivative(t).magnitude().max(1.), "{:?} {:?}", difference, spline.derivative(t));
+            }
+        }
+    }
+    #[test]
+    fn catmull_rom_passes_through_points() {
+        let points = spline_points();
+        for parameterization in [curves::Parameterization::Uniform, curves::Parameterization::Centripetal, curves::Parameterization::Chordal] {
+            let spline = curves::CatmullRom::new(points.clone(), parameterization);
+            assert_eq!(points.len() - 1, curves::Spline::segments(&spline));
+            for (i, point) in points.iter().enumerate() {
+                assert!(curves::Spline::at(&spline, i as f32 / 5.).equals(*point, 1e-5));
+            }
+            assert_derivative(&spline);
+        }
+    }
+    #[test]
+    fn catmull_rom_uniform_tangents() {
+        use curves::Spline;
+        let points = spline_points();
+        let spline = curves::CatmullRom::new(points.clone(), curves::Parameterization::Uniform);
+        for i in 1..points.len() - 1 {
+            let expected = (points[i + 1] - points[i - 1]) * 0.5 * 5.;
+            assert!(spline.derivative(i as f32 / 5.).equals(expected, 1e-4));
+        }
+    }
+    #[test]
+    fn hermite_spline() {
+        use curves::Spline;
+        let points = spline_points();
+        let tangents: Vec<Vec3> = (0..points.len()).map(|i| Vec3::new(i as f32, 1., -2.)).collect();
+        let spline = curves::Hermite::new(points.clone(), tangents.clone());
+        for i in 0..points.len() {
+            let t = i as f32 / 5.;
+            assert!(spline.at(t).equals(points[i], 1e-5));
+            assert!(spline.derivative(t).equals(tangents[i] * 5., 1e-4));
+        }
+        assert_derivative(&spline);
+    }
+    #[test]
+    fn bspline_continuity() {
+        use curves::Spline;
+        let spline = curves::BSpline::new(spline_points());
+        assert_eq!(3, spline.segments());
+        for joint in 1..3 {
+            let t = joint as f32 / 3.;
+            assert!(spline.at(t - 1e-6).equals(spline.at(t + 1e-6), 1e-3));
+            assert!(spline.derivative(t - 1e-6).equals(spline.derivative(t + 1e-6), 1e-2));
+        }
+        assert_derivative(&spline);
+    }
+    #[test]
+    fn arc_length_constant_speed() {
+        use curves::Spline;
+        let path = curves::CatmullRom::new(spline_points(), curves::Parameterization::Centripetal).arc_length_table(64);
+        let mut exact = 0.;
+        for i in 0..100000 {
+            exact += path.spline().at(i as f32 / 100000.).distance_to(path.spline().at((i + 1) as f32 / 100000.));
+        }
+        assert!((path.length() - exact).abs() < exact * 1e-3);
+        assert_eq!(0., path.t_at(-1.));
+        assert_eq!(1., path.t_at(path.length() + 1.));
+        let step = path.length() / 50.;
+        for i in 0..50 {
+            let (start, end) = (path.t_at(i as f32 * step), path.t_at((i + 1) as f32 * step));
+            let mut distance = 0.;
+            for j in 0..200 {
+                let t = start + (end - start) * j as f32 / 200.;
+                distance += path.spline().at(t).distance_to(path.spline().at(t + (end - start) / 200.));
+            }
+            assert!((distance - step).abs() < step * 0.01);
+        }
+    }
+
+    //---Animation----
+    #[test]
+    fn easing_endpoints() {
+        use animation::easing::*;
+        let functions: [fn(f32) -> f32; 31] = [
+            linear, sine_in, sine_out, sine_in_out, quad_in, quad_out, quad_in_out, cubic_in, cubic_out, cubic_in_out,
+            quart_in, quart_out, quart_in_out, quint_in, quint_out, quint_in_out, expo_in, expo_out, expo_in_out,
+            circ_in, circ_out, circ_in_out, back_in, back_out, back_in_out, elastic_in, elastic_out, elastic_in_out,
+            bounce_in, bounce_out, bounce_in_out];
+        for function in functions {
+            assert!(function(0.).abs() < 1e-5);
+            assert!((function(1.) - 1.).abs() < 1e-5);
+        }
+        assert!((quad_in_out(0.5) - 0.5).abs() < 1e-6);
+        assert!((bounce_out(0.5) - 0.765625).abs() < 1e-6);
+        assert!(back_in(0.2) < 0.);
+        assert!(back_out(0.8) > 1.);
+    }
+    #[test]
+    fn tween_once() {
+        let mut tween = animation::Tween::new(Vec3::new(0., 0., 0.), Vec3::new(4., 8., -4.), 2., animation::easing::quad_in);
+        assert_eq!(Vec3::new(0., 0., 0.), tween.value());
+        assert_eq!(Vec3::new(1., 2., -1.), tween.step(1.));
+        assert!(!tween.is_finished());
+        assert_eq!(Vec3::new(4., 8., -4.), tween.step(1.5));
+        assert!(tween.is_finished());
+        assert_eq!(2., tween.elapsed());
+        tween.reset();
+        assert_eq!(Vec3::new(0., 0., 0.), tween.value());
+    }
+    #[test]
+    fn tween_yoyo() {
+        let mut tween = animation::Tween::linear(Vec2::new(0., 0.), Vec2::new(10., 0.), 1.);
+        tween.yoyo = true;
+        assert_eq!(Vec2::new(10., 0.), tween.step(1.));
+        assert_eq!(Vec2::new(7.5, 0.), tween.step(0.25));
+        assert!(!tween.is_finished());
+        assert_eq!(Vec2::new(0., 0.), tween.step(10.));
+        assert!(tween.is_finished());
+    }
+    #[test]
+    fn tween_looping() {
+        let mut tween = animation::Tween::linear(Vec2::new(0., 0.), Vec2::new(10., 0.), 1.);
+        tween.looping = true;
+        assert_eq!(Vec2::new(5., 0.), tween.step(0.5));
+        assert_eq!(Vec2::new(10., 0.), tween.step(0.5));
+        assert_eq!(Vec2::new(2.5, 0.), tween.step(0.25));
+        assert!(tween.step(1000.5).equals(Vec2::new(7.5, 0.), 1e-3));
+        assert!(!tween.is_finished());
+    }
+
+    //---Noise----
+    fn assert_noise_gradient<N: noise::Noise<Vec3>>(noise: &N) {
+        //small coordinates keep the finite differences precise
+        for point in random_points3(500, 30.) {
+            let (value, gradient) = noise.sample_gradient(point);
+            assert_eq!(value, noise.sample(point));
+            assert!((-1. ..=1.).contains(&value));
+            let epsilon = 1e-3;
+            let difference = Vec3::new(
+                noise.sample(point + Vec3::new(epsilon, 0., 0.)) - noise.sample(point - Vec3::new(epsilon, 0., 0.)),
+                noise.sample(point + Vec3::new(0., epsilon, 0.)) - noise.sample(point - Vec3::new(0., epsilon, 0.)),
+                noise.sample(point + Vec3::new(0., 0., epsilon)) - noise.sample(point - Vec3::new(0., 0., epsilon))) / (2. * epsilon);
+            assert!(difference.distance_to(gradient) < 2e-2 * gradient.magnitude().max(1.), "{:?} {:?}", difference, gradient);
+        }
+    }
+    #[test]
+    fn perlin_gradient() {
+        assert_noise_gradient(&noise::Perlin::new(1));
+        let perlin = noise::Perlin::new(2);
+        for point in random_points2(500, 300.) {
+            let (value, gradient) = noise::Noise::sample_gradient(&perlin, point);
+            let epsilon = 1e-2;
+            let difference = Vec2::new(
+                noise::Noise::sample(&perlin, point + Vec2::new(epsilon, 0.)) - noise::Noise::sample(&perlin, point - Vec2::new(epsilon, 0.)),
+                noise::Noise::sample(&perlin, point + Vec2::new(0., epsilon)) - noise::Noise::sample(&perlin, point - Vec2::new(0., epsilon))) / (2. * epsilon);
+            assert!((-1. ..=1.).contains(&value));
+            assert!(difference.distance_to(gradient) < 2e-2);
+        }
+        //zero at integer points
+        assert_eq!(0., noise::Noise::sample(&perlin, Vec2::new(3., -7.)));
+    }
+    #[test]
+    fn simplex_gradient() {
+        assert_noise_gradient(&noise::Simplex::new(1));
+        let simplex = noise::Simplex::new(3);
+        let values = random_values(2000);
+        for point in values.chunks(4) {
+            let point = [point[0] * 50., point[1] * 50., point[2] * 50., point[3] * 50.];
+            let (value, gradient) = noise::Noise::sample_gradient(&simplex, point);
+            assert!((-1. ..=1.).contains(&value));
+            for axis in 0..4 {
+                let (mut above, mut below) = (point, point);
+                above[axis] += 1e-2;
+                below[axis] -= 1e-2;
+                let difference = (noise::Noise::sample(&simplex, above) - noise::Noise::sample(&simplex, below)) / 2e-2;
+                assert!((difference - gradient[axis]).abs() < 2e-2);
+            }
+        }
+    }
+    #[test]
+    fn noise_seeds() {
+        use noise::Noise;
+        let point = Vec3::new(12.3, 4.56, 7.89);
+        assert_eq!(noise::Perlin::new(5).sample(point), noise::Perlin::new(5).sample(point));
+        assert_ne!(noise::Perlin::new(5).sample(point), noise::Perlin::new(6).sample(point));
+        assert_eq!(noise::Simplex::new(5).sample(point), noise::Simplex::new(5).sample(point));
+        assert_ne!(noise::Simplex::new(5).sample(point), noise::Simplex::new(6).sample(point));
+        assert_eq!(6, noise::Simplex::new(6).seed());
+        //values must not change between versions or platforms
+        assert_eq!(1051131130, noise::Perlin::new(5).sample(point).to_bits());
+        assert_eq!(3192214188, noise::Simplex::new(5).sample(point).to_bits());
+    }
+    #[test]
+    fn fractal_noise() {
+        use noise::Noise;
+        let mut fractal = noise::Fractal::new(noise::Perlin::new(4));
+        fractal.octaves = 5;
+        assert_noise_gradient(&fractal);
+        for kind in [noise::FractalKind::Ridged, noise::FractalKind::Billow] {
+            fractal.kind = kind;
+            for point in random_points3(500, 300.) {
+                assert!((-1. ..=1.).contains(&fractal.sample(point)));
+            }
+        }
+        fractal.octaves = 0;
+        assert_eq!(0., fractal.sample(Vec3::new(1.5, 2.5, 3.5)));
+    }
+    #[test]
+    fn domain_warp() {
+        use noise::Noise;
+        let warped = noise::DomainWarp::simplex(noise::Simplex::new(8), 9, 0.5);
+        assert_noise_gradient(&warped);
+        let mut warped = noise::DomainWarp::new(noise::Perlin::new(8), noise::Perlin::new(9), 0.7);
+        warped.frequency = 0.5;
+        for point in random_points2(500, 300.) {
+            let (value, gradient) = warped.sample_gradient(point);
+            let epsilon = 1e-2;
+            let difference = Vec2::new(
+                warped.sample(point + Vec2::new(epsilon, 0.)) - warped.sample(point - Vec2::new(epsilon, 0.)),
+                warped.sample(point + Vec2::new(0., epsilon)) - warped.sample(point - Vec2::new(0., epsilon))) / (2. * epsilon);
+            assert_eq!(value, warped.sample(point));
+            assert!(difference.distance_to(gradient) < 2e-2);
+        }
+    }
+    #[test]
+    fn worley_noise() {
+        let worley = noise::Worley::new(11);
+        let points = random_points3(1000, 100.);
+        for pair in points.windows(2) {
+            let sample = worley.sample3(pair[0]);
+            assert!(sample.f1 <= sample.f2);
+            assert!((sample.f1 - sample.nearest.distance_to(pair[0])).abs() < 1e-5);
+            //the feature point belongs to its own cell
+            let feature = worley.sample3(sample.nearest);
+            assert_eq!(0., feature.f1);
+            assert_eq!(sample.cell, feature.cell);
+            //distances change no faster than the point moves
+            let other = worley.sample3(pair[1]);
+            assert!((sample.f1 - other.f1).abs() <= pair[0].distance_to(pair[1]) + 1e-4);
+        }
+        let sample = worley.sample2(Vec2::new(0.5, 0.5));
+        assert_eq!(sample, noise::Worley::new(11).sample2(Vec2::new(0.5, 0.5)));
+        assert_ne!(sample.cell, noise::Worley::new(12).sample2(Vec2::new(0.5, 0.5)).cell);
+    }
+
+    //---Random----
+    #[cfg(feature = "rand")]
+    fn test_rng() -> rand::rngs::SmallRng {
+        rand::SeedableRng::seed_from_u64(0x5EED)
+    }
+    //fails if the counts are unlikely to come from a uniform distribution
+    #[cfg(feature = "rand")]
+    fn assert_uniform(counts: &[usize]) {
+        let total: usize = counts.iter().sum();
+        let expected = total as f32 / counts.len() as f32;
+        let chi_squared: f32 = counts.iter().map(|count| (*count as f32 - expected).powi(2) / expected).sum();
+        let degrees = (counts.len() - 1) as f32;
+        assert!(chi_squared < degrees + 6. * (2. * degrees).sqrt(), "chi squared {} for {} bins", chi_squared, counts.len());
+    }
+    #[cfg(feature = "rand")]
+    fn bin(value: f32, bins: usize) -> usize {
+        ((value * bins as f32) as usize).min(bins - 1)
+    }
+    #[test]
+    #[cfg(feature = "rand")]
+    fn random_circle() {
+        let mut rng = test_rng();
+        let mut inside = [0; 64];
+        let mut border = [0; 32];
+        for _ in 0..100000 {
+            let point = random::in_unit_circle(&mut rng);
+            assert!(point.magnitude() <= 1.);
+            let angle = (point.y.atan2(point.x) + std::f32::consts::PI) / std::f32::consts::TAU;
+            inside[bin(point.magnitude_squared(), 8) * 8 + bin(angle, 8)] += 1;
+
+            let direction = random::on_unit_circle(&mut rng);
+            assert!((direction.magnitude() - 1.).abs() < 1e-5);
+            border[bin((direction.y.atan2(direction.x) + std::f32::consts::PI) / std::f32::consts::TAU, 32)] += 1;
+        }
+        assert_uniform(&inside);
+        assert_uniform(&border);
+    }
+    #[test]
+    #[cfg(feature = "rand")]
+    fn random_sphere() {
+        let mut rng = test_rng();
+        let mut inside = [0; 64];
+        let mut surface = [0; 64];
+        for _ in 0..100000 {
+            let point = random::in_unit_sphere(&mut rng);
+            let radius = point.magnitude();
+            assert!(radius <= 1. + 1e-6);
+            inside[bin(radius.powi(3), 8) * 8 + bin((point.z / radius + 1.) * 0.5, 8)] += 1;
+
+            let direction = random::on_unit_sphere(&mut rng);
+            assert!((direction.magnitude() - 1.).abs() < 1e-5);
+            let angle = (direction.y.atan2(direction.x) + std::f32::consts::PI) / std::f32::consts::TAU;
+            surface[bin((direction.z + 1.) * 0.5, 8) * 8 + bin(angle, 8)] += 1;
+        }
+        assert_uniform(&inside);
+        assert_uniform(&surface);
+    }
+    #[test]
+    #[cfg(feature = "rand")]
+    fn random_cone() {
+        let mut rng = test_rng();
+        let axis = Vec3::new(1., 2., 3.).normalized();
+        let half_angle = 0.6f32;
+        let mut heights = [0; 32];
+        let mut sum = Vec3::ZERO;
+        for _ in 0..100000 {
+            let direction = random::in_cone(&mut rng, axis * 4., half_angle);
+            assert!((direction.magnitude() - 1.).abs() < 1e-5);
+            let cos = direction.dot(axis);
+            assert!(cos >= half_angle.cos() - 1e-5);
+            //the height along the axis is evenly distributed over a spherical cap
+            heights[bin((cos - half_angle.cos()) / (1. - half_angle.cos()), 32)] += 1;
+            sum += direction;
+        }
+        assert_uniform(&heights);
+        assert!(sum.normalized().equals(axis, 1e-2));
+    }
+    #[test]
+    #[cfg(feature = "rand")]
+    fn random_aabb() {
+        let mut rng = test_rng();
+        let aabb = Aabb3::new(Vec3::new(-1., 2., 3.), Vec3::new(4., 3., 10.));
+        let mut cells = [0; 64];
+        for _ in 0..100000 {
+            let point = random::in_aabb3(&mut rng, &aabb);
+            assert!(aabb.contains(point));
+            let local = (point - aabb.min) / (aabb.max - aabb.min);
+            cells[bin(local.x, 4) * 16 + bin(local.y, 4) * 4 + bin(local.z, 4)] += 1;
+        }
+        assert_uniform(&cells);
+        let aabb = Aabb2::new(Vec2::new(-1., 2.), Vec2::new(4., 3.));
+        let mut cells = [0; 64];
+        for _ in 0..100000 {
+            let point = random::in_aabb2(&mut rng, &aabb);
+            assert!(aabb.contains(point));
+            let local = (point - aabb.min) / (aabb.max - aabb.min);
+            cells[bin(local.x, 8) * 8 + bin(local.y, 8)] += 1;
+        }
+        assert_uniform(&cells);
+    }
+    #[test]
+    #[cfg(feature = "rand")]
+    fn random_triangle() {
+        let mut rng = test_rng();
+        let mut cells = [0; 72];
+        for _ in 0..100000 {
+            let point = random::in_triangle(&mut rng, Vec2::new(1., 1.), Vec2::new(3., 1.), Vec2::new(1., 5.));
+            let (u, v) = ((point.x - 1.) / 2., (point.y - 1.) / 4.);
+            assert!(u >= 0. && v >= 0. && u + v <= 1. + 1e-5);
+            //split the triangle into 36 smaller ones of equal area
+            let (column, row) = (bin(u, 6), bin(v, 6));
+            let upper = (u * 6. - column as f32) + (v * 6. - row as f32) > 1.;
+            cells[(row * 6 + column) * 2 + upper as usize] += 1;
+        }
+        let cells: Vec<usize> = (0..72).filter(|i| {
+            let (row, column, upper) = (i / 12, i / 2 % 6, i % 2);
+            row + column + upper <= 5
+        }).map(|i| cells[i]).collect();
+        assert_eq!(36, cells.len());
+        assert_uniform(&cells);
+    }
+
+    //---Sampling----
+    fn assert_min_distance<V: Vector>(points: &[V], distance: impl Fn(V, V) -> f32) {
+        for (i, a) in points.iter().enumerate() {
+            for b in &points[i + 1..] {
+                assert!(a.distance_to(*b) >= distance(*a, *b) * 0.9999);
+            }
+        }
+    }
+    #[test]
+    fn poisson_disk_aabb2() {
+        let bounds = Aabb2::new(Vec2::new(-20., 5.), Vec2::new(30., 40.));
+        let sampler = sampling::PoissonDisk::new(1.5, 9);
+        let points = sampler.in_aabb2(bounds);
+        assert!(points.len() > 200);
+        assert!(points.iter().all(|point| bounds.contains(*point)));
+        assert_min_distance(&points, |_, _| 1.5);
+        //no large gaps
+        for probe in random_points2(1000, 1.) {
+            let probe = bounds.min + (bounds.max - bounds.min) * probe;
+            assert!(points.iter().any(|point| point.distance_to(probe) < 3.));
+        }
+        assert_eq!(points, sampler.in_aabb2(bounds));
+        assert_ne!(points, sampling::PoissonDisk::new(1.5, 10).in_aabb2(bounds));
+    }
+    #[test]
+    fn poisson_disk_polygon() {
+        //L shape
+        let polygon = [Vec2::new(0., 0.), Vec2::new(20., 0.), Vec2::new(20., 5.), Vec2::new(5., 5.), Vec2::new(5., 20.), Vec2::new(0., 20.)];
+        let points = sampling::PoissonDisk::new(1., 4).in_polygon(&polygon);
+        assert!(points.len() > 50);
+        for point in &points {
+            assert!(point.x >= 0. && point.y >= 0. && (point.x <= 5. || point.y <= 5.));
+        }
+        assert_min_distance(&points, |_, _| 1.);
+        assert!(sampling::PoissonDisk::new(1., 4).in_polygon(&polygon[..2]).is_empty());
+        //a diagonal sliver that random guesses in its bounding box almost never hit
+        let sliver = [Vec2::new(0., 0.), Vec2::new(1000., 1000.), Vec2::new(999.99, 1000.)];
+        let points = sampling::PoissonDisk::new(1., 4).in_polygon(&sliver);
+        assert!(!points.is_empty());
+        assert!(points.iter().all(|point| point.x <= point.y + 1e-3));
+        let flat = [Vec2::new(0., 0.), Vec2::new(10., 0.), Vec2::new(20., 0.)];
+        assert!(sampling::PoissonDisk::new(1., 4).in_polygon(&flat).is_empty());
+    }
+    #[test]
+    fn poisson_disk_aabb3() {
+        let bounds = Aabb3::new(Vec3::new(0., 0., 0.), Vec3::new(10., 12., 8.));
+        let points = sampling::PoissonDisk::new(1.2, 1).in_aabb3(bounds);
+        assert!(points.iter().all(|point| bounds.contains(*point)));
+        assert_min_distance(&points, |_, _| 1.2);
+        for probe in random_points3(1000, 1.) {
+            let probe = bounds.min + (bounds.max - bounds.min) * probe;
+            assert!(points.iter().any(|point| point.distance_to(probe) < 2.4));
+        }
+    }
+    #[test]
+    fn poisson_disk_density() {
+        let mut sampler = sampling::PoissonDisk::new(0.5, 2);
+        sampler.max_radius = 3.;
+        let density = |point: Vec2| point.y / 30.;
+        let radius = |point: Vec2| 3. - 2.5 * density(point);
+        let points = sampler.in_aabb2_with_density(Aabb2::new(Vec2::new(0., 0.), Vec2::new(30., 30.)), density);
+        assert_min_distance(&points, |a, b| radius(a).max(radius(b)));
+        let top = points.iter().filter(|point| point.y > 15.).count();
+        assert!(top > (points.len() - top) * 2);
+    }
+    #[test]
+    fn fibonacci_sphere() {
+        let directions = sampling::fibonacci_sphere(500);
+        assert_eq!(500, directions.len());
+        let mut sum = Vec3::ZERO;
+        for (i, a) in directions.iter().enumerate() {
+            assert!((a.magnitude() - 1.).abs() < 1e-6);
+            sum += *a;
+            //no two points closer than half the spacing of a perfect distribution
+            for b in &directions[i + 1..] {
+                assert!(a.distance_to(*b) > 0.5 * (4. * std::f32::consts::PI / 500.).sqrt());
+            }
+        }
+        assert!(sum.magnitude() < 1e-2 * 500.);
+        assert!(sampling::fibonacci_sphere(0).is_empty());
+    }
+    #[test]
+    fn fibonacci_hemisphere() {
+        let normal = Vec3::new(-1., 2., 0.5).normalized();
+        let directions = sampling::fibonacci_hemisphere(400, normal);
+        for direction in &directions {
+            assert!((direction.magnitude() - 1.).abs() < 1e-6);
+            assert!(direction.dot(normal) > 0.);
+        }
+        //the average cosine of an evenly covered hemisphere is 1/2
+        let average = directions.iter().map(|direction| direction.dot(normal)).sum::<f32>() / 400.;
+        assert!((average - 0.5).abs() < 1e-2);
+        let sum = directions.iter().fold(Vec3::ZERO, |sum, direction| sum + *direction);
+        assert!(sum.normalized().equals(normal, 1e-2));
+    }
+    #[test]
+    fn cosine_hemisphere() {
+        let normal = Vec3::new(0.3, -0.2, -1.).normalized();
+        let directions = sampling::cosine_hemisphere(1000, normal);
+        for direction in &directions {
+            assert!((direction.magnitude() - 1.).abs() < 1e-6);
+            assert!(direction.dot(normal) > 0.);
+        }
+        let average = directions.iter().map(|direction| direction.dot(normal)).sum::<f32>() / 1000.;
+        assert!((average - 2. / 3.).abs() < 1e-2);
+    }
+    #[test]
+    fn circle_directions() {
+        let directions = sampling::circle_directions(12);
+        let mut sum = Vec2::ZERO;
+        for (i, direction) in directions.iter().enumerate() {
+            assert!((direction.magnitude() - 1.).abs() < 1e-6);
+            assert!((direction.angle_between(directions[(i + 1) % 12]) - std::f32::consts::TAU / 12.).abs() < 1e-3);
+            sum += *direction;
+        }
+        assert!(sum.magnitude() < 1e-5);
+    }
+
+    //---Integrate----
+    type Step<V> = fn(&mut integrate::Particle<V>, f32, &dyn Fn(V, V) -> V);
+    //simulates a spring for 100 seconds at 10 steps per second and returns the relative energy change
+    fn spring_energy_drift(step: Step<Vec3>) -> f32 {
+        let stiffness = 4.;
+        let spring = |position: Vec3, _: Vec3| -position * stiffness;
+        let energy = |particle: &integrate::Particle<Vec3>| 0.5 * particle.velocity.magnitude_squared() + 0.5 * stiffness * particle.position.magnitude_squared();
+        let mut particle = integrate::Particle::new(Vec3::new(1., 0., 0.), Vec3::new(0., 1., 0.5));
+        let start = energy(&particle);
+        for _ in 0..1000 {
+            step(&mut particle, 0.1, &spring);
+        }
+        (energy(&particle) - start) / start
+    }
+    #[test]
+    fn integrator_energy_drift() {
+        let explicit = spring_energy_drift(|particle, dt, acceleration| integrate::explicit_euler(particle, dt, acceleration));
+        let semi_implicit = spring_energy_drift(|particle, dt, acceleration| integrate::semi_implicit_euler(particle, dt, acceleration));
+        let velocity_verlet = spring_energy_drift(|particle, dt, acceleration| integrate::velocity_verlet(particle, dt, acceleration));
+        let position_verlet = spring_energy_drift(|particle, dt, acceleration| integrate::position_verlet(particle, dt, acceleration));
+        let rk4 = spring_energy_drift(|particle, dt, acceleration| integrate::rk4(particle, dt, acceleration));
+        //explicit euler gains energy every step
+        assert!(explicit > 1000.);
+        //symplectic integrators oscillate around the right energy without drifting
+        assert!(semi_implicit.abs() < 0.25);
+        assert!(velocity_verlet.abs() < 0.02);
+        assert!(position_verlet.abs() < 0.02);
+        //rk4 slowly loses energy but stays accurate
+        assert!(rk4 < 0. && rk4 > -0.01);
+    }
+    #[test]
+    fn integrator_accuracy() {
+        //constant acceleration, exact solution x = v t + a t^2 / 2
+        let gravity = Vec2::new(1., -9.81);
+        let start = integrate::Particle::new(Vec2::new(0., 0.), Vec2::new(3., 5.));
+        let expected = start.velocity * 2. + gravity * 2.;
+        let steps: [Step<Vec2>; 3] = [
+            |particle, dt, acceleration| integrate::velocity_verlet(particle, dt, acceleration),
+            |particle, dt, acceleration| integrate::position_verlet(particle, dt, acceleration),
+            |particle, dt, acceleration| integrate::rk4(particle, dt, acceleration),
+        ];
+        for step in steps {
+            let mut particle = start;
+            for _ in 0..20 {
+                step(&mut particle, 0.1, &|_, _| gravity);
+            }
+            assert!(particle.position.equals(expected, 1e-3));
+            assert!(particle.velocity.equals(start.velocity + gravity * 2., 1e-4));
+        }
+        //drag, exact solution v = v0 e^(-t)
+        let mut particle = integrate::Particle::new(Vec2::ZERO, Vec2::new(1., 0.));
+        for _ in 0..10 {
+            integrate::rk4(&mut particle, 0.1, |_, velocity| -velocity);
+        }
+        assert!((particle.velocity.x - (-1f32).exp()).abs() < 1e-6);
+        assert!((particle.position.x - (1. - (-1f32).exp())).abs() < 1e-6);
+    }
+
+    //---Steering----
+    #[test]
+    fn steering_seek_flee() {
+        let agent = steering::Agent::new(Vec3::new(0., 0., 0.), Vec3::new(0., 1., 0.), 5., 2.);
+        let target = Vec3::new(10., 0., 0.);
+        let seek = agent.seek(target);
+        let flee = agent.flee(target);
+        assert!((seek.magnitude() - 2.).abs() < 1e-5);
+        assert!(seek.x > 0. && seek.y < 0.);
+        assert!(flee.x < 0. && flee.y < 0.);
+        //standing on the target only brakes
+        assert_eq!(Vec3::new(0., -1., 0.), steering::Agent::new(target, Vec3::new(0., 1., 0.), 5., 2.).seek(target));
+    }
+    #[test]
+    fn steering_arrive() {
+        let target = Vec2::new(-10., 5.);
+        let mut agent = steering::Agent::new(Vec2::new(3., 3.), Vec2::new(2., 0.), 4., 8.);
+        for _ in 0..1500 {
+            let force = agent.arrive(target, 4.);
+            assert!(force.magnitude() <= 8. + 1e-4);
+            agent.step(force, 1. / 60.);
+            assert!(agent.velocity.magnitude() <= 4. + 1e-4);
+        }
+        assert!(agent.position.distance_to(target) < 1e-2);
+        assert!(agent.velocity.magnitude() < 1e-2);
+    }
+    #[test]
+    fn steering_pursue_evade() {
+        let run = |pursue: bool| {
+            let mut hunter = steering::Agent::new(Vec2::new(0., 0.), Vec2::ZERO, 6., 20.);
+            let mut prey = steering::Agent::new(Vec2::new(20., 0.), Vec2::new(0., 4.), 4., 20.);
+            for i in 0..600 {
+                let force = if pursue { hunter.pursue(&prey) } else { hunter.seek(prey.position) };
+                hunter.step(force, 1. / 60.);
+                prey.step(Vec2::ZERO, 1. / 60.);
+                if hunter.position.distance_to(prey.position) < 0.5 {
+                    return i;
+                }
+            }
+            600
+        };
+        //leading the target catches it sooner than chasing it
+        assert!(run(true) < run(false));
+
+        let hunter = steering::Agent::new(Vec2::new(0., 0.), Vec2::new(5., 0.), 5., 10.);
+        let mut prey = steering::Agent::new(Vec2::new(6., 1.), Vec2::ZERO, 6., 10.);
+        for _ in 0..120 {
+            let force = prey.evade(&hunter);
+            prey.step(force, 1. / 60.);
+        }
+        assert!(prey.position.distance_to(hunter.position) > 10.);
+    }
+    #[test]
+    fn steering_wander() {
+        let mut agent = steering::Agent::new(Vec3::ZERO, Vec3::new(1., 0., 0.), 2., 4.);
+        let mut wander = steering::Wander::new(Vec3::new(1., 0., 0.), 2., 1., 0.5);
+        let offsets = random_points3(500, 2.);
+        for offset in offsets {
+            let force = agent.wander(&mut wander, offset - Vec3::ONE);
+            assert!((wander.target.magnitude() - 1.).abs() < 1e-4);
+            assert!(force.magnitude() <= 4. + 1e-4);
+            agent.step(force, 0.1);
+        }
+        assert!(agent.position.magnitude() > 1.);
+    }
+    #[test]
+    fn steering_avoid_obstacles() {
+        let agent = steering::Agent::new(Vec2::new(0., 0.), Vec2::new(3., 0.), 3., 5.);
+        let obstacles = [(Vec2::new(5., 0.5), 1.), (Vec2::new(3., -0.5), 1.), (Vec2::new(-3., 0.), 1.), (Vec2::new(4., 5.), 1.)];
+        //the nearest obstacle in front is below the path, so steer up
+        let force = agent.avoid_obstacles(&obstacles, 0.5, 6.);
+        assert!(force.y > 0.);
+        assert!((force.magnitude() - 5.).abs() < 1e-4);
+        assert_eq!(Vec2::ZERO, agent.avoid_obstacles(&obstacles[2..], 0.5, 6.));
+        assert_eq!(Vec2::ZERO, agent.avoid_obstacles(&obstacles, 0.5, 1.));
+
+        //driving through a field of obstacles without touching any
+        let field = [(Vec2::new(10., 0.2), 1.5), (Vec2::new(20., -0.3), 1.5), (Vec2::new(30., 0.5), 1.5)];
+        let mut agent = steering::Agent::new(Vec2::new(0., 0.), Vec2::new(3., 0.), 3., 6.);
+        let goal = Vec2::new(40., 0.);
+        for _ in 0..1200 {
+            let force = steering::blend_priority(&[agent.avoid_obstacles(&field, 0.5, 3.), agent.seek(goal)], 6.);
+            agent.step(force, 1. / 60.);
+            for (center, radius) in field {
+                assert!(agent.position.distance_to(center) > radius);
+            }
+        }
+        assert!(agent.position.x > 35.);
+    }
+    #[test]
+    fn steering_follow_path() {
+        let path = [Vec3::new(0., 0., 0.), Vec3::new(10., 0., 0.), Vec3::new(10., 0., 10.), Vec3::new(0., 5., 10.)];
+        let mut agent = steering::Agent::new(Vec3::new(0., 1., 0.), Vec3::ZERO, 3., 12.);
+        let distance_to_path = |point: Vec3| path.windows(2).map(|segment| {
+            let direction = segment[1] - segment[0];
+            let t = ((point - segment[0]).dot(direction) / direction.magnitude_squared()).clamp(0., 1.);
+            point.distance_to(segment[0] + direction * t)
+        }).fold(f32::INFINITY, f32::min);
+        for i in 0..1500 {
+            let force = agent.follow_path(&path, 0.5, 1.);
+            agent.step(force, 1. / 60.);
+            if i > 120 {
+                assert!(distance_to_path(agent.position) < 1.);
+            }
+        }
+        assert!(agent.position.distance_to(path[3]) < 0.1);
+        assert_eq!(Vec3::ZERO, agent.follow_path(&[], 0.5, 1.));
+    }
+    #[test]
+    fn steering_blend() {
+        let forces = [(Vec2::new(1., 0.), 2.), (Vec2::new(0., 3.), 1.)];
+        assert_eq!(Vec2::new(2., 3.), steering::blend_weighted(&forces, 10.));
+        assert!((steering::blend_weighted(&forces, 1.).magnitude() - 1.).abs() < 1e-6);
+        let forces = [Vec2::new(0., 3.), Vec2::new(5., 0.), Vec2::new(0., -4.)];
+        assert_eq!(Vec2::new(2., 3.), steering::blend_priority(&forces, 5.));
+        assert_eq!(Vec2::new(0., 3.), steering::blend_priority(&forces, 3.));
+    }
+
+
+    //---Boids----
+
+    #[test]
+    fn boids_neighbours() {
+        let points = random_points3(500, 40.);
+        let mut flock = boids::Flock::new(boids::FlockSettings::new(5., 1., 1.));
+        for point in &points {
+            flock.add(*point, Vec3::ZERO);
+        }
+        flock.remove(10);
+        let boids = flock.boids();
+
+        for center in random_points3(20, 40.) {
+            let mut found: Vec<usize> = flock.neighbours(center).collect();
+            found.sort();
+            let expected: Vec<usize> = (0..boids.len()).filter(|i| boids[*i].position.distance_to(center) <= 5.).collect();
+            assert_eq!(expected, found);
+        }
+    }
+
+    #[test]
+    fn boids_rules() {
+        let mut settings = boids::FlockSettings::new(5., 2., 4.);
+        settings.alignment_weight = 0.;
+        settings.cohesion_weight = 0.;
+        let mut flock = boids::Flock::new(settings);
+        flock.add(Vec2::new(0., 0.), Vec2::ZERO);
+        flock.add(Vec2::new(1., 0.), Vec2::ZERO);
+        flock.step(0.1);
+        assert!(flock.boids()[0].position.distance_to(flock.boids()[1].position) > 1.);
+
+        let mut settings = boids::FlockSettings::new(5., 2., 4.);
+        settings.separation_weight = 0.;
+        settings.alignment_weight = 0.;
+        let mut flock = boids::Flock::new(settings);
+        flock.add(Vec2::new(0., 0.), Vec2::ZERO);
+        flock.add(Vec2::new(4., 0.), Vec2::ZERO);
+        flock.step(0.1);
+        assert!(flock.boids()[0].position.distance_to(flock.boids()[1].position) < 4.);
+
+        let mut settings = boids::FlockSettings::new(20., 2., 4.);
+        settings.separation_weight = 0.;
+        settings.cohesion_weight = 0.;
+        let mut flock = boids::Flock::new(settings);
+        flock.add(Vec2::new(0., 0.), Vec2::new(2., 0.));
+        flock.add(Vec2::new(0., 2.), Vec2::new(0., 2.));
+        for _ in 0..60 {
+            flock.step(0.05);
+        }
+        let [a, b] = [flock.boids()[0].velocity, flock.boids()[1].velocity];
+        assert!(a.normalized().dot(b.normalized()) > 0.99);
+    }
+
+    #[test]
+    fn boids_large_flock() {
+        let mut flock = boids::Flock::new(boids::FlockSettings::new(3., 5., 10.));
+        let velocities = random_points2(1000, 2.);
+        for (position, velocity) in random_points2(1000, 100.).iter().zip(velocities) {
+            flock.add(*position, velocity - Vec2::ONE);
+        }
+
+        for _ in 0..100 {
+            flock.step(1. / 30.);
+        }
+
+        assert_eq!(1000, flock.len());
+        for boid in flock.boids() {
+            assert!(boid.velocity.magnitude() <= 5. + 1e-4);
+            assert!(boid.position.x.is_finite() && boid.position.y.is_finite());
+        }
+        //boids that end up together fly in a similar direction
+        let boids = flock.boids();
+        let mut agreement = 0.;
+        let mut pairs = 0;
+        for (i, boid) in boids.iter().enumerate() {
+            for j in flock.neighbours(boid.position).filter(|j| *j != i) {
+                agreement += boid.velocity.normalized().dot(boids[j].velocity.normalized());
+                pairs += 1;
+            }
+        }
+        assert!(agreement / pairs as f32 > 0.5);
+    }
+
+
+    //---Pathfinding----
+
+    #[test]
+    fn pathfinding_heuristics() {
+        use pathfinding::{GridPathfinder, Connectivity, Heuristic};
+        let blocked = random_values(40 * 30);
+        let costs = random_values(40 * 30);
+        let cost = |[x, y]: [i32; 2]| {
+            let i = (y * 40 + x) as usize;
+            if blocked[i] < 0.25 { None } else { Some(1. + costs[i] * 3.) }
+        };
+        let goals = random_points2(20, 1.);
+
+        for connectivity in [Connectivity::Orthogonal, Connectivity::Diagonal] {
+            let mut finder = GridPathfinder::new([40, 30], 1.);
+            finder.connectivity = connectivity;
+            for goal in &goals {
+                let start = [0, 0];
+                let goal = [(goal.x * 39.) as i32, (goal.y * 29.) as i32];
+                finder.heuristic = Heuristic::Zero;
+                let dijkstra = finder.find_path_with_costs(start, goal, cost);
+                let admissible: &[Heuristic] = match connectivity {
+                    Connectivity::Orthogonal => &[Heuristic::Manhattan, Heuristic::Octile, Heuristic::Euclidean],
+                    Connectivity::Diagonal => &[Heuristic::Octile, Heuristic::Euclidean],
+                };
+                for heuristic in admissible {
+                    finder.heuristic = *heuristic;
+                    let path = finder.find_path_with_costs(start, goal, cost);
+                    assert_eq!(dijkstra.is_some(), path.is_some());
+                    let (Some(dijkstra), Some(path)) = (&dijkstra, path) else { continue };
+                    assert!((dijkstra.cost - path.cost).abs() < 1e-3);
+
+                    assert_eq!(start, path.cells[0]);
+                    assert_eq!(goal, path.cells[path.cells.len() - 1]);
+                    for pair in path.cells.windows(2) {
+                        let [a, b] = [pair[0], pair[1]];
+                        let moved = (b[0] - a[0]).abs() + (b[1] - a[1]).abs();
+                        assert!(cost(b).is_some());
+                        assert!(moved == 1 || (moved == 2 && connectivity == Connectivity::Diagonal && (b[0] - a[0]).abs() == 1));
+                        //no squeezing past blocked corners
+                        assert!(cost([a[0], b[1]]).is_some() && cost([b[0], a[1]]).is_some());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn pathfinding_smoothing() {
+        use pathfinding::GridPathfinder;
+        let mut finder = GridPathfinder::new([10, 10], 2.);
+        finder.origin = Vec2::new(-10., -10.);
+        finder.smooth = true;
+
+        let path = finder.find_path([0, 0], [9, 4], |_| true).unwrap();
+        assert_eq!(vec![Vec2::new(-9., -9.), Vec2::new(9., -1.)], path.waypoints);
+        assert_eq!([9, 4], finder.cell_at(Vec2::new(9., -1.)));
+
+        //a wall with a gap at the top
+        let passable = |[x, y]: [i32; 2]| x != 5 || y == 9;
+        let path = finder.find_path([0, 0], [9, 0], passable).unwrap();
+        assert!(path.waypoints.len() >= 3 && path.waypoints.len() < path.cells.len());
+        for pair in path.waypoints.windows(2) {
+            assert!(finder.line_of_sight(finder.cell_at(pair[0]), finder.cell_at(pair[1]), passable));
+        }
+        assert!(!finder.line_of_sight([0, 0], [9, 0], passable));
+
+        //diagonal lines can't slip between two blocks touching at a corner
+        let passable = |cell: [i32; 2]| cell != [1, 0] && cell != [0, 1];
+        assert!(!finder.line_of_sight([0, 0], [1, 1], passable));
+        assert!(finder.find_path([0, 0], [1, 1], passable).is_none());
+    }
+
+    #[test]
+    fn pathfinding_3d() {
+        use pathfinding::GridPathfinder;
+        let finder = GridPathfinder::new([6, 6, 6], 1.);
+        //a floor with a hole in the corner
+        let passable = |[x, y, z]: [i32; 3]| y != 3 || (x == 5 && z == 5);
+
+        let path = finder.find_path([0, 0, 0], [0, 5, 0], passable).unwrap();
+        assert!(path.cells.contains(&[5, 3, 5]));
+        assert_eq!(Vec3::new(0.5, 5.5, 0.5), path.waypoints[path.waypoints.len() - 1]);
+        assert!(finder.find_path([0, 0, 0], [0, 3, 0], passable).is_none());
+        assert!(finder.find_path([0, 0, 0], [0, 6, 0], passable).is_none());
+    }
+
+    #[test]
+    fn flow_field_matches_paths() {
+        use pathfinding::GridPathfinder;
+        let blocked = random_values(30 * 30);
+        let costs = random_values(30 * 30);
+        let cost = |[x, y]: [i32; 2]| {
+            let i = (y * 30 + x) as usize;
+            if blocked[i] < 0.2 { None } else { Some(1. + costs[i] * 3.) }
+        };
+        let mut finder = GridPathfinder::new([30, 30], 1.);
+        finder.origin = Vec2::new(5., -3.);
+        let goal = [17, 12];
+        let field = finder.flow_field(&[goal], cost);
+
+        for start in random_points2(50, 30.) {
+            let start = [start.x as i32, start.y as i32];
+            let path = finder.find_path_with_costs(start, goal, cost);
+            assert_eq!(path.is_some(), field.integration(start).is_some());
+            let Some(path) = path else { continue };
+            assert!((path.cost - field.integration(start).unwrap()).abs() < 1e-3);
+
+            //following the directions cell by cell costs as much as the best path
+            let mut cell = start;
+            let mut followed = 0.;
+            while cell != goal {
+                let direction = field.direction(cell);
+                let next = [cell[0] + direction.x.round() as i32, cell[1] + direction.y.round() as i32];
+                followed += Vec2::new((next[0] - cell[0]) as f32, (next[1] - cell[1]) as f32).magnitude() * cost(next).unwrap();
+                cell = next;
+            }
+            assert!((path.cost - followed).abs() < 1e-3);
+            assert_eq!(field.direction(start), field.sample(finder.center(start)));
+        }
+        assert_eq!(Vec2::ZERO, field.direction(goal));
+        assert_eq!(None, field.integration([-1, 0]));
+    }
+
+    #[test]
+    fn navmesh_paths() {
+        use pathfinding::{GridPathfinder, Connectivity, NavMesh};
+        //a sloped grid of quads with holes, some quads split into triangles
+        let blocked = random_values(12 * 12);
+        let split = random_values(12 * 12);
+        let passable = |[x, z]: [i32; 2]| blocked[(z * 12 + x) as usize] > 0.3;
+        let vertices: Vec<Vec3> = (0..13 * 13).map(|i| Vec3::new((i % 13) as f32, (i % 13) as f32 * 0.2, (i / 13) as f32)).collect();
+        let mut polygons = Vec::new();
+        for z in 0..12 {
+            for x in 0..12 {
+                if !passable([x, z]) {
+                    continue;
+                }
+                let corners = [z * 13 + x, z * 13 + x + 1, (z + 1) * 13 + x + 1, (z + 1) * 13 + x].map(|i| i as u32);
+                if split[(z * 12 + x) as usize] < 0.5 {
+                    polygons.push(vec![corners[0], corners[1], corners[2]]);
+                    polygons.push(vec![corners[2], corners[3], corners[0]]);
+                } else {
+                    polygons.push(corners.to_vec());
+                }
+            }
+        }
+        let mesh = NavMesh::new(vertices, &polygons);
+        let mut finder = GridPathfinder::new([12, 12], 1.);
+        finder.connectivity = Connectivity::Orthogonal;
+        let on_mesh = |point: Vec3| mesh.project(point).is_some_and(|projected| (projected.y - point.x * 0.2).abs() < 1e-4);
+
+        let points = random_points2(60, 12.);
+        for pair in points.chunks(2) {
+            let [start, goal] = [pair[0], pair[1]].map(|point| Vec3::new(point.x, point.x * 0.2, point.y));
+            let grid_path = finder.find_path([start.x as i32, start.z as i32], [goal.x as i32, goal.z as i32], passable);
+            let path = mesh.find_path(start, goal);
+            assert_eq!(grid_path.is_some(), path.is_some());
+            let (Some(grid_path), Some(path)) = (grid_path, path) else { continue };
+
+            assert_eq!(start, path.waypoints[0]);
+            assert_eq!(goal, path.waypoints[path.waypoints.len() - 1]);
+            let length: f32 = path.waypoints.windows(2).map(|pair| Vec2::new(pair[0].x, pair[0].z).distance_to(Vec2::new(pair[1].x, pair[1].z))).sum();
+            //the grid path goes through the cell centers, which can be up to half a cell away from start and goal
+            assert!(length <= grid_path.cost + 1.5);
+            for pair in path.waypoints.windows(2) {
+                for i in 0..=10 {
+                    assert!(on_mesh(pair[0].lerp(pair[1], i as f32 / 10.)));
+                }
+            }
+        }
+
+        for pair in random_points2(100, 12.).chunks(2) {
+            let start = Vec3::new(pair[0].x, pair[0].x * 0.2, pair[0].y);
+            let end = Vec3::new(pair[1].x, pair[1].x * 0.2, pair[1].y);
+            if !on_mesh(start) {
+                assert_eq!(None, mesh.raycast(start, end));
+                continue;
+            }
+            let reached = match mesh.raycast(start, end) {
+                Some(hit) => {
+                    assert!((hit.point.y - hit.point.x * 0.2).abs() < 1e-4);
+                    assert!(!on_mesh(hit.point - hit.normal * 0.01));
+                    hit.t
+                }
+                None => 1.,
+            };
+            for i in 0..10 {
+                assert!(on_mesh(start.lerp(end, reached * i as f32 / 10.)));
+            }
+        }
+    }
+
+
+    //---Hex----
+
+    #[test]
+    fn hex_coordinates() {
+        use hex::Hex;
+        let center = Hex::from_cube(2, -5, 3);
+        let spiral = center.spiral(5);
+        assert_eq!(1 + 3 * 5 * 6, spiral.len());
+        for (i, hex) in spiral.iter().enumerate() {
+            assert!(hex.distance_to(center) <= 5);
+            assert!(!spiral[..i].contains(hex));
+            assert_eq!(0, hex.q + hex.r + hex.s());
+            assert_eq!(*hex, hex.rotate_left().rotate_right());
+            assert_eq!(hex.length(), hex.rotate_left().length());
+            assert_eq!(*hex, hex.rotate_around(center, 4).rotate_around(center, 2));
+            assert_eq!(hex.distance_to(center), hex.rotate_around(center, 1).distance_to(center));
+            assert!(hex.neighbours().iter().all(|neighbour| neighbour.distance_to(*hex) == 1));
+            assert!(hex.diagonals().iter().all(|diagonal| diagonal.distance_to(*hex) == 2));
+
+            let line = center.line_to(*hex);
+            assert_eq!(hex.distance_to(center) as usize + 1, line.len());
+            assert!(line.windows(2).all(|pair| pair[0].distance_to(pair[1]) == 1));
+        }
+        for ring in 1..5 {
+            let ring = center.ring(ring);
+            //consecutive hexes of a ring touch, including the last and the first
+            assert!((0..ring.len()).all(|i| ring[i].distance_to(ring[(i + 1) % ring.len()]) == 1));
+        }
+        assert_eq!(Hex::new(1, 1), Hex::DIRECTIONS[0] + Hex::DIRECTIONS[1]);
+    }
+
+    #[test]
+    fn hex_layout() {
+        use hex::{Hex, HexLayout, HexOrientation};
+        for orientation in [HexOrientation::PointyTop, HexOrientation::FlatTop] {
+            let layout = HexLayout::new(orientation, Vec2::new(3., 2.), Vec2::new(-7., 4.));
+            //neighbours go around counter clockwise with y up
+            let unit = HexLayout::new(orientation, Vec2::ONE, Vec2::ZERO);
+            for i in 0..6 {
+                let a = unit.to_pixel(Hex::DIRECTIONS[i]);
+                let b = unit.to_pixel(Hex::DIRECTIONS[(i + 1) % 6]);
+                assert!((a.angle_between(b) - std::f32::consts::FRAC_PI_3).abs() < 1e-4);
+                assert!(a.x * b.y - a.y * b.x > 0.);
+            }
+
+            let offsets = random_points2(200, 2.);
+            for (hex, offset) in Hex::new(3, -1).spiral(6).into_iter().zip(offsets.iter().cycle()) {
+                let center = layout.to_pixel(hex);
+                assert_eq!(hex, layout.to_hex(center));
+                assert!((layout.to_fractional(center) - hex.to_vec2()).magnitude() < 1e-4);
+                //anything inside the circle touching the edges belongs to the hex
+                let offset = (*offset - Vec2::ONE) * 0.43;
+                assert_eq!(hex, layout.to_hex(center + offset * layout.size));
+                for corner in layout.corners(hex) {
+                    assert!((((corner - center) / layout.size).magnitude() - 1.).abs() < 1e-4);
+                }
+            }
+        }
+    }
+
+
+    //---Fixed----
+
+    #[test]
+    fn fixed_scalar() {
+        use fixed::Fixed;
+        for value in random_values(500) {
+            let angle = (value - 0.5) * 100.;
+            let fixed = Fixed::from_f32(angle);
+            assert!((fixed.sin().to_f32() - fixed.to_f32().sin()).abs() < 1e-4);
+            assert!((fixed.cos().to_f32() - fixed.to_f32().cos()).abs() < 1e-4);
+
+            let positive = Fixed::from_f32(value * 1000.);
+            let root = positive.sqrt();
+            assert!((root.to_f32() - positive.to_f32().sqrt()).abs() < 1e-3);
+            assert_eq!(Fixed::from_f32(angle), Fixed::from_bits(fixed.to_bits()));
+            assert!((fixed.to_f32() - angle).abs() <= 1. / 65536.);
+            assert_eq!(angle.floor(), fixed.floor().to_f32());
+            assert_eq!(angle.ceil(), fixed.ceil().to_f32());
+            assert_eq!(angle.floor() as i32, fixed.to_int());
+            let x = Fixed::from_f32(value * 60. - 20.);
+            assert!((fixed.atan2(x).to_f32() - angle.atan2(x.to_f32())).abs() < 1e-4);
+        }
+        assert_eq!(Fixed::ZERO, Fixed::ZERO.atan2(Fixed::ZERO));
+        assert_eq!(Fixed::PI, Fixed::ZERO.atan2(-Fixed::ONE));
+        assert_eq!(-Fixed::FRAC_PI_2, Fixed::MIN.atan2(Fixed::ZERO));
+        assert_eq!(Fixed::ZERO, Fixed::from_int(-4).sqrt());
+        assert_eq!(Fixed::from_int(-1), Fixed::PI.cos());
+        assert_eq!(Fixed::ZERO, Fixed::ZERO.sin());
+        assert_eq!(Fixed::from_int(1), Fixed::from_f32(0.5).round());
+        assert_eq!(Fixed::from_int(-2), Fixed::from_f32(-1.5).floor());
+        assert_eq!(Fixed::from_f32(-0.5), -Fixed::ONE / Fixed::from_int(2));
+        //division rounds like multiplication
+        assert_eq!(Fixed::from_bits(2), Fixed::from_bits(3) / Fixed::from_int(2));
+        assert_eq!(Fixed::from_bits(-1), Fixed::from_bits(-3) / Fixed::from_int(2));
+
+        //range edges
+        let max = Fixed::from_int(32767);
+        //TAU is rounded to 16 fraction bits, so the phase drifts slightly far from 0
+        assert!((max.cos().to_f32() - 32767f32.cos()).abs() < 2e-2);
+        assert!((Fixed::MIN.cos().to_f32() - (-32768f32).cos()).abs() < 2e-2);
+        assert_eq!(max, Fixed::MAX.floor());
+        assert_eq!(Fixed::MIN, Fixed::MIN.floor());
+        assert_eq!(Fixed::MIN, Fixed::MIN.round());
+        assert_eq!(Fixed::MIN, Fixed::MIN.ceil());
+        assert!(std::panic::catch_unwind(|| Fixed::MAX.round()).is_err());
+        assert!(std::panic::catch_unwind(|| Fixed::MAX.ceil()).is_err());
+        assert!(std::panic::catch_unwind(|| Fixed::MIN.abs()).is_err());
+        assert!(std::panic::catch_unwind(|| -Fixed::MIN).is_err());
+        assert!(std::panic::catch_unwind(|| Fixed::MAX + Fixed::EPSILON).is_err());
+        assert!(std::panic::catch_unwind(|| Fixed::MIN - Fixed::EPSILON).is_err());
+        assert!(std::panic::catch_unwind(|| Fixed::from_int(32768)).is_err());
+    }
+
+    #[test]
+    fn fixed_vectors() {
+        use fixed::{Fixed, FixedVec2, FixedVec3};
+        let epsilon = Fixed::from_f32(1e-3);
+        for pair in random_points3(100, 20.).chunks(2) {
+            let [a, b] = [pair[0] - Vec3::ONE * 10., pair[1] - Vec3::ONE * 10.];
+            let [fa, fb] = [FixedVec3::from(a), FixedVec3::from(b)];
+            assert!(fa.to_vec3().equals(a, 1e-4));
+            assert!((fa.magnitude().to_f32() - a.magnitude()).abs() < 1e-3);
+            assert!((fa.dot(fb).to_f32() - a.dot(b)).abs() < 1e-2);
+            assert!(fa.cross(fb).to_vec3().equals(a.cross(b), 1e-2));
+            assert!((fa.normalized().magnitude() - Fixed::ONE).abs() <= epsilon);
+            assert!(fa.clamp_length(Fixed::ONE).magnitude() <= Fixed::ONE + epsilon);
+            assert!(fa.project(fb).to_vec3().equals(a.project(b), 1e-2));
+            assert!(fa.lerp(fb, Fixed::from_f32(0.25)).to_vec3().equals(a.lerp(b, 0.25), 1e-3));
+
+            let [a, b] = [Vec2::new(a.x, a.y), Vec2::new(b.x, b.y)];
+            let [fa, fb] = [FixedVec2::from(a), FixedVec2::from(b)];
+            assert!((fa.cross(fb).to_f32() - (a.x * b.y - a.y * b.x)).abs() < 1e-2);
+            assert!(fa.rotate(Fixed::from_f32(0.7)).to_vec2().equals(a.rotate(0.7), 1e-2));
+            assert!((fa.distance_to(fb).to_f32() - a.distance_to(b)).abs() < 1e-3);
+            assert!((fa.angle_between(fb).to_f32() - a.angle_between(b)).abs() < 1e-3);
+            assert!(fa.add_length_by_angle(Fixed::from_f32(0.7), Fixed::from_int(2)).to_vec2().equals(a.add_length_by_angle(0.7, 2.), 1e-3));
+        }
+        for pair in random_points3(100, 20.).chunks(2) {
+            let [a, b] = [pair[0] - Vec3::ONE * 10., pair[1] - Vec3::ONE * 10.];
+            assert!((FixedVec3::from(a).angle_between(FixedVec3::from(b)).to_f32() - a.angle_between(b)).abs() < 1e-3);
+        }
+        assert_eq!(Fixed::ZERO, FixedVec3::ZERO.angle_between(FixedVec3::UP));
+        assert_eq!(Fixed::PI, FixedVec2::LEFT.angle_between(FixedVec2::RIGHT));
+        let mut vec = FixedVec3::ZERO;
+        vec.set(Fixed::ONE, Fixed::ZERO, Fixed::ZERO);
+        assert_eq!(FixedVec3::RIGHT, vec);
+        assert_eq!(FixedVec2::ZERO, FixedVec2::ZERO.normalized());
+        assert_eq!(FixedVec3::UP, FixedVec3::FORWARD.cross(FixedVec3::RIGHT));
+        assert_eq!(FixedVec3::from(Vec3::BACK), FixedVec3::BACK);
+
+        //longer than Fixed::MAX
+        let large = FixedVec2::new(Fixed::from_int(30000), Fixed::from_int(30000));
+        assert!(large.normalized().to_vec2().equals(Vec2::new(1., 1.).normalized(), 1e-4));
+        assert!(large.clamp_length(Fixed::from_int(10)).to_vec2().equals(Vec2::new(1., 1.).normalized() * 10., 1e-3));
+        assert!(std::panic::catch_unwind(|| large.magnitude()).is_err());
+        let large = FixedVec3::new(Fixed::from_int(-20000), Fixed::from_int(20000), Fixed::from_int(20000));
+        assert!(large.normalized().to_vec3().equals(Vec3::new(-1., 1., 1.).normalized(), 1e-4));
+        assert!(large.clamp_length(Fixed::ONE).to_vec3().equals(Vec3::new(-1., 1., 1.).normalized(), 1e-4));
+        assert!(std::panic::catch_unwind(|| large.magnitude()).is_err());
+    }
+
+    #[test]
+    fn fixed_golden_values() {
+        use fixed::{Fixed, FixedVec2};
+        //a small simulation whose result has to be the same on every platform
+        let mut position = FixedVec2::from(Vec2::new(1.25, -3.5));
+        let mut velocity = FixedVec2::new(Fixed::from_f32(0.3), Fixed::from_f32(0.1));
+        let dt = Fixed::from_int(1) / Fixed::from_int(60);
+        for step in 0..600 {
+            velocity = velocity.rotate(Fixed::from_f32(0.01) * Fixed::from_int(step % 7 - 3));
+            velocity = (velocity + FixedVec2::DOWN * dt).clamp_length(Fixed::from_int(2));
+            position += velocity * dt;
+        }
+        assert_eq!([162245, -1396307, 7564], [position.x.to_bits(), position.y.to_bits(), position.normalized().x.to_bits()]);
+    }
+
+
+    //---Libm----
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn libm_golden_values() {
+        //the software math gives these exact bits on every platform
+        let vec2 = Vec2::new(0.3, -1.7);
+        let vec3 = Vec3::new(2.1, 0.4, -0.9);
+        let bits = [
+            vec2.magnitude(),
+            vec2.normalized().x,
+            vec2.rotate(0.8).x,
+            vec2.rotate(0.8).y,
+            vec2.angle_between(Vec2::new(-1.2, 0.5)),
+            vec2.to_polar().1.0,
+            vec3.magnitude(),
+            vec3.normalized().z,
+            vec3.angle_between(Vec3::new(0.3, 1.1, 0.2)),
+            vec3.to_spherical().1.0,
+            vec3.to_spherical().2.0,
+            Vec3::from_spherical(1.5, 0.7, -2.3).x,
+            Radians(1.2).tan(),
+        ].map(f32::to_bits);
+        assert_eq!([
+            1071445591, 1043461320, 1068947881, 3212320034, 1074330116, 3216159794, 1075081831,
+            3200690749, 1067307202, 1068687500, 1073537881, 3208149221, 1076141603,
+        ], bits);
+    }
 }
@@ -0,0 +1,13 @@
+mod source;
+mod perlin;
+mod simplex;
+mod fractal;
+mod warp;
+mod worley;
+
+pub use self::source::Noise;
+pub use self::perlin::Perlin;
+pub use self::simplex::Simplex;
+pub use self::fractal::{Fractal, FractalKind};
+pub use self::warp::DomainWarp;
+pub use self::worley::{Worley, WorleySample};
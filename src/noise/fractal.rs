@@ -0,0 +1,89 @@
+use crate::Vector;
+use crate::noise::Noise;
+
+
+//shifts every octave so their lattices don't line up at the origin
+const OCTAVE_OFFSET: f32 = 31.416;
+
+
+///How the octaves of a Fractal are combined.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum FractalKind {
+    ///Fractal brownian motion, the plain sum of the octaves. Rolling hills and clouds.
+    Fbm,
+    ///Sharp ridges where the octaves cross zero. Mountain ranges.
+    Ridged,
+    ///Rounded bumps with creases where the octaves cross zero. Puffy clouds and rocks.
+    Billow,
+}
+
+///Combines several octaves of a noise source with increasing frequency and decreasing amplitude.
+/// Works with any source sampled by Vec2 or Vec3 and stays in the range -1.0 to 1.0.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::noise::{Fractal, FractalKind, Noise, Simplex};
+/// let mut terrain = Fractal::new(Simplex::new(3));
+/// terrain.octaves = 6;
+/// terrain.kind = FractalKind::Ridged;
+///
+/// let (height, slope) = terrain.sample_gradient(Vec2::new(10.5, 3.25));
+///
+/// assert!((-1.0..=1.0).contains(&height));
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct Fractal<N> {
+    pub source: N,
+    pub kind: FractalKind,
+    pub octaves: u32,
+    ///Factor the frequency grows by from one octave to the next.
+    pub lacunarity: f32,
+    ///Factor the amplitude shrinks by from one octave to the next.
+    pub gain: f32,
+}
+
+impl<N> Fractal<N> {
+    ///Creates fractal brownian motion with 4 octaves, a lacunarity of 2.0 and a gain of 0.5.
+    pub fn new(source: N) -> Fractal<N> {
+        Fractal { source, kind: FractalKind::Fbm, octaves: 4, lacunarity: 2.0, gain: 0.5 }
+    }
+}
+
+impl<V: Vector, N: Noise<V>> Noise<V> for Fractal<N> {
+    fn sample(&self, point: V) -> f32 {
+        self.sample_gradient(point).0
+    }
+
+    fn sample_gradient(&self, point: V) -> (f32, V) {
+        let mut value = 0.0;
+        let mut gradient = V::ZERO;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        let mut total_amplitude = 0.0;
+        for octave in 0..self.octaves {
+            let offset = V::ONE * (octave as f32 * OCTAVE_OFFSET);
+            let (octave_value, octave_gradient) = self.source.sample_gradient(point * frequency + offset);
+            //map every octave back to -1.0 to 1.0 so the sum stays in range
+            let (octave_value, octave_gradient) = match self.kind {
+                FractalKind::Fbm => (octave_value, octave_gradient),
+                FractalKind::Billow => (octave_value.abs() * 2.0 - 1.0, octave_gradient * (2.0 * octave_value.signum())),
+                FractalKind::Ridged => {
+                    let ridge = 1.0 - octave_value.abs();
+                    (ridge * ridge * 2.0 - 1.0, octave_gradient * (-4.0 * ridge * octave_value.signum()))
+                }
+            };
+
+            value += octave_value * amplitude;
+            gradient += octave_gradient * (amplitude * frequency);
+            total_amplitude += amplitude;
+            amplitude *= self.gain;
+            frequency *= self.lacunarity;
+        }
+
+        if total_amplitude == 0.0 {
+            return (0.0, V::ZERO);
+        }
+        (value / total_amplitude, gradient / total_amplitude)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{Vec2, Vec3};
+use crate::noise::Noise;
+use crate::noise::source::{Permutation, gradient, dot, from_vec2, to_vec2, from_vec3, to_vec3};
+
+
+///Seeded improved Perlin noise in 2, 3 and 4 dimensions.
+/// Only uses basic arithmetic, so the same seed gives the same values on every platform.
+/// The noise repeats every 256 units along each axis and is 0.0 at every integer point.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::{Vec2, Vec3};
+/// use gamevecs::noise::{Noise, Perlin};
+/// let perlin = Perlin::new(42);
+///
+/// let height = perlin.sample(Vec2::new(3.7, 1.2));
+/// let (value, gradient) = perlin.sample_gradient(Vec3::new(3.7, 1.2, 0.5));
+///
+/// assert!((-1.0..=1.0).contains(&height));
+/// assert_eq!(value, perlin.sample(Vec3::new(3.7, 1.2, 0.5)));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Perlin {
+    seed: u32,
+    permutation: Permutation,
+}
+
+impl Perlin {
+    pub fn new(seed: u32) -> Perlin {
+        Perlin { seed, permutation: Permutation::new(seed) }
+    }
+
+    ///Returns the seed this noise was created with.
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    fn evaluate<const N: usize>(&self, point: [f32; N]) -> (f32, [f32; N]) {
+        let mut cell = [0; N];
+        let mut local = [0.0; N];
+        let mut fade = [0.0; N];
+        let mut fade_derivative = [0.0; N];
+        for i in 0..N {
+            let floor = point[i].floor();
+            cell[i] = floor as i32;
+            local[i] = point[i] - floor;
+            let t = local[i];
+            fade[i] = t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+            fade_derivative[i] = 30.0 * t * t * (t * (t - 2.0) + 1.0);
+        }
+
+        //blend the gradient ramps of all corners of the cell
+        let mut value = 0.0;
+        let mut derivative = [0.0; N];
+        for corner in 0..1usize << N {
+            let mut corner_cell = cell;
+            let mut offset = local;
+            let mut weights = [0.0; N];
+            for i in 0..N {
+                if corner >> i & 1 == 1 {
+                    corner_cell[i] = corner_cell[i].wrapping_add(1);
+                    offset[i] -= 1.0;
+                    weights[i] = fade[i];
+                } else {
+                    weights[i] = 1.0 - fade[i];
+                }
+            }
+
+            let direction = gradient::<N>(self.permutation.hash(corner_cell));
+            let ramp = dot(direction, offset);
+            let weight: f32 = weights.iter().product();
+            value += weight * ramp;
+            for k in 0..N {
+                let mut weight_derivative = if corner >> k & 1 == 1 { fade_derivative[k] } else { -fade_derivative[k] };
+                for (i, other) in weights.iter().enumerate() {
+                    if i != k {
+                        weight_derivative *= other;
+                    }
+                }
+                derivative[k] += weight * direction[k] + ramp * weight_derivative;
+            }
+        }
+
+        //the gradients have a magnitude of 1, so the ramps never exceed half the diagonal of a cell
+        let scale = match N {
+            2 => std::f32::consts::SQRT_2,
+            3 => 1.1547005,
+            _ => 1.0,
+        };
+        for component in derivative.iter_mut() {
+            *component *= scale;
+        }
+        (value * scale, derivative)
+    }
+}
+
+impl Noise<Vec2> for Perlin {
+    fn sample(&self, point: Vec2) -> f32 {
+        self.evaluate(from_vec2(point)).0
+    }
+    fn sample_gradient(&self, point: Vec2) -> (f32, Vec2) {
+        let (value, gradient) = self.evaluate(from_vec2(point));
+        (value, to_vec2(gradient))
+    }
+}
+
+impl Noise<Vec3> for Perlin {
+    fn sample(&self, point: Vec3) -> f32 {
+        self.evaluate(from_vec3(point)).0
+    }
+    fn sample_gradient(&self, point: Vec3) -> (f32, Vec3) {
+        let (value, gradient) = self.evaluate(from_vec3(point));
+        (value, to_vec3(gradient))
+    }
+}
+
+impl Noise<[f32; 4]> for Perlin {
+    fn sample(&self, point: [f32; 4]) -> f32 {
+        self.evaluate(point).0
+    }
+    fn sample_gradient(&self, point: [f32; 4]) -> (f32, [f32; 4]) {
+        self.evaluate(point)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{Vec2, Vec3};
+
+
+///A coherent noise function that can be sampled at points of type P.
+/// Implemented by Perlin and Simplex for Vec2, Vec3 and [f32; 4].
+pub trait Noise<P> {
+    ///Returns the value of the noise at the point, in the range -1.0 to 1.0.
+    fn sample(&self, point: P) -> f32;
+    ///Returns the value of the noise at the point together with its analytic gradient.
+    fn sample_gradient(&self, point: P) -> (f32, P);
+}
+
+//shuffled table of all bytes used to hash lattice points, created from a seed
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub(crate) struct Permutation {
+    table: [u8; 256],
+}
+
+impl Permutation {
+    pub(crate) fn new(seed: u32) -> Permutation {
+        let mut table = [0u8; 256];
+        for (i, entry) in table.iter_mut().enumerate() {
+            *entry = i as u8;
+        }
+
+        //fisher-yates with a splitmix generator, integer only so every platform builds the same table
+        let mut state = seed as u64;
+        for i in (1..256).rev() {
+            state = state.wrapping_add(0x9E3779B97F4A7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            z ^= z >> 31;
+            table.swap(i, (z % (i as u64 + 1)) as usize);
+        }
+
+        Permutation { table }
+    }
+
+    //hashes a lattice point, repeating every 256 units
+    pub(crate) fn hash<const N: usize>(&self, cell: [i32; N]) -> u8 {
+        let mut hash = 0u8;
+        for coordinate in cell {
+            hash = self.table[(hash ^ coordinate as u8) as usize];
+        }
+        hash
+    }
+}
+
+const DIAGONAL: f32 = std::f32::consts::FRAC_1_SQRT_2;
+const EDGE3: f32 = std::f32::consts::FRAC_1_SQRT_2;
+const EDGE4: f32 = 0.57735026;
+
+//unit length gradient directions, flattened
+const GRADIENTS2: [f32; 16] = [
+    1.0, 0.0, -1.0, 0.0, 0.0, 1.0, 0.0, -1.0,
+    DIAGONAL, DIAGONAL, -DIAGONAL, DIAGONAL, DIAGONAL, -DIAGONAL, -DIAGONAL, -DIAGONAL,
+];
+//midpoints of the edges of a cube
+const GRADIENTS3: [f32; 36] = [
+    EDGE3, EDGE3, 0.0, -EDGE3, EDGE3, 0.0, EDGE3, -EDGE3, 0.0, -EDGE3, -EDGE3, 0.0,
+    EDGE3, 0.0, EDGE3, -EDGE3, 0.0, EDGE3, EDGE3, 0.0, -EDGE3, -EDGE3, 0.0, -EDGE3,
+    0.0, EDGE3, EDGE3, 0.0, -EDGE3, EDGE3, 0.0, EDGE3, -EDGE3, 0.0, -EDGE3, -EDGE3,
+];
+//midpoints of the edges of a tesseract
+const GRADIENTS4: [f32; 128] = [
+    0.0, EDGE4, EDGE4, EDGE4, 0.0, EDGE4, EDGE4, -EDGE4, 0.0, EDGE4, -EDGE4, EDGE4, 0.0, EDGE4, -EDGE4, -EDGE4,
+    0.0, -EDGE4, EDGE4, EDGE4, 0.0, -EDGE4, EDGE4, -EDGE4, 0.0, -EDGE4, -EDGE4, EDGE4, 0.0, -EDGE4, -EDGE4, -EDGE4,
+    EDGE4, 0.0, EDGE4, EDGE4, EDGE4, 0.0, EDGE4, -EDGE4, EDGE4, 0.0, -EDGE4, EDGE4, EDGE4, 0.0, -EDGE4, -EDGE4,
+    -EDGE4, 0.0, EDGE4, EDGE4, -EDGE4, 0.0, EDGE4, -EDGE4, -EDGE4, 0.0, -EDGE4, EDGE4, -EDGE4, 0.0, -EDGE4, -EDGE4,
+    EDGE4, EDGE4, 0.0, EDGE4, EDGE4, EDGE4, 0.0, -EDGE4, EDGE4, -EDGE4, 0.0, EDGE4, EDGE4, -EDGE4, 0.0, -EDGE4,
+    -EDGE4, EDGE4, 0.0, EDGE4, -EDGE4, EDGE4, 0.0, -EDGE4, -EDGE4, -EDGE4, 0.0, EDGE4, -EDGE4, -EDGE4, 0.0, -EDGE4,
+    EDGE4, EDGE4, EDGE4, 0.0, EDGE4, EDGE4, -EDGE4, 0.0, EDGE4, -EDGE4, EDGE4, 0.0, EDGE4, -EDGE4, -EDGE4, 0.0,
+    -EDGE4, EDGE4, EDGE4, 0.0, -EDGE4, EDGE4, -EDGE4, 0.0, -EDGE4, -EDGE4, EDGE4, 0.0, -EDGE4, -EDGE4, -EDGE4, 0.0,
+];
+
+//returns the gradient direction belonging to a hash
+pub(crate) fn gradient<const N: usize>(hash: u8) -> [f32; N] {
+    let table: &[f32] = match N {
+        2 => &GRADIENTS2,
+        3 => &GRADIENTS3,
+        4 => &GRADIENTS4,
+        _ => unreachable!("noise is only implemented for 2 to 4 dimensions"),
+    };
+    let index = hash as usize % (table.len() / N);
+    let mut gradient = [0.0; N];
+    gradient.copy_from_slice(&table[index * N..(index + 1) * N]);
+    gradient
+}
+
+pub(crate) fn dot<const N: usize>(a: [f32; N], b: [f32; N]) -> f32 {
+    let mut sum = 0.0;
+    for i in 0..N {
+        sum += a[i] * b[i];
+    }
+    sum
+}
+
+//conversions between the public point types and the arrays used internally
+
+pub(crate) fn from_vec2(point: Vec2) -> [f32; 2] {
+    [point.x, point.y]
+}
+
+pub(crate) fn to_vec2(point: [f32; 2]) -> Vec2 {
+    Vec2::new(point[0], point[1])
+}
+
+pub(crate) fn from_vec3(point: Vec3) -> [f32; 3] {
+    [point.x, point.y, point.z]
+}
+
+pub(crate) fn to_vec3(point: [f32; 3]) -> Vec3 {
+    Vec3::new(point[0], point[1], point[2])
+}
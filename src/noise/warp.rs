@@ -0,0 +1,89 @@
+use crate::{Vec2, Vec3};
+use crate::noise::{Noise, Simplex};
+
+
+//sample positions of the warp field for the different axes, far enough apart to be unrelated
+const AXIS_OFFSETS: [f32; 3] = [0.0, 47.3, 91.7];
+
+
+///Distorts a noise source by moving every sample point along another noise field.
+/// Turns regular noise into swirling, eroded looking patterns.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::noise::{DomainWarp, Fractal, Noise, Perlin};
+/// let warped = DomainWarp::simplex(Fractal::new(Perlin::new(1)), 2, 0.8);
+///
+/// let (value, gradient) = warped.sample_gradient(Vec2::new(4.2, 1.7));
+///
+/// assert!((-1.0..=1.0).contains(&value));
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct DomainWarp<N, W = Simplex> {
+    pub source: N,
+    ///Noise field sampled once per axis to offset the sample points.
+    pub warp: W,
+    ///Maximum distance a sample point gets moved along every axis.
+    pub strength: f32,
+    ///Frequency the warp field gets sampled at.
+    pub frequency: f32,
+}
+
+impl<N, W> DomainWarp<N, W> {
+    ///Creates a warp with a frequency of 1.0.
+    pub fn new(source: N, warp: W, strength: f32) -> DomainWarp<N, W> {
+        DomainWarp { source, warp, strength, frequency: 1.0 }
+    }
+}
+
+impl<N> DomainWarp<N, Simplex> {
+    ///Creates a warp driven by simplex noise with the given seed.
+    pub fn simplex(source: N, seed: u32, strength: f32) -> DomainWarp<N, Simplex> {
+        DomainWarp::new(source, Simplex::new(seed), strength)
+    }
+}
+
+impl<N: Noise<Vec2>, W: Noise<Vec2>> Noise<Vec2> for DomainWarp<N, W> {
+    fn sample(&self, point: Vec2) -> f32 {
+        let sample_point = point * self.frequency;
+        let offset = Vec2::new(
+            self.warp.sample(sample_point + Vec2::ONE * AXIS_OFFSETS[0]),
+            self.warp.sample(sample_point + Vec2::ONE * AXIS_OFFSETS[1]));
+        self.source.sample(point + offset * self.strength)
+    }
+
+    fn sample_gradient(&self, point: Vec2) -> (f32, Vec2) {
+        let sample_point = point * self.frequency;
+        let (x, x_gradient) = self.warp.sample_gradient(sample_point + Vec2::ONE * AXIS_OFFSETS[0]);
+        let (y, y_gradient) = self.warp.sample_gradient(sample_point + Vec2::ONE * AXIS_OFFSETS[1]);
+        let (value, gradient) = self.source.sample_gradient(point + Vec2::new(x, y) * self.strength);
+
+        //chain rule through the moved sample point
+        let scale = self.strength * self.frequency;
+        (value, gradient + (x_gradient * gradient.x + y_gradient * gradient.y) * scale)
+    }
+}
+
+impl<N: Noise<Vec3>, W: Noise<Vec3>> Noise<Vec3> for DomainWarp<N, W> {
+    fn sample(&self, point: Vec3) -> f32 {
+        let sample_point = point * self.frequency;
+        let offset = Vec3::new(
+            self.warp.sample(sample_point + Vec3::ONE * AXIS_OFFSETS[0]),
+            self.warp.sample(sample_point + Vec3::ONE * AXIS_OFFSETS[1]),
+            self.warp.sample(sample_point + Vec3::ONE * AXIS_OFFSETS[2]));
+        self.source.sample(point + offset * self.strength)
+    }
+
+    fn sample_gradient(&self, point: Vec3) -> (f32, Vec3) {
+        let sample_point = point * self.frequency;
+        let (x, x_gradient) = self.warp.sample_gradient(sample_point + Vec3::ONE * AXIS_OFFSETS[0]);
+        let (y, y_gradient) = self.warp.sample_gradient(sample_point + Vec3::ONE * AXIS_OFFSETS[1]);
+        let (z, z_gradient) = self.warp.sample_gradient(sample_point + Vec3::ONE * AXIS_OFFSETS[2]);
+        let (value, gradient) = self.source.sample_gradient(point + Vec3::new(x, y, z) * self.strength);
+
+        //chain rule through the moved sample point
+        let scale = self.strength * self.frequency;
+        (value, gradient + (x_gradient * gradient.x + y_gradient * gradient.y + z_gradient * gradient.z) * scale)
+    }
+}
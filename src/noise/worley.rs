@@ -0,0 +1,137 @@
+use crate::{Vec2, Vec3};
+use crate::noise::source::{from_vec2, to_vec2, from_vec3, to_vec3};
+
+
+///Result of sampling Worley noise.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct WorleySample<V> {
+    ///Distance to the nearest feature point.
+    pub f1: f32,
+    ///Distance to the second nearest feature point.
+    pub f2: f32,
+    ///Random id of the cell of the nearest feature point. Equal for all points of a Voronoi cell.
+    pub cell: u32,
+    ///Position of the nearest feature point.
+    pub nearest: V,
+}
+
+///Seeded Worley (cellular) noise, with one randomly placed feature point in every unit cell of the lattice.
+/// Gives distances to the nearest feature points and an id for the Voronoi cell a point belongs to.
+/// Only uses integer hashing and basic arithmetic, so the same seed gives the same values on every platform.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::noise::Worley;
+/// let worley = Worley::new(9);
+/// let sample = worley.sample2(Vec2::new(3.3, 8.1));
+///
+/// //pick a biome per cell and carve caves along the cell borders
+/// let biome = sample.cell % 4;
+/// let cave = sample.f2 - sample.f1 < 0.1;
+///
+/// assert!(sample.f1 <= sample.f2);
+/// assert_eq!(sample.f1, sample.nearest.distance_to(Vec2::new(3.3, 8.1)));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Worley {
+    seed: u32,
+}
+
+impl Worley {
+    pub fn new(seed: u32) -> Worley {
+        Worley { seed }
+    }
+
+    ///Returns the seed this noise was created with.
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    ///Samples the noise at a 2D point.
+    pub fn sample2(&self, point: Vec2) -> WorleySample<Vec2> {
+        let (f1, f2, cell, nearest) = self.evaluate(from_vec2(point));
+        WorleySample { f1, f2, cell, nearest: to_vec2(nearest) }
+    }
+
+    ///Samples the noise at a 3D point.
+    pub fn sample3(&self, point: Vec3) -> WorleySample<Vec3> {
+        let (f1, f2, cell, nearest) = self.evaluate(from_vec3(point));
+        WorleySample { f1, f2, cell, nearest: to_vec3(nearest) }
+    }
+
+    fn evaluate<const N: usize>(&self, point: [f32; N]) -> (f32, f32, u32, [f32; N]) {
+        let mut cell = [0; N];
+        for i in 0..N {
+            cell[i] = point[i].floor() as i32;
+        }
+
+        //feature points can lie anywhere in their cell, so a point one cell away on every side can still be closer
+        //than the ones next to the point. anything outside of the 5 cell wide block is at least 2 units away along one axis.
+        //the inner 3 cell wide block is searched first, so most cells of the outer shell can be skipped
+        let mut f1 = f32::INFINITY;
+        let mut f2 = f32::INFINITY;
+        let mut nearest_id = 0;
+        let mut nearest = point;
+        for outer in [false, true] {
+            for neighbour in 0..5usize.pow(N as u32) {
+                let mut neighbour_cell = cell;
+                let mut code = neighbour;
+                let mut on_shell = false;
+                let mut bound_squared = 0.0;
+                for i in 0..N {
+                    let offset = (code % 5) as i32 - 2;
+                    code /= 5;
+                    on_shell |= offset.abs() == 2;
+                    neighbour_cell[i] = neighbour_cell[i].wrapping_add(offset);
+                    //distance from the point to the nearest face of the cell
+                    let gap = (neighbour_cell[i] as f32 - point[i]).max(point[i] - (neighbour_cell[i] as f32 + 1.0)).max(0.0);
+                    bound_squared += gap * gap;
+                }
+                if on_shell != outer || bound_squared >= f2 {
+                    continue;
+                }
+
+                let id = hash(self.seed, neighbour_cell);
+                let mut feature = [0.0; N];
+                let mut distance_squared = 0.0;
+                let mut jitter = id;
+                for i in 0..N {
+                    jitter = mix(jitter ^ i as u32);
+                    feature[i] = neighbour_cell[i] as f32 + (jitter >> 8) as f32 / (1 << 24) as f32;
+                    let difference = feature[i] - point[i];
+                    distance_squared += difference * difference;
+                }
+
+                if distance_squared < f1 {
+                    f2 = f1;
+                    f1 = distance_squared;
+                    nearest_id = id;
+                    nearest = feature;
+                } else if distance_squared < f2 {
+                    f2 = distance_squared;
+                }
+            }
+        }
+
+        (f1.sqrt(), f2.sqrt(), nearest_id, nearest)
+    }
+}
+
+//hashes a lattice cell together with the seed
+fn hash<const N: usize>(seed: u32, cell: [i32; N]) -> u32 {
+    let mut hash = mix(seed);
+    for coordinate in cell {
+        hash = mix(hash ^ coordinate as u32);
+    }
+    hash
+}
+
+//murmur3 finalizer
+fn mix(mut value: u32) -> u32 {
+    value ^= value >> 16;
+    value = value.wrapping_mul(0x85EBCA6B);
+    value ^= value >> 13;
+    value = value.wrapping_mul(0xC2B2AE35);
+    value ^ (value >> 16)
+}
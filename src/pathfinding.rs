@@ -0,0 +1,8 @@
+mod search;
+mod grid;
+mod flow_field;
+mod navmesh;
+
+pub use self::grid::{GridCell, Connectivity, Heuristic, GridPathfinder, GridPath};
+pub use self::flow_field::FlowField;
+pub use self::navmesh::{NavMesh, NavPath, NavHit};
@@ -0,0 +1,120 @@
+use crate::Vec2;
+use crate::pathfinding::GridPathfinder;
+use crate::pathfinding::search::dijkstra;
+
+
+///Directions towards the nearest goal for every cell of a 2D grid, so any number of units can find their way by looking up where they stand.
+/// Created by GridPathfinder::flow_field.
+#[derive(PartialEq, Debug, Clone)]
+pub struct FlowField {
+    grid: GridPathfinder<[i32; 2]>,
+    integration: Vec<f32>,
+    directions: Vec<Vec2>,
+}
+
+impl GridPathfinder<[i32; 2]> {
+    ///Builds a flow field towards the goals, using the connectivity of this pathfinder.
+    /// cost returns the cost of entering a cell, or None if the cell is blocked.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::pathfinding::GridPathfinder;
+    /// let finder = GridPathfinder::new([20, 20], 1.0);
+    /// //a wall with a gap at the bottom
+    /// let field = finder.flow_field(&[[15, 10]], |[x, y]| if x == 10 && y > 2 { None } else { Some(1.0) });
+    ///
+    /// let mut unit = Vec2::new(2.5, 15.5);
+    /// for _ in 0..400 {
+    ///     let direction = field.sample(unit);
+    ///     if direction != Vec2::ZERO {
+    ///         unit += direction.normalized() * 0.1;
+    ///     }
+    /// }
+    ///
+    /// assert!(unit.distance_to(Vec2::new(15.5, 10.5)) < 0.5);
+    /// ```
+    pub fn flow_field(&self, goals: &[[i32; 2]], cost: impl Fn([i32; 2]) -> Option<f32>) -> FlowField {
+        let passable = |cell: [i32; 2]| self.contains(cell) && cost(cell).is_some();
+        let offsets = self.offsets();
+        let cell_count = (self.size[0] * self.size[1]) as usize;
+
+        //searches backwards from the goals, so every step costs what the cell it leaves costs
+        let integration = dijkstra(
+            cell_count,
+            goals.iter().filter(|goal| passable(**goal)).map(|goal| self.index(*goal)),
+            |node, neighbours| {
+                let cell = self.cell(node);
+                let cell_cost = cost(cell).unwrap();
+                for (previous, length) in self.moves(cell, &offsets, &passable) {
+                    neighbours.push((self.index(previous), length * cell_cost));
+                }
+            },
+        );
+
+        let directions = (0..cell_count).map(|node| {
+            let cell = self.cell(node);
+            if integration[node] == 0.0 || integration[node] == f32::INFINITY {
+                return Vec2::ZERO;
+            }
+            //the neighbour along the cheapest way to the goal
+            let best = self.moves(cell, &offsets, &passable)
+                .map(|(next, length)| (next, length * cost(next).unwrap() + integration[self.index(next)]))
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+            match best {
+                Some((next, _)) => Vec2::new((next[0] - cell[0]) as f32, (next[1] - cell[1]) as f32).normalized(),
+                None => Vec2::ZERO,
+            }
+        }).collect();
+
+        FlowField { grid: *self, integration, directions }
+    }
+}
+
+impl FlowField {
+    ///Returns the layout of the grid of this field.
+    pub fn grid(&self) -> &GridPathfinder<[i32; 2]> {
+        &self.grid
+    }
+
+    ///Returns the cost of getting from a cell to the nearest goal, or None if the cell is outside the grid or can't reach a goal.
+    pub fn integration(&self, cell: [i32; 2]) -> Option<f32> {
+        if !self.grid.contains(cell) {
+            return None;
+        }
+        Some(self.integration[self.grid.index(cell)]).filter(|cost| cost.is_finite())
+    }
+
+    ///Returns the normalized direction to move in from a cell.
+    /// Returns ZERO at goals, outside the grid and in cells that can't reach a goal.
+    pub fn direction(&self, cell: [i32; 2]) -> Vec2 {
+        if !self.grid.contains(cell) {
+            return Vec2::ZERO;
+        }
+        self.directions[self.grid.index(cell)]
+    }
+
+    ///Returns the direction at a position in the world, blended bilinearly between the centers of the closest cells.
+    /// Cells that can't reach a goal are left out of the blend. The result is shorter than 1 where directions differ, so normalize it if needed.
+    pub fn sample(&self, position: Vec2) -> Vec2 {
+        let local = (position - self.grid.origin) / self.grid.cell_size - Vec2::new(0.5, 0.5);
+        let (x, y) = (local.x.floor(), local.y.floor());
+        let (fx, fy) = (local.x - x, local.y - y);
+
+        let mut sum = Vec2::ZERO;
+        let mut weight_sum = 0.0;
+        for (dx, dy, weight) in [(0, 0, (1.0 - fx) * (1.0 - fy)), (1, 0, fx * (1.0 - fy)), (0, 1, (1.0 - fx) * fy), (1, 1, fx * fy)] {
+            let cell = [x as i32 + dx, y as i32 + dy];
+            if self.integration(cell).is_some() && weight > 0.0 {
+                sum += self.direction(cell) * weight;
+                weight_sum += weight;
+            }
+        }
+
+        //avoid division by 0
+        if weight_sum == 0.0 {
+            return Vec2::ZERO;
+        }
+        sum / weight_sum
+    }
+}
@@ -0,0 +1,356 @@
+use std::fmt::Debug;
+
+use crate::{Vec2, Vec3, Vector};
+use crate::pathfinding::search::astar;
+
+
+///Integer coordinates of a cell in a 2D or 3D grid.
+/// Implemented by [i32; 2] with Vec2 positions and [i32; 3] with Vec3 positions.
+pub trait GridCell: Copy + Eq + Debug + Default + AsRef<[i32]> + AsMut<[i32]> {
+    ///Vector type of positions in the world.
+    type World: Vector;
+
+    ///Returns the center of this cell in the world.
+    fn center(&self, origin: Self::World, cell_size: f32) -> Self::World;
+
+    ///Returns the cell containing a position in the world.
+    fn containing(position: Self::World, origin: Self::World, cell_size: f32) -> Self;
+}
+
+impl GridCell for [i32; 2] {
+    type World = Vec2;
+
+    fn center(&self, origin: Vec2, cell_size: f32) -> Vec2 {
+        origin + Vec2::new(self[0] as f32 + 0.5, self[1] as f32 + 0.5) * cell_size
+    }
+
+    fn containing(position: Vec2, origin: Vec2, cell_size: f32) -> [i32; 2] {
+        let local = (position - origin) / cell_size;
+        [local.x.floor() as i32, local.y.floor() as i32]
+    }
+}
+
+impl GridCell for [i32; 3] {
+    type World = Vec3;
+
+    fn center(&self, origin: Vec3, cell_size: f32) -> Vec3 {
+        origin + Vec3::new(self[0] as f32 + 0.5, self[1] as f32 + 0.5, self[2] as f32 + 0.5) * cell_size
+    }
+
+    fn containing(position: Vec3, origin: Vec3, cell_size: f32) -> [i32; 3] {
+        let local = (position - origin) / cell_size;
+        [local.x.floor() as i32, local.y.floor() as i32, local.z.floor() as i32]
+    }
+}
+
+
+///Which cells count as neighbours.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Connectivity {
+    ///Only cells sharing a side: 4 neighbours in 2D, 6 in 3D.
+    Orthogonal,
+    ///All surrounding cells: 8 neighbours in 2D, 26 in 3D.
+    /// Diagonal moves that would cut the corner of a blocked cell are not allowed.
+    Diagonal,
+}
+
+///Estimate of the remaining cost used to guide A*. Measured in cells.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Heuristic {
+    ///No estimate, which turns A* into Dijkstra's algorithm.
+    Zero,
+    ///Sum of the distances along every axis. Exact for orthogonal moves, overestimates with diagonal ones.
+    Manhattan,
+    ///Exact for diagonal moves on an empty grid.
+    Octile,
+    ///Straight line distance. Never overestimates, but guides the search less than octile.
+    Euclidean,
+}
+
+impl Heuristic {
+    fn estimate(&self, offset: &[i32]) -> f32 {
+        match self {
+            Heuristic::Zero => 0.0,
+            Heuristic::Manhattan => offset.iter().map(|d| d.abs() as f32).sum(),
+            Heuristic::Octile => {
+                //every diagonal step covering i + 1 axes at once costs sqrt(i + 1) instead of i + 1
+                let mut distances = [0; 3];
+                for (distance, d) in distances.iter_mut().zip(offset) {
+                    *distance = d.abs();
+                }
+                distances.sort_unstable_by(|a, b| b.cmp(a));
+                distances.iter().enumerate().map(|(i, d)| (((i + 1) as f32).sqrt() - (i as f32).sqrt()) * *d as f32).sum()
+            }
+            Heuristic::Euclidean => offset.iter().map(|d| (d * d) as f32).sum::<f32>().sqrt(),
+        }
+    }
+}
+
+
+///Path found by a GridPathfinder.
+#[derive(PartialEq, Debug, Clone)]
+pub struct GridPath<C: GridCell> {
+    ///Every cell of the path from start to goal.
+    pub cells: Vec<C>,
+    ///Centers of the cells to walk through in the world, from start to goal.
+    /// Only the corners of the path if it was smoothed.
+    pub waypoints: Vec<C::World>,
+    ///Cost of the path in cells. A step costs its length times the cost of the cell it enters.
+    pub cost: f32,
+}
+
+///A* search over a grid of cells from 0 up to size on every axis.
+/// Use [i32; 2] cells for 2D and [i32; 3] cells for 3D.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::pathfinding::GridPathfinder;
+/// let map = [
+///     "....#...",
+///     "....#...",
+///     "....#...",
+///     "........",
+/// ];
+/// let finder = GridPathfinder::new([8, 4], 2.0);
+///
+/// let path = finder.find_path([0, 0], [7, 0], |[x, y]| map[y as usize].as_bytes()[x as usize] == b'.').unwrap();
+///
+/// assert_eq!([0, 0], path.cells[0]);
+/// assert!(path.cells.contains(&[4, 3]));
+/// assert_eq!(Vec2::new(15.0, 1.0), *path.waypoints.last().unwrap());
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct GridPathfinder<C: GridCell> {
+    ///Number of cells along every axis.
+    pub size: C,
+    ///Position of the corner of cell 0 in the world.
+    pub origin: C::World,
+    pub cell_size: f32,
+    pub connectivity: Connectivity,
+    pub heuristic: Heuristic,
+    ///Removes the waypoints that can be skipped by walking in a straight line.
+    /// Only passability is checked, so smoothed paths can cut across expensive cells.
+    pub smooth: bool,
+}
+
+impl<C: GridCell> GridPathfinder<C> {
+    ///Creates a pathfinder for a grid with its origin at 0 that allows diagonal moves and uses the octile heuristic without smoothing.
+    pub fn new(size: C, cell_size: f32) -> GridPathfinder<C> {
+        GridPathfinder {
+            size,
+            origin: C::World::ZERO,
+            cell_size,
+            connectivity: Connectivity::Diagonal,
+            heuristic: Heuristic::Octile,
+            smooth: false,
+        }
+    }
+
+    ///Returns true if the cell is inside the grid.
+    pub fn contains(&self, cell: C) -> bool {
+        cell.as_ref().iter().zip(self.size.as_ref()).all(|(c, size)| (0..*size).contains(c))
+    }
+
+    ///Returns the center of a cell in the world.
+    pub fn center(&self, cell: C) -> C::World {
+        cell.center(self.origin, self.cell_size)
+    }
+
+    ///Returns the cell containing a position in the world. The cell can be outside of the grid.
+    pub fn cell_at(&self, position: C::World) -> C {
+        C::containing(position, self.origin, self.cell_size)
+    }
+
+    ///Finds the shortest path between two cells where every passable cell costs the same.
+    /// Returns None if there is no path or start or goal are blocked.
+    pub fn find_path(&self, start: C, goal: C, passable: impl Fn(C) -> bool) -> Option<GridPath<C>> {
+        self.find_path_with_costs(start, goal, |cell| if passable(cell) { Some(1.0) } else { None })
+    }
+
+    ///Finds the cheapest path between two cells.
+    /// cost returns the cost of entering a cell, or None if the cell is blocked. Costs below 1 make the heuristics overestimate, so the path may not be the cheapest.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::pathfinding::{GridPathfinder, Connectivity, Heuristic};
+    /// let mut finder = GridPathfinder::new([5, 3], 1.0);
+    /// finder.connectivity = Connectivity::Orthogonal;
+    /// finder.heuristic = Heuristic::Manhattan;
+    ///
+    /// //wading through the swamp between start and goal costs 5 per cell
+    /// let path = finder.find_path_with_costs([0, 1], [4, 1], |[x, y]| Some(if y == 1 && (1..4).contains(&x) { 5.0 } else { 1.0 })).unwrap();
+    ///
+    /// assert_eq!(6.0, path.cost);
+    /// assert!(path.cells[1..4].iter().all(|[_, y]| *y != 1));
+    /// ```
+    pub fn find_path_with_costs(&self, start: C, goal: C, cost: impl Fn(C) -> Option<f32>) -> Option<GridPath<C>> {
+        let passable = |cell: C| self.contains(cell) && cost(cell).is_some();
+        if !passable(start) || !passable(goal) {
+            return None;
+        }
+
+        let offsets = self.offsets();
+        let (nodes, path_cost) = astar(
+            self.size.as_ref().iter().map(|size| *size as usize).product(),
+            self.index(start),
+            self.index(goal),
+            |node, neighbours| {
+                for (next, length) in self.moves(self.cell(node), &offsets, &passable) {
+                    neighbours.push((self.index(next), length * cost(next).unwrap()));
+                }
+            },
+            |node| self.heuristic.estimate(sub(goal, self.cell(node)).as_ref()),
+        )?;
+
+        let cells: Vec<C> = nodes.into_iter().map(|node| self.cell(node)).collect();
+        let corners = if self.smooth { self.smoothed(&cells, &passable) } else { cells.clone() };
+        Some(GridPath { waypoints: corners.iter().map(|cell| self.center(*cell)).collect(), cells, cost: path_cost })
+    }
+
+    ///Returns true if a straight line between the centers of two cells only touches passable cells.
+    /// Lines through the corner where cells meet need all of these cells to be passable.
+    pub fn line_of_sight(&self, from: C, to: C, passable: impl Fn(C) -> bool) -> bool {
+        let passable = |cell: C| self.contains(cell) && passable(cell);
+        if !passable(from) {
+            return false;
+        }
+
+        //walks the cells crossed by the line, like a ray through a voxel grid
+        let axes = from.as_ref().len();
+        let mut step = C::default();
+        let mut t_max = [f32::INFINITY; 3];
+        let mut t_delta = [f32::INFINITY; 3];
+        for axis in 0..axes {
+            let d = to.as_ref()[axis] - from.as_ref()[axis];
+            step.as_mut()[axis] = d.signum();
+            if d != 0 {
+                t_delta[axis] = 1.0 / d.abs() as f32;
+                t_max[axis] = 0.5 * t_delta[axis];
+            }
+        }
+
+        let mut cell = from;
+        while cell != to {
+            let t = t_max[..axes].iter().copied().fold(f32::INFINITY, f32::min);
+            let mut crossing = C::default();
+            for axis in 0..axes {
+                if t_max[axis] <= t + 1e-6 {
+                    crossing.as_mut()[axis] = step.as_ref()[axis];
+                    t_max[axis] += t_delta[axis];
+                }
+            }
+            if self.cuts_corner(cell, crossing, &passable) {
+                return false;
+            }
+            cell = add(cell, crossing);
+            if !passable(cell) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    //----------Internals----------
+
+    pub(super) fn index(&self, cell: C) -> usize {
+        let mut index = 0;
+        for (c, size) in cell.as_ref().iter().zip(self.size.as_ref()).rev() {
+            index = index * *size as usize + *c as usize;
+        }
+        index
+    }
+
+    pub(super) fn cell(&self, mut index: usize) -> C {
+        let mut cell = C::default();
+        for (c, size) in cell.as_mut().iter_mut().zip(self.size.as_ref()) {
+            *c = (index % *size as usize) as i32;
+            index /= *size as usize;
+        }
+        cell
+    }
+
+    pub(super) fn offsets(&self) -> Vec<C> {
+        let axes = C::default().as_ref().len();
+        let mut offsets = Vec::new();
+        //counts through every offset in -1..=1 on every axis
+        for code in 0..3usize.pow(axes as u32) {
+            let mut offset = C::default();
+            let mut rest = code;
+            for d in offset.as_mut() {
+                *d = (rest % 3) as i32 - 1;
+                rest /= 3;
+            }
+            let moved_axes = offset.as_ref().iter().filter(|d| **d != 0).count();
+            if moved_axes == 1 || (moved_axes > 1 && self.connectivity == Connectivity::Diagonal) {
+                offsets.push(offset);
+            }
+        }
+        offsets
+    }
+
+    //the passable cells next to a cell that can be reached without cutting corners, with the length of the step
+    pub(super) fn moves<'a>(&'a self, cell: C, offsets: &'a [C], passable: &'a impl Fn(C) -> bool) -> impl Iterator<Item = (C, f32)> + 'a {
+        offsets.iter().filter_map(move |offset| {
+            let next = add(cell, *offset);
+            if !passable(next) || self.cuts_corner(cell, *offset, passable) {
+                return None;
+            }
+            Some((next, (offset.as_ref().iter().filter(|d| **d != 0).count() as f32).sqrt()))
+        })
+    }
+
+    //a diagonal move needs every cell it squeezes past to be passable
+    fn cuts_corner(&self, cell: C, offset: C, passable: &impl Fn(C) -> bool) -> bool {
+        //grid cells have at most 3 axes, so this runs without allocating
+        let mut moved = [0usize; 3];
+        let mut count = 0;
+        for (axis, delta) in offset.as_ref().iter().enumerate() {
+            if *delta != 0 {
+                moved[count] = axis;
+                count += 1;
+            }
+        }
+        if count < 2 {
+            return false;
+        }
+        //every proper subset of the moved axes
+        (1..(1 << count) - 1).any(|mask: usize| {
+            let mut partial = C::default();
+            for (bit, axis) in moved[..count].iter().enumerate() {
+                if mask & (1 << bit) != 0 {
+                    partial.as_mut()[*axis] = offset.as_ref()[*axis];
+                }
+            }
+            !passable(add(cell, partial))
+        })
+    }
+
+    fn smoothed(&self, cells: &[C], passable: &impl Fn(C) -> bool) -> Vec<C> {
+        let mut corners = vec![cells[0]];
+        for i in 2..cells.len() {
+            if !self.line_of_sight(corners[corners.len() - 1], cells[i], passable) {
+                corners.push(cells[i - 1]);
+            }
+        }
+        if cells.len() > 1 {
+            corners.push(cells[cells.len() - 1]);
+        }
+        corners
+    }
+}
+
+fn add<C: GridCell>(mut a: C, b: C) -> C {
+    for (a, b) in a.as_mut().iter_mut().zip(b.as_ref()) {
+        *a += b;
+    }
+    a
+}
+
+fn sub<C: GridCell>(mut a: C, b: C) -> C {
+    for (a, b) in a.as_mut().iter_mut().zip(b.as_ref()) {
+        *a -= b;
+    }
+    a
+}
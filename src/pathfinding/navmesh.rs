@@ -0,0 +1,342 @@
+use std::collections::HashMap;
+
+use crate::Vec3;
+use crate::pathfinding::search::astar;
+
+
+//how far a point can be outside of a polygon and still count as inside, to close the gaps between neighbours
+const EDGE_TOLERANCE: f32 = 1e-4;
+
+//twice the signed area of the triangle o a b seen from above, positive if counter clockwise
+fn cross(o: Vec3, a: Vec3, b: Vec3) -> f32 {
+    (a.z - o.z) * (b.x - o.x) - (a.x - o.x) * (b.z - o.z)
+}
+
+
+#[derive(PartialEq, Debug, Clone)]
+struct Polygon {
+    //counter clockwise seen from above
+    vertices: Vec<u32>,
+    //polygon on the other side of every edge, edge i goes from vertex i to vertex i + 1
+    neighbours: Vec<Option<u32>>,
+    center: Vec3,
+    normal: Vec3,
+}
+
+///Path found on a NavMesh.
+#[derive(PartialEq, Debug, Clone)]
+pub struct NavPath {
+    ///Polygons the path crosses, from start to goal.
+    pub polygons: Vec<usize>,
+    ///Corners of the shortest path through these polygons, including start and goal.
+    pub waypoints: Vec<Vec3>,
+}
+
+///Hit of a NavMesh raycast against the border of the mesh
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct NavHit {
+    ///Fraction of the way from start to end where the border was hit.
+    pub t: f32,
+    pub point: Vec3,
+    ///Horizontal normal of the hit border with a magnitude of 1, pointing into the mesh.
+    pub normal: Vec3,
+    ///Index of the polygon whose border was hit.
+    pub polygon: usize,
+}
+
+///Walkable surface made of convex polygons, for moving over 3D levels with Y up.
+/// Polygons sharing an edge are connected.
+/// Locating and raycasting look at the mesh from above, so floors on top of each other are told apart by height.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec3;
+/// use gamevecs::pathfinding::NavMesh;
+/// //an L shaped corridor
+/// let vertices = vec![
+///     Vec3::new(0.0, 0.0, 0.0), Vec3::new(2.0, 0.0, 0.0), Vec3::new(2.0, 0.0, 2.0), Vec3::new(0.0, 0.0, 2.0),
+///     Vec3::new(0.0, 0.0, 10.0), Vec3::new(2.0, 0.0, 10.0), Vec3::new(10.0, 0.0, 0.0), Vec3::new(10.0, 0.0, 2.0),
+/// ];
+/// let mesh = NavMesh::new(vertices, &[vec![0, 1, 2, 3], vec![3, 2, 5, 4], vec![1, 6, 7, 2]]);
+///
+/// let path = mesh.find_path(Vec3::new(1.0, 0.0, 9.0), Vec3::new(9.0, 0.0, 1.0)).unwrap();
+///
+/// assert_eq!(vec![1, 0, 2], path.polygons);
+/// assert_eq!(vec![Vec3::new(1.0, 0.0, 9.0), Vec3::new(2.0, 0.0, 2.0), Vec3::new(9.0, 0.0, 1.0)], path.waypoints);
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct NavMesh {
+    vertices: Vec<Vec3>,
+    polygons: Vec<Polygon>,
+}
+
+impl NavMesh {
+    ///Creates a mesh from vertices and polygons given as indices into the vertices, in any winding.
+    /// Panics if a polygon has less than 3 vertices or an index is out of range.
+    pub fn new(vertices: Vec<Vec3>, polygons: &[Vec<u32>]) -> NavMesh {
+        let mut built = Vec::with_capacity(polygons.len());
+        for indices in polygons {
+            assert!(indices.len() >= 3, "polygons need at least 3 vertices");
+            let mut indices = indices.clone();
+            let points: Vec<Vec3> = indices.iter().map(|i| vertices[*i as usize]).collect();
+            let area: f32 = (1..points.len() - 1).map(|i| cross(points[0], points[i], points[i + 1])).sum();
+            if area < 0.0 {
+                indices.reverse();
+            }
+
+            //newell's method, works for any convex polygon even if the first corner is degenerate
+            let mut normal = Vec3::ZERO;
+            for (i, a) in points.iter().enumerate() {
+                let b = points[(i + 1) % points.len()];
+                normal += Vec3::new((a.y - b.y) * (a.z + b.z), (a.z - b.z) * (a.x + b.x), (a.x - b.x) * (a.y + b.y));
+            }
+            if normal.y < 0.0 {
+                normal = -normal;
+            }
+            let center = points.iter().fold(Vec3::ZERO, |sum, point| sum + *point) / points.len() as f32;
+            built.push(Polygon { neighbours: vec![None; indices.len()], vertices: indices, center, normal: normal.normalized() });
+        }
+
+        //every edge is walked the other way around by the polygon on its other side
+        let mut edges = HashMap::new();
+        for (index, polygon) in built.iter().enumerate() {
+            for edge in 0..polygon.vertices.len() {
+                let (a, b) = (polygon.vertices[edge], polygon.vertices[(edge + 1) % polygon.vertices.len()]);
+                edges.insert((a, b), index);
+            }
+        }
+        for polygon in &mut built {
+            for edge in 0..polygon.vertices.len() {
+                let (a, b) = (polygon.vertices[edge], polygon.vertices[(edge + 1) % polygon.vertices.len()]);
+                polygon.neighbours[edge] = edges.get(&(b, a)).map(|other| *other as u32);
+            }
+        }
+
+        NavMesh { vertices, polygons: built }
+    }
+
+    ///Returns the vertices the polygons of this mesh index into.
+    pub fn vertices(&self) -> &[Vec3] {
+        &self.vertices
+    }
+
+    ///Returns the number of polygons in this mesh.
+    pub fn len(&self) -> usize {
+        self.polygons.len()
+    }
+
+    ///Returns true if this mesh has no polygons.
+    pub fn is_empty(&self) -> bool {
+        self.polygons.is_empty()
+    }
+
+    ///Returns the vertex indices of a polygon, counter clockwise seen from above.
+    pub fn polygon(&self, index: usize) -> &[u32] {
+        &self.polygons[index].vertices
+    }
+
+    ///Returns the polygons sharing an edge with a polygon.
+    pub fn neighbours(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.polygons[index].neighbours.iter().flatten().map(|neighbour| *neighbour as usize)
+    }
+
+    ///Returns the polygon below or above the point with the smallest vertical distance, or None if there is no polygon at its horizontal position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::pathfinding::NavMesh;
+    /// //two floors on top of each other
+    /// let vertices = vec![
+    ///     Vec3::new(0.0, 0.0, 0.0), Vec3::new(4.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 4.0),
+    ///     Vec3::new(0.0, 3.0, 0.0), Vec3::new(4.0, 3.0, 0.0), Vec3::new(0.0, 3.0, 4.0),
+    /// ];
+    /// let mesh = NavMesh::new(vertices, &[vec![0, 1, 2], vec![3, 4, 5]]);
+    ///
+    /// assert_eq!(Some(0), mesh.locate(Vec3::new(1.0, 0.5, 1.0)));
+    /// assert_eq!(Some(1), mesh.locate(Vec3::new(1.0, 2.5, 1.0)));
+    /// assert_eq!(None, mesh.locate(Vec3::new(3.0, 0.0, 3.0)));
+    /// ```
+    pub fn locate(&self, point: Vec3) -> Option<usize> {
+        (0..self.polygons.len())
+            .filter(|index| self.contains(*index, point))
+            .filter_map(|index| self.height(index, point).map(|height| (index, (height - point.y).abs())))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index)
+    }
+
+    ///Returns the point on the surface of the mesh straight below or above the point, or None if there is no polygon at its horizontal position.
+    pub fn project(&self, point: Vec3) -> Option<Vec3> {
+        let polygon = self.locate(point)?;
+        Some(Vec3::new(point.x, self.height(polygon, point)?, point.z))
+    }
+
+    ///Finds the shortest path over the mesh with A* across the polygons, tightened with the funnel algorithm.
+    /// Returns None if start or goal are off the mesh or not connected.
+    pub fn find_path(&self, start: Vec3, goal: Vec3) -> Option<NavPath> {
+        let start_polygon = self.locate(start)?;
+        let goal_polygon = self.locate(goal)?;
+        let (polygons, _) = astar(
+            self.polygons.len(),
+            start_polygon,
+            goal_polygon,
+            |index, neighbours| {
+                //steps between the centers, but from the actual start and to the actual goal
+                let from = if index == start_polygon { start } else { self.polygons[index].center };
+                for next in self.neighbours(index) {
+                    let to = if next == goal_polygon { goal } else { self.polygons[next].center };
+                    neighbours.push((next, from.distance_to(to)));
+                }
+            },
+            |index| self.polygons[index].center.distance_to(goal),
+        )?;
+
+        let waypoints = self.string_pull(start, goal, &polygons);
+        Some(NavPath { polygons, waypoints })
+    }
+
+    ///Returns the shortest path from start to goal through a corridor of connected polygons, using the simple stupid funnel algorithm.
+    /// The corridor has to start at the polygon of start and end at the polygon of goal.
+    pub fn string_pull(&self, start: Vec3, goal: Vec3, corridor: &[usize]) -> Vec<Vec3> {
+        let mut portals = vec![(start, start)];
+        for pair in corridor.windows(2) {
+            portals.push(self.portal(pair[0], pair[1]).expect("consecutive polygons of a corridor have to be neighbours"));
+        }
+        portals.push((goal, goal));
+
+        let mut path = vec![start];
+        let (mut apex, mut left, mut right) = (start, start, start);
+        let (mut left_index, mut right_index) = (0, 0);
+        let mut i = 1;
+        while i < portals.len() {
+            let (next_left, next_right) = portals[i];
+
+            //narrow the funnel from the right, unless the new side crosses over the left one
+            if cross(apex, right, next_right) >= 0.0 {
+                if apex == right || cross(apex, left, next_right) < 0.0 {
+                    right = next_right;
+                    right_index = i;
+                } else {
+                    path.push(left);
+                    apex = left;
+                    right = apex;
+                    right_index = left_index;
+                    i = left_index + 1;
+                    continue;
+                }
+            }
+
+            if cross(apex, left, next_left) <= 0.0 {
+                if apex == left || cross(apex, right, next_left) > 0.0 {
+                    left = next_left;
+                    left_index = i;
+                } else {
+                    path.push(right);
+                    apex = right;
+                    left = apex;
+                    left_index = right_index;
+                    i = right_index + 1;
+                    continue;
+                }
+            }
+
+            i += 1;
+        }
+
+        if path[path.len() - 1] != goal {
+            path.push(goal);
+        }
+        path
+    }
+
+    ///Walks along the surface from start towards end, seen from above, and returns where the border of the mesh is hit first.
+    /// Returns None if end is reached, or if start is off the mesh.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::pathfinding::NavMesh;
+    /// let vertices = vec![Vec3::new(0.0, 0.0, 0.0), Vec3::new(4.0, 0.0, 0.0), Vec3::new(4.0, 0.0, 4.0), Vec3::new(0.0, 0.0, 4.0)];
+    /// let mesh = NavMesh::new(vertices, &[vec![0, 1, 2, 3]]);
+    ///
+    /// let hit = mesh.raycast(Vec3::new(2.0, 0.0, 2.0), Vec3::new(6.0, 0.0, 2.0)).unwrap();
+    ///
+    /// assert_eq!(0.5, hit.t);
+    /// assert_eq!(Vec3::new(4.0, 0.0, 2.0), hit.point);
+    /// assert_eq!(Vec3::LEFT, hit.normal);
+    /// assert_eq!(None, mesh.raycast(Vec3::new(1.0, 0.0, 1.0), Vec3::new(3.0, 0.0, 3.0)));
+    /// ```
+    pub fn raycast(&self, start: Vec3, end: Vec3) -> Option<NavHit> {
+        let mut polygon = self.locate(start)?;
+        let mut t = 0.0;
+        //every polygon is crossed at most once
+        for _ in 0..self.polygons.len() {
+            //clips the segment against the edges the segment leaves through
+            let vertices = &self.polygons[polygon].vertices;
+            let mut exit = None;
+            for edge in 0..vertices.len() {
+                let a = self.vertices[vertices[edge] as usize];
+                let b = self.vertices[vertices[(edge + 1) % vertices.len()] as usize];
+                let (inside_start, inside_end) = (cross(a, b, start), cross(a, b, end));
+                if inside_end < inside_start && inside_end < 0.0 {
+                    let edge_t = inside_start / (inside_start - inside_end);
+                    if exit.map_or(true, |(exit_t, _)| edge_t < exit_t) {
+                        exit = Some((edge_t, edge));
+                    }
+                }
+            }
+
+            //no exit means end is inside this polygon
+            let (exit_t, edge) = exit?;
+            t = f32::max(t, exit_t);
+            match self.polygons[polygon].neighbours[edge] {
+                Some(next) => polygon = next as usize,
+                None => {
+                    let a = self.vertices[vertices[edge] as usize];
+                    let b = self.vertices[vertices[(edge + 1) % vertices.len()] as usize];
+                    let point = start + (end - start) * t;
+                    let height = self.height(polygon, point).unwrap_or(point.y);
+                    return Some(NavHit {
+                        t,
+                        point: Vec3::new(point.x, height, point.z),
+                        normal: Vec3::new(b.z - a.z, 0.0, a.x - b.x).normalized(),
+                        polygon,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    //----------Internals----------
+
+    //the shared edge of two neighbours as (left, right) seen when walking from the first into the second
+    fn portal(&self, from: usize, to: usize) -> Option<(Vec3, Vec3)> {
+        let polygon = &self.polygons[from];
+        let edge = polygon.neighbours.iter().position(|neighbour| *neighbour == Some(to as u32))?;
+        let a = self.vertices[polygon.vertices[edge] as usize];
+        let b = self.vertices[polygon.vertices[(edge + 1) % polygon.vertices.len()] as usize];
+        //leaving a counter clockwise polygon, the edge end is on the left
+        Some((b, a))
+    }
+
+    fn contains(&self, index: usize, point: Vec3) -> bool {
+        let vertices = &self.polygons[index].vertices;
+        (0..vertices.len()).all(|edge| {
+            let a = self.vertices[vertices[edge] as usize];
+            let b = self.vertices[vertices[(edge + 1) % vertices.len()] as usize];
+            cross(a, b, point) >= -EDGE_TOLERANCE * a.distance_to(b)
+        })
+    }
+
+    //height of the plane of a polygon at the horizontal position of the point, none for vertical polygons
+    fn height(&self, index: usize, point: Vec3) -> Option<f32> {
+        let polygon = &self.polygons[index];
+        if polygon.normal.y.abs() < 1e-6 {
+            return None;
+        }
+        let offset = point - polygon.center;
+        Some(polygon.center.y - (polygon.normal.x * offset.x + polygon.normal.z * offset.z) / polygon.normal.y)
+    }
+}
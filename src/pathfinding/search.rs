@@ -0,0 +1,110 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+
+//node in the open set, ordered so the binary heap pops the lowest estimate first
+#[derive(PartialEq, Debug, Clone, Copy)]
+struct Open {
+    estimate: f32,
+    node: usize,
+}
+
+impl Eq for Open {}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate).then(other.node.cmp(&self.node))
+    }
+}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+
+const NO_PARENT: usize = usize::MAX;
+
+///Runs A* over the nodes 0..node_count and returns the nodes from start to goal and the cost of the path.
+/// neighbours fills the buffer with the reachable nodes of a node and the costs to get there.
+pub(crate) fn astar(
+    node_count: usize,
+    start: usize,
+    goal: usize,
+    mut neighbours: impl FnMut(usize, &mut Vec<(usize, f32)>),
+    heuristic: impl Fn(usize) -> f32,
+) -> Option<(Vec<usize>, f32)> {
+    let mut costs = vec![f32::INFINITY; node_count];
+    let mut parents = vec![NO_PARENT; node_count];
+    let mut closed = vec![false; node_count];
+    let mut open = BinaryHeap::new();
+    let mut buffer = Vec::new();
+    costs[start] = 0.0;
+    open.push(Open { estimate: heuristic(start), node: start });
+
+    while let Some(Open { node, .. }) = open.pop() {
+        if node == goal {
+            let mut path = vec![goal];
+            while parents[path[path.len() - 1]] != NO_PARENT {
+                path.push(parents[path[path.len() - 1]]);
+            }
+            path.reverse();
+            return Some((path, costs[goal]));
+        }
+        //the heap can hold outdated entries of nodes that were reached cheaper later
+        if closed[node] {
+            continue;
+        }
+        closed[node] = true;
+
+        buffer.clear();
+        neighbours(node, &mut buffer);
+        for &(next, step_cost) in &buffer {
+            let cost = costs[node] + step_cost;
+            if cost < costs[next] {
+                costs[next] = cost;
+                parents[next] = node;
+                closed[next] = false;
+                open.push(Open { estimate: cost + heuristic(next), node: next });
+            }
+        }
+    }
+
+    None
+}
+
+///Runs Dijkstra's algorithm over the nodes 0..node_count and returns the cost of reaching every node from the nearest start.
+/// Unreachable nodes cost infinity.
+pub(crate) fn dijkstra(
+    node_count: usize,
+    starts: impl IntoIterator<Item = usize>,
+    mut neighbours: impl FnMut(usize, &mut Vec<(usize, f32)>),
+) -> Vec<f32> {
+    let mut costs = vec![f32::INFINITY; node_count];
+    let mut open = BinaryHeap::new();
+    let mut buffer = Vec::new();
+    for start in starts {
+        costs[start] = 0.0;
+        open.push(Open { estimate: 0.0, node: start });
+    }
+
+    while let Some(Open { estimate, node }) = open.pop() {
+        //skip outdated entries of nodes that were reached cheaper later
+        if estimate > costs[node] {
+            continue;
+        }
+
+        buffer.clear();
+        neighbours(node, &mut buffer);
+        for &(next, step_cost) in &buffer {
+            let cost = estimate + step_cost;
+            if cost < costs[next] {
+                costs[next] = cost;
+                open.push(Open { estimate: cost, node: next });
+            }
+        }
+    }
+
+    costs
+}
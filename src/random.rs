@@ -0,0 +1,122 @@
+//!Uniformly distributed random points and directions, drawn from a caller provided random number generator.
+//! Requires the `rand` feature.
+//!
+//! # Examples
+//! ```rust
+//! use gamevecs::random;
+//! use rand::SeedableRng;
+//! use rand::rngs::SmallRng;
+//! let mut rng = SmallRng::seed_from_u64(1);
+//!
+//! let spawn_offset = random::in_unit_circle(&mut rng) * 5.0;
+//! let direction = random::on_unit_sphere(&mut rng);
+//!
+//! assert!(spawn_offset.magnitude() <= 5.0);
+//! assert!((direction.magnitude() - 1.0).abs() < 1e-5);
+//! ```
+
+use std::f32::consts::TAU;
+
+use rand::Rng;
+
+use crate::{Vec2, Vec3, Vector, Radians};
+use crate::shapes::{Aabb2, Aabb3};
+
+
+///Returns a random point inside of the circle with radius 1 around the origin.
+pub fn in_unit_circle<R: Rng + ?Sized>(rng: &mut R) -> Vec2 {
+    //the square root spreads the points evenly over the area instead of clumping them at the center
+    on_unit_circle(rng) * rng.gen::<f32>().sqrt()
+}
+
+///Returns a random direction in 2D with a magnitude of 1.
+pub fn on_unit_circle<R: Rng + ?Sized>(rng: &mut R) -> Vec2 {
+    let angle = rng.gen::<f32>() * TAU;
+    Vec2::new(angle.cos(), angle.sin())
+}
+
+///Returns a random point inside of the sphere with radius 1 around the origin.
+pub fn in_unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Vec3 {
+    on_unit_sphere(rng) * rng.gen::<f32>().cbrt()
+}
+
+///Returns a random direction in 3D with a magnitude of 1.
+pub fn on_unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Vec3 {
+    //the height of a point on a sphere is evenly distributed (archimedes' hat-box theorem)
+    let z = rng.gen::<f32>() * 2.0 - 1.0;
+    let angle = rng.gen::<f32>() * TAU;
+    let radius = (1.0 - z * z).max(0.0).sqrt();
+    Vec3::new(radius * angle.cos(), radius * angle.sin(), z)
+}
+
+///Returns a random direction with a magnitude of 1 that is at most half_angle away from the given direction.
+/// The directions are spread evenly over the spherical cap.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::{random, Vec3};
+/// use rand::SeedableRng;
+/// use rand::rngs::SmallRng;
+/// let mut rng = SmallRng::seed_from_u64(1);
+///
+/// //spread the bullets of a shotgun
+/// let aim = Vec3::new(0.0, 0.0, 1.0);
+/// let bullet = random::in_cone(&mut rng, aim, 0.1);
+///
+/// assert!(bullet.angle_between(aim) <= 0.1 + 1e-3);
+/// ```
+pub fn in_cone<R: Rng + ?Sized>(rng: &mut R, direction: Vec3, half_angle: impl Into<Radians>) -> Vec3 {
+    let half_angle = half_angle.into().0;
+    let axis = direction.normalized();
+    let (tangent, bitangent) = axis.perpendiculars();
+
+    let cos_max = half_angle.clamp(0.0, std::f32::consts::PI).cos();
+    let cos = 1.0 - rng.gen::<f32>() * (1.0 - cos_max);
+    let sin = (1.0 - cos * cos).max(0.0).sqrt();
+    let angle = rng.gen::<f32>() * TAU;
+
+    axis * cos + (tangent * angle.cos() + bitangent * angle.sin()) * sin
+}
+
+///Returns a random point inside of the box.
+pub fn in_aabb2<R: Rng + ?Sized>(rng: &mut R, aabb: &Aabb2) -> Vec2 {
+    Vec2::new(
+        aabb.min.x + rng.gen::<f32>() * (aabb.max.x - aabb.min.x),
+        aabb.min.y + rng.gen::<f32>() * (aabb.max.y - aabb.min.y))
+}
+
+///Returns a random point inside of the box.
+pub fn in_aabb3<R: Rng + ?Sized>(rng: &mut R, aabb: &Aabb3) -> Vec3 {
+    Vec3::new(
+        aabb.min.x + rng.gen::<f32>() * (aabb.max.x - aabb.min.x),
+        aabb.min.y + rng.gen::<f32>() * (aabb.max.y - aabb.min.y),
+        aabb.min.z + rng.gen::<f32>() * (aabb.max.z - aabb.min.z))
+}
+
+///Returns a random point inside of the triangle with the corners a, b and c.
+/// Works with Vec2 and Vec3.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::{random, Vec3};
+/// use gamevecs::shapes::Triangle3;
+/// use rand::SeedableRng;
+/// use rand::rngs::SmallRng;
+/// let mut rng = SmallRng::seed_from_u64(1);
+/// let triangle = Triangle3::new(Vec3::ZERO, Vec3::RIGHT, Vec3::UP);
+///
+/// let point = random::in_triangle(&mut rng, triangle.a, triangle.b, triangle.c);
+///
+/// assert!(point.x >= 0.0 && point.y >= 0.0 && point.x + point.y <= 1.0 + 1e-6);
+/// ```
+pub fn in_triangle<V: Vector, R: Rng + ?Sized>(rng: &mut R, a: V, b: V, c: V) -> V {
+    let mut u = rng.gen::<f32>();
+    let mut v = rng.gen::<f32>();
+    //fold points from the other half of the parallelogram back into the triangle
+    if u + v > 1.0 {
+        u = 1.0 - u;
+        v = 1.0 - v;
+    }
+
+    a + (b - a) * u + (c - a) * v
+}
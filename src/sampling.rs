@@ -0,0 +1,5 @@
+mod poisson;
+mod directions;
+
+pub use self::poisson::PoissonDisk;
+pub use self::directions::{fibonacci_sphere, fibonacci_hemisphere, cosine_hemisphere, circle_directions};
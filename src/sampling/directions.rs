@@ -0,0 +1,114 @@
+use std::f32::consts::{PI, TAU};
+
+use crate::{Vec2, Vec3};
+
+
+//angle between consecutive points of a fibonacci spiral
+const GOLDEN_ANGLE: f32 = PI * (3.0 - 2.236068);
+
+
+///Returns count directions evenly spread over the unit sphere, with a magnitude of 1.
+/// The points follow a Fibonacci spiral from the top (+y) to the bottom (-y).
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::sampling::fibonacci_sphere;
+/// let probes = fibonacci_sphere(64);
+///
+/// assert_eq!(64, probes.len());
+/// assert!(probes.iter().all(|direction| (direction.magnitude() - 1.0).abs() < 1e-6));
+/// ```
+pub fn fibonacci_sphere(count: usize) -> Vec<Vec3> {
+    (0..count).map(|i| {
+        //heights at the centers of count equally sized bands
+        let y = 1.0 - (i as f32 + 0.5) / count as f32 * 2.0;
+        spiral_point(i, y)
+    }).collect()
+}
+
+///Returns count directions evenly spread over the hemisphere around the normal, with a magnitude of 1.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec3;
+/// use gamevecs::sampling::fibonacci_hemisphere;
+/// let normal = Vec3::new(0.0, 0.0, 1.0);
+/// let rays = fibonacci_hemisphere(32, normal);
+///
+/// assert!(rays.iter().all(|direction| direction.dot(normal) > 0.0));
+/// ```
+pub fn fibonacci_hemisphere(count: usize, normal: Vec3) -> Vec<Vec3> {
+    let basis = Basis::new(normal);
+    (0..count).map(|i| {
+        let height = 1.0 - (i as f32 + 0.5) / count as f32;
+        basis.transform(spiral_point(i, height))
+    }).collect()
+}
+
+///Returns count directions over the hemisphere around the normal with a magnitude of 1,
+/// spread with a density proportional to the cosine of their angle to the normal.
+/// Used to gather light for diffuse surfaces, where every direction has the same weight.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec3;
+/// use gamevecs::sampling::cosine_hemisphere;
+/// let normal = Vec3::new(0.0, 1.0, 0.0);
+/// let rays = cosine_hemisphere(256, normal);
+///
+/// //the average cosine of a cosine weighted hemisphere is 2/3
+/// let average = rays.iter().map(|direction| direction.dot(normal)).sum::<f32>() / 256.0;
+/// assert!((average - 2.0 / 3.0).abs() < 1e-2);
+/// ```
+pub fn cosine_hemisphere(count: usize, normal: Vec3) -> Vec<Vec3> {
+    let basis = Basis::new(normal);
+    (0..count).map(|i| {
+        //evenly spread points on a disk, lifted onto the hemisphere (malley's method)
+        let radius_squared = (i as f32 + 0.5) / count as f32;
+        let height = (1.0 - radius_squared).sqrt();
+        basis.transform(spiral_point(i, height))
+    }).collect()
+}
+
+///Returns count directions evenly spaced around the unit circle with a magnitude of 1, starting at +x and going counterclockwise.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::sampling::circle_directions;
+/// let directions = circle_directions(4);
+///
+/// assert!(directions[1].equals(Vec2::new(0.0, 1.0), 1e-6));
+/// ```
+pub fn circle_directions(count: usize) -> Vec<Vec2> {
+    (0..count).map(|i| {
+        let angle = i as f32 / count as f32 * TAU;
+        Vec2::new(angle.cos(), angle.sin()).normalized()
+    }).collect()
+}
+
+//point with the given height along y on the spiral around the y axis
+fn spiral_point(i: usize, height: f32) -> Vec3 {
+    let radius = (1.0 - height * height).max(0.0).sqrt();
+    let angle = i as f32 * GOLDEN_ANGLE;
+    Vec3::new(angle.cos() * radius, height, angle.sin() * radius).normalized()
+}
+
+//rotates directions around +y to directions around a normal
+struct Basis {
+    normal: Vec3,
+    tangent: Vec3,
+    bitangent: Vec3,
+}
+
+impl Basis {
+    fn new(normal: Vec3) -> Basis {
+        let normal = normal.normalized();
+        let (tangent, bitangent) = normal.perpendiculars();
+        Basis { normal, tangent, bitangent }
+    }
+
+    fn transform(&self, direction: Vec3) -> Vec3 {
+        (self.tangent * direction.x + self.normal * direction.y + self.bitangent * direction.z).normalized()
+    }
+}
@@ -0,0 +1,298 @@
+use std::f32::consts::TAU;
+
+use crate::{Vec2, Vec3, Vector};
+use crate::shapes::{Aabb2, Aabb3};
+
+
+const NONE: u32 = u32::MAX;
+//tries to find a first point inside of a polygon before giving up
+const FIRST_POINT_ATTEMPTS: u32 = 1000;
+
+
+///Bridson's Poisson-disk sampling. Fills an area with points that are never closer than a minimum distance
+/// but leave no large gaps, for natural looking placement of foliage, rocks or loot.
+/// The result only depends on the settings, the area and the seed.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::sampling::PoissonDisk;
+/// use gamevecs::shapes::Aabb2;
+/// let trees = PoissonDisk::new(2.0, 42).in_aabb2(Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(50.0, 50.0)));
+///
+/// for (i, a) in trees.iter().enumerate() {
+///     for b in &trees[i + 1..] {
+///         assert!(a.distance_to(*b) >= 2.0);
+///     }
+/// }
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct PoissonDisk {
+    ///Minimum distance between points, used where the density is 1.0.
+    pub radius: f32,
+    ///Minimum distance between points where the density is 0.0. Only used by the density variants.
+    pub max_radius: f32,
+    ///Number of candidates tried around every point before it stops spawning new ones.
+    /// Higher values fill the area more tightly.
+    pub attempts: u32,
+    pub seed: u64,
+}
+
+impl PoissonDisk {
+    ///Creates a sampler with a constant radius and 30 attempts per point. Panics if the radius is not positive.
+    pub fn new(radius: f32, seed: u64) -> PoissonDisk {
+        assert!(radius > 0.0, "the radius of Poisson-disk sampling has to be positive");
+        PoissonDisk { radius, max_radius: radius, attempts: 30, seed }
+    }
+
+    ///Returns points inside of the rectangle.
+    pub fn in_aabb2(&self, bounds: Aabb2) -> Vec<Vec2> {
+        self.in_aabb2_with_density(bounds, |_| 1.0)
+    }
+
+    ///Returns points inside of the rectangle, spaced by the density at every point.
+    /// A density of 1.0 spaces points by radius, 0.0 by max_radius.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::sampling::PoissonDisk;
+    /// use gamevecs::shapes::Aabb2;
+    /// let mut sampler = PoissonDisk::new(1.0, 7);
+    /// sampler.max_radius = 4.0;
+    ///
+    /// //grass gets denser towards the right
+    /// let grass = sampler.in_aabb2_with_density(Aabb2::new(Vec2::new(0.0, 0.0), Vec2::new(40.0, 40.0)), |point| point.x / 40.0);
+    ///
+    /// let left = grass.iter().filter(|point| point.x < 20.0).count();
+    /// assert!(left < grass.len() - left);
+    /// ```
+    pub fn in_aabb2_with_density(&self, bounds: Aabb2, density: impl Fn(Vec2) -> f32) -> Vec<Vec2> {
+        sample(self, bounds.min, bounds.max, |point| bounds.contains(point), &[], density)
+    }
+
+    ///Returns points inside of the polygon, given by its corners in order.
+    /// Returns no points if the polygon has fewer than 3 corners or no area.
+    pub fn in_polygon(&self, polygon: &[Vec2]) -> Vec<Vec2> {
+        self.in_polygon_with_density(polygon, |_| 1.0)
+    }
+
+    ///Returns points inside of the polygon, given by its corners in order, spaced by the density at every point.
+    /// A density of 1.0 spaces points by radius, 0.0 by max_radius. Returns no points if the polygon has fewer than 3 corners or no area.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::sampling::PoissonDisk;
+    /// let triangle = [Vec2::new(0.0, 0.0), Vec2::new(20.0, 0.0), Vec2::new(0.0, 20.0)];
+    /// let points = PoissonDisk::new(1.0, 3).in_polygon(&triangle);
+    ///
+    /// assert!(points.iter().all(|point| point.x >= 0.0 && point.y >= 0.0 && point.x + point.y <= 20.0));
+    /// ```
+    pub fn in_polygon_with_density(&self, polygon: &[Vec2], density: impl Fn(Vec2) -> f32) -> Vec<Vec2> {
+        if polygon.len() < 3 {
+            return Vec::new();
+        }
+
+        let mut min = polygon[0];
+        let mut max = polygon[0];
+        for corner in polygon {
+            min = min.min(*corner);
+            max = max.max(*corner);
+        }
+        //thin polygons can be missed by random guesses, but the corner triangles of every simple polygon include an ear,
+        //whose centroid lies inside
+        let corner_centroids: Vec<Vec2> = (0..polygon.len())
+            .map(|i| (polygon[(i + polygon.len() - 1) % polygon.len()] + polygon[i] + polygon[(i + 1) % polygon.len()]) / 3.0)
+            .collect();
+        sample(self, min, max, |point| polygon_contains(polygon, point), &corner_centroids, density)
+    }
+
+    ///Returns points inside of the box.
+    pub fn in_aabb3(&self, bounds: Aabb3) -> Vec<Vec3> {
+        self.in_aabb3_with_density(bounds, |_| 1.0)
+    }
+
+    ///Returns points inside of the box, spaced by the density at every point.
+    /// A density of 1.0 spaces points by radius, 0.0 by max_radius.
+    pub fn in_aabb3_with_density(&self, bounds: Aabb3, density: impl Fn(Vec3) -> f32) -> Vec<Vec3> {
+        sample(self, bounds.min, bounds.max, |point| bounds.contains(point), &[], density)
+    }
+}
+
+//even-odd rule
+fn polygon_contains(polygon: &[Vec2], point: Vec2) -> bool {
+    let mut inside = false;
+    let mut previous = polygon[polygon.len() - 1];
+    for corner in polygon {
+        if (corner.y > point.y) != (previous.y > point.y) {
+            let x = corner.x + (point.y - corner.y) / (previous.y - corner.y) * (previous.x - corner.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+        previous = *corner;
+    }
+    inside
+}
+
+//random values that sampling needs per dimension
+trait SampleVector: Vector {
+    fn random_direction(random: &mut Random) -> Self;
+    fn random_in(random: &mut Random, min: Self, max: Self) -> Self;
+}
+
+impl SampleVector for Vec2 {
+    fn random_direction(random: &mut Random) -> Vec2 {
+        let angle = random.next() * TAU;
+        Vec2::new(angle.cos(), angle.sin())
+    }
+    fn random_in(random: &mut Random, min: Vec2, max: Vec2) -> Vec2 {
+        Vec2::new(min.x + random.next() * (max.x - min.x), min.y + random.next() * (max.y - min.y))
+    }
+}
+
+impl SampleVector for Vec3 {
+    fn random_direction(random: &mut Random) -> Vec3 {
+        let z = random.next() * 2.0 - 1.0;
+        let angle = random.next() * TAU;
+        let radius = (1.0 - z * z).max(0.0).sqrt();
+        Vec3::new(radius * angle.cos(), radius * angle.sin(), z)
+    }
+    fn random_in(random: &mut Random, min: Vec3, max: Vec3) -> Vec3 {
+        Vec3::new(
+            min.x + random.next() * (max.x - min.x),
+            min.y + random.next() * (max.y - min.y),
+            min.z + random.next() * (max.z - min.z))
+    }
+}
+
+//fallback holds first points to try when random guesses don't hit the area
+fn sample<V: SampleVector>(settings: &PoissonDisk, min: V, max: V, inside: impl Fn(V) -> bool, fallback: &[V], density: impl Fn(V) -> f32) -> Vec<V> {
+    let min_radius = settings.radius.min(settings.max_radius);
+    let max_radius = settings.radius.max(settings.max_radius);
+    assert!(min_radius > 0.0, "the radius of Poisson-disk sampling has to be positive");
+    let radius_at = |point: V| {
+        let density = density(point).clamp(0.0, 1.0);
+        settings.max_radius + (settings.radius - settings.max_radius) * density
+    };
+
+    //cells small enough to hold at most one point each
+    let cell_size = min_radius / (V::DIMENSIONS as f32).sqrt();
+    let mut cells = [1usize; 3];
+    for (axis, cell_count) in cells.iter_mut().enumerate().take(V::DIMENSIONS) {
+        *cell_count = (((max.component(axis) - min.component(axis)) / cell_size).ceil() as usize).max(1);
+    }
+    let mut grid = vec![NONE; cells.iter().product()];
+    let cell_of = |point: V| {
+        let mut cell = [0usize; 3];
+        for axis in 0..V::DIMENSIONS {
+            cell[axis] = (((point.component(axis) - min.component(axis)) / cell_size) as usize).min(cells[axis] - 1);
+        }
+        cell
+    };
+    let grid_index = |cell: [usize; 3]| cell[0] + cells[0] * (cell[1] + cells[1] * cell[2]);
+
+    let mut random = Random::new(settings.seed);
+    let mut points: Vec<V> = Vec::new();
+    let mut radii: Vec<f32> = Vec::new();
+    let mut active: Vec<u32> = Vec::new();
+
+    let mut first = None;
+    for _ in 0..FIRST_POINT_ATTEMPTS {
+        let candidate = V::random_in(&mut random, min, max);
+        if inside(candidate) {
+            first = Some(candidate);
+            break;
+        }
+    }
+    let first = first.or_else(|| fallback.iter().copied().find(|point| inside(*point)));
+    let Some(first) = first else {
+        return points;
+    };
+    grid[grid_index(cell_of(first))] = 0;
+    points.push(first);
+    radii.push(radius_at(first));
+    active.push(0);
+
+    //points further away than this many cells can't be too close to a candidate
+    let reach = (max_radius / cell_size).ceil() as isize;
+    while !active.is_empty() {
+        let active_index = (random.next_u64() % active.len() as u64) as usize;
+        let center = points[active[active_index] as usize];
+        let center_radius = radii[active[active_index] as usize];
+
+        let mut found = false;
+        for _ in 0..settings.attempts {
+            let candidate = center + V::random_direction(&mut random) * (center_radius * (1.0 + random.next()));
+            if !inside(candidate) {
+                continue;
+            }
+            let candidate_radius = radius_at(candidate);
+
+            let cell = cell_of(candidate);
+            let mut free = true;
+            let mut lower = [0isize; 3];
+            let mut upper = [0isize; 3];
+            for axis in 0..V::DIMENSIONS {
+                lower[axis] = (cell[axis] as isize - reach).max(0);
+                upper[axis] = (cell[axis] as isize + reach).min(cells[axis] as isize - 1);
+            }
+            'search: for z in lower[2]..=upper[2] {
+                for y in lower[1]..=upper[1] {
+                    for x in lower[0]..=upper[0] {
+                        let other = grid[grid_index([x as usize, y as usize, z as usize])];
+                        if other == NONE {
+                            continue;
+                        }
+                        //both points have to respect their own radius
+                        let distance = candidate_radius.max(radii[other as usize]);
+                        if points[other as usize].distance_to_squared(candidate) < distance * distance {
+                            free = false;
+                            break 'search;
+                        }
+                    }
+                }
+            }
+
+            if free {
+                grid[grid_index(cell)] = points.len() as u32;
+                active.push(points.len() as u32);
+                points.push(candidate);
+                radii.push(candidate_radius);
+                found = true;
+                break;
+            }
+        }
+
+        if !found {
+            active.swap_remove(active_index);
+        }
+    }
+
+    points
+}
+
+//splitmix64, small and good enough for placing points
+struct Random {
+    state: u64,
+}
+
+impl Random {
+    fn new(seed: u64) -> Random {
+        Random { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    //uniform in 0.0..1.0
+    fn next(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
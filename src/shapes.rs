@@ -0,0 +1,14 @@
+mod aabb;
+mod ball;
+mod capsule;
+mod hull;
+mod triangle;
+mod ray;
+
+pub use self::aabb::{Aabb2, Aabb3};
+pub use self::ball::{Circle, Sphere};
+pub use self::capsule::{Capsule2, Capsule3};
+pub use self::hull::{ConvexHull2, ConvexHull3};
+pub use self::triangle::Triangle3;
+pub use self::ray::Ray3;
+pub(crate) use self::ray::slabs;
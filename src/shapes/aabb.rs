@@ -0,0 +1,244 @@
+use crate::{Vec2, Vec3};
+
+
+///2D axis aligned bounding box
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Aabb2 {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl Aabb2 {
+    pub fn new(min: Vec2, max: Vec2) -> Aabb2 {
+        Aabb2 { min, max }
+    }
+
+    ///Creates a box from its center and half of its size.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::shapes::Aabb2;
+    /// let aabb = Aabb2::from_center(Vec2::new(1.0, 1.0), Vec2::new(2.0, 3.0));
+    ///
+    /// assert_eq!(Aabb2::new(Vec2::new(-1.0, -2.0), Vec2::new(3.0, 4.0)), aabb);
+    /// ```
+    pub fn from_center(center: Vec2, half_extents: Vec2) -> Aabb2 {
+        Aabb2 { min: center - half_extents, max: center + half_extents }
+    }
+
+    ///Returns the center of this box.
+    pub fn center(&self) -> Vec2 {
+        self.min.lerp(self.max, 0.5)
+    }
+
+    ///Returns half of the size of this box.
+    pub fn half_extents(&self) -> Vec2 {
+        (self.max - self.min) * 0.5
+    }
+
+    ///Returns true if the point lies inside of this box or on its border.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::shapes::Aabb2;
+    /// let aabb = Aabb2::new(Vec2::ZERO, Vec2::ONE);
+    ///
+    /// assert!(aabb.contains(Vec2::new(0.5, 1.0)));
+    /// assert!(!aabb.contains(Vec2::new(0.5, 1.5)));
+    /// ```
+    pub fn contains(&self, point: Vec2) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x &&
+        point.y >= self.min.y && point.y <= self.max.y
+    }
+
+    ///Returns true if this box overlaps another one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::shapes::Aabb2;
+    /// let aabb = Aabb2::new(Vec2::ZERO, Vec2::ONE);
+    /// let other = Aabb2::new(Vec2::new(0.5, 0.5), Vec2::new(2.0, 2.0));
+    ///
+    /// assert!(aabb.intersects(&other));
+    /// ```
+    pub fn intersects(&self, other: &Aabb2) -> bool {
+        self.min.x <= other.max.x && self.max.x >= other.min.x &&
+        self.min.y <= other.max.y && self.max.y >= other.min.y
+    }
+
+    ///Returns the point of this box that is closest to the given point.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::shapes::Aabb2;
+    /// let aabb = Aabb2::new(Vec2::ZERO, Vec2::ONE);
+    ///
+    /// assert_eq!(Vec2::new(1.0, 0.5), aabb.closest_point(Vec2::new(3.0, 0.5)));
+    /// ```
+    pub fn closest_point(&self, point: Vec2) -> Vec2 {
+        point.max(self.min).min(self.max)
+    }
+
+    ///Returns the squared distance from this box to the given point, or 0.0 if the point lies inside.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::shapes::Aabb2;
+    /// let aabb = Aabb2::new(Vec2::ZERO, Vec2::ONE);
+    ///
+    /// assert_eq!(4.0, aabb.distance_squared_to(Vec2::new(3.0, 0.5)));
+    /// ```
+    pub fn distance_squared_to(&self, point: Vec2) -> f32 {
+        self.closest_point(point).distance_to_squared(point)
+    }
+
+    ///Returns the smallest box containing this box and another one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::shapes::Aabb2;
+    /// let aabb = Aabb2::new(Vec2::ZERO, Vec2::ONE);
+    /// let other = Aabb2::new(Vec2::ONE, Vec2::new(2.0, 3.0));
+    ///
+    /// assert_eq!(Aabb2::new(Vec2::ZERO, Vec2::new(2.0, 3.0)), aabb.union(&other));
+    /// ```
+    pub fn union(&self, other: &Aabb2) -> Aabb2 {
+        Aabb2 { min: self.min.min(other.min), max: self.max.max(other.max) }
+    }
+}
+
+
+///3D axis aligned bounding box
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Aabb3 {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb3 {
+    pub fn new(min: Vec3, max: Vec3) -> Aabb3 {
+        Aabb3 { min, max }
+    }
+
+    ///Creates a box from its center and half of its size.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::Aabb3;
+    /// let aabb = Aabb3::from_center(Vec3::new(1.0, 1.0, 1.0), Vec3::new(2.0, 3.0, 4.0));
+    ///
+    /// assert_eq!(Aabb3::new(Vec3::new(-1.0, -2.0, -3.0), Vec3::new(3.0, 4.0, 5.0)), aabb);
+    /// ```
+    pub fn from_center(center: Vec3, half_extents: Vec3) -> Aabb3 {
+        Aabb3 { min: center - half_extents, max: center + half_extents }
+    }
+
+    ///Returns the center of this box.
+    pub fn center(&self) -> Vec3 {
+        self.min.lerp(self.max, 0.5)
+    }
+
+    ///Returns half of the size of this box.
+    pub fn half_extents(&self) -> Vec3 {
+        (self.max - self.min) * 0.5
+    }
+
+    ///Returns true if the point lies inside of this box or on its border.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::Aabb3;
+    /// let aabb = Aabb3::new(Vec3::ZERO, Vec3::ONE);
+    ///
+    /// assert!(aabb.contains(Vec3::new(0.5, 1.0, 0.0)));
+    /// assert!(!aabb.contains(Vec3::new(0.5, 0.5, -0.5)));
+    /// ```
+    pub fn contains(&self, point: Vec3) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x &&
+        point.y >= self.min.y && point.y <= self.max.y &&
+        point.z >= self.min.z && point.z <= self.max.z
+    }
+
+    ///Returns true if this box overlaps another one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::Aabb3;
+    /// let aabb = Aabb3::new(Vec3::ZERO, Vec3::ONE);
+    /// let other = Aabb3::new(Vec3::new(0.5, 0.5, 0.5), Vec3::new(2.0, 2.0, 2.0));
+    ///
+    /// assert!(aabb.intersects(&other));
+    /// ```
+    pub fn intersects(&self, other: &Aabb3) -> bool {
+        self.min.x <= other.max.x && self.max.x >= other.min.x &&
+        self.min.y <= other.max.y && self.max.y >= other.min.y &&
+        self.min.z <= other.max.z && self.max.z >= other.min.z
+    }
+
+    ///Returns the point of this box that is closest to the given point.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::Aabb3;
+    /// let aabb = Aabb3::new(Vec3::ZERO, Vec3::ONE);
+    ///
+    /// assert_eq!(Vec3::new(1.0, 0.5, 0.5), aabb.closest_point(Vec3::new(3.0, 0.5, 0.5)));
+    /// ```
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        point.max(self.min).min(self.max)
+    }
+
+    ///Returns the squared distance from this box to the given point, or 0.0 if the point lies inside.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::Aabb3;
+    /// let aabb = Aabb3::new(Vec3::ZERO, Vec3::ONE);
+    ///
+    /// assert_eq!(4.0, aabb.distance_squared_to(Vec3::new(3.0, 0.5, 0.5)));
+    /// ```
+    pub fn distance_squared_to(&self, point: Vec3) -> f32 {
+        self.closest_point(point).distance_to_squared(point)
+    }
+
+    ///Returns the smallest box containing this box and another one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::Aabb3;
+    /// let aabb = Aabb3::new(Vec3::ZERO, Vec3::ONE);
+    /// let other = Aabb3::new(Vec3::ONE, Vec3::new(2.0, 3.0, 4.0));
+    ///
+    /// assert_eq!(Aabb3::new(Vec3::ZERO, Vec3::new(2.0, 3.0, 4.0)), aabb.union(&other));
+    /// ```
+    pub fn union(&self, other: &Aabb3) -> Aabb3 {
+        Aabb3 { min: self.min.min(other.min), max: self.max.max(other.max) }
+    }
+
+    ///Returns the total area of the six sides of this box.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::Aabb3;
+    /// let aabb = Aabb3::new(Vec3::ZERO, Vec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(22.0, aabb.surface_area());
+    /// ```
+    pub fn surface_area(&self) -> f32 {
+        let size = self.max - self.min;
+        2.0 * (size.x * size.y + size.y * size.z + size.z * size.x)
+    }
+}
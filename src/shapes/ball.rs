@@ -0,0 +1,29 @@
+use crate::{Vec2, Vec3};
+
+
+///Circle described by its center and radius
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Circle {
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+impl Circle {
+    pub fn new(center: Vec2, radius: f32) -> Circle {
+        Circle { center, radius }
+    }
+}
+
+
+///Sphere described by its center and radius
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Sphere {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+impl Sphere {
+    pub fn new(center: Vec3, radius: f32) -> Sphere {
+        Sphere { center, radius }
+    }
+}
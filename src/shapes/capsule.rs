@@ -0,0 +1,31 @@
+use crate::{Vec2, Vec3};
+
+
+///2D capsule: every point within radius of the segment from a to b
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Capsule2 {
+    pub a: Vec2,
+    pub b: Vec2,
+    pub radius: f32,
+}
+
+impl Capsule2 {
+    pub fn new(a: Vec2, b: Vec2, radius: f32) -> Capsule2 {
+        Capsule2 { a, b, radius }
+    }
+}
+
+
+///3D capsule: every point within radius of the segment from a to b
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Capsule3 {
+    pub a: Vec3,
+    pub b: Vec3,
+    pub radius: f32,
+}
+
+impl Capsule3 {
+    pub fn new(a: Vec3, b: Vec3, radius: f32) -> Capsule3 {
+        Capsule3 { a, b, radius }
+    }
+}
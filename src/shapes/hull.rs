@@ -0,0 +1,33 @@
+use crate::{Vec2, Vec3};
+
+
+///Convex hull of a set of 2D points.
+/// The points don't need to be ordered and may include interior points.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ConvexHull2 {
+    pub points: Vec<Vec2>,
+}
+
+impl ConvexHull2 {
+    ///Creates a hull from its points. Panics if points is empty.
+    pub fn new(points: Vec<Vec2>) -> ConvexHull2 {
+        assert!(!points.is_empty(), "a convex hull needs at least one point");
+        ConvexHull2 { points }
+    }
+}
+
+
+///Convex hull of a set of 3D points.
+/// The points don't need to be ordered and may include interior points.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ConvexHull3 {
+    pub points: Vec<Vec3>,
+}
+
+impl ConvexHull3 {
+    ///Creates a hull from its points. Panics if points is empty.
+    pub fn new(points: Vec<Vec3>) -> ConvexHull3 {
+        assert!(!points.is_empty(), "a convex hull needs at least one point");
+        ConvexHull3 { points }
+    }
+}
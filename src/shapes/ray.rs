@@ -0,0 +1,114 @@
+use crate::{Vec3, Dir3};
+use crate::shapes::{Aabb3, Triangle3};
+
+
+//determinants below this mean the ray is parallel to the triangle
+const PARALLEL_TOLERANCE: f32 = 1e-8;
+
+
+///3D ray described by its origin and a direction with a magnitude of 1
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Ray3 {
+    pub origin: Vec3,
+    pub direction: Vec3,
+}
+
+impl Ray3 {
+    ///Creates a ray. The direction gets normalized.
+    pub fn new(origin: Vec3, direction: Vec3) -> Ray3 {
+        Ray3 { origin, direction: direction.normalized() }
+    }
+
+    ///Creates a ray from a direction that is already normalized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::{Vec3, Dir3};
+    /// use gamevecs::shapes::Ray3;
+    /// let ray = Ray3::from_dir(Vec3::ZERO, Dir3::FORWARD);
+    ///
+    /// assert_eq!(Vec3::new(0.0, 0.0, 2.0), ray.at(2.0));
+    /// ```
+    pub fn from_dir(origin: Vec3, direction: Dir3) -> Ray3 {
+        Ray3 { origin, direction: *direction }
+    }
+
+    ///Returns the point at the given distance along this ray.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::Ray3;
+    /// let ray = Ray3::new(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 2.0, 0.0));
+    ///
+    /// assert_eq!(Vec3::new(1.0, 3.0, 0.0), ray.at(3.0));
+    /// ```
+    pub fn at(&self, distance: f32) -> Vec3 {
+        self.origin + self.direction * distance
+    }
+
+    ///Returns the distance at which this ray enters the box, 0.0 if it starts inside,
+    /// or None if it misses.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::{Aabb3, Ray3};
+    /// let ray = Ray3::new(Vec3::new(-5.0, 0.5, 0.5), Vec3::RIGHT);
+    /// let aabb = Aabb3::new(Vec3::ZERO, Vec3::ONE);
+    ///
+    /// assert_eq!(Some(5.0), ray.intersect_aabb(&aabb));
+    /// ```
+    pub fn intersect_aabb(&self, aabb: &Aabb3) -> Option<f32> {
+        let inverse = Vec3::ONE / self.direction;
+        let (near, far) = slabs(self.origin, inverse, aabb);
+        (near <= far && far >= 0.0).then_some(near.max(0.0))
+    }
+
+    ///Returns the distance at which this ray hits the triangle from either side, or None if it misses.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::{Ray3, Triangle3};
+    /// let ray = Ray3::new(Vec3::new(0.2, 0.2, -3.0), Vec3::FORWARD);
+    /// let triangle = Triangle3::new(Vec3::ZERO, Vec3::RIGHT, Vec3::UP);
+    ///
+    /// assert_eq!(Some(3.0), ray.intersect_triangle(&triangle));
+    /// ```
+    pub fn intersect_triangle(&self, triangle: &Triangle3) -> Option<f32> {
+        //Möller-Trumbore
+        let edge1 = triangle.b - triangle.a;
+        let edge2 = triangle.c - triangle.a;
+        let p = self.direction.cross(edge2);
+        let determinant = edge1.dot(p);
+        if determinant.abs() < PARALLEL_TOLERANCE {
+            return None;
+        }
+
+        let inverse = 1.0 / determinant;
+        let offset = self.origin - triangle.a;
+        let u = offset.dot(p) * inverse;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = offset.cross(edge1);
+        let v = self.direction.dot(q) * inverse;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let distance = edge2.dot(q) * inverse;
+        (distance >= 0.0).then_some(distance)
+    }
+}
+
+//returns the distances at which a ray enters and leaves the slabs of a box
+pub(crate) fn slabs(origin: Vec3, inverse_direction: Vec3, aabb: &Aabb3) -> (f32, f32) {
+    let t1 = (aabb.min - origin) * inverse_direction;
+    let t2 = (aabb.max - origin) * inverse_direction;
+    let near = t1.min(t2);
+    let far = t1.max(t2);
+
+    (near.x.max(near.y).max(near.z), far.x.min(far.y).min(far.z))
+}
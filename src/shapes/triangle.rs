@@ -0,0 +1,47 @@
+use crate::Vec3;
+use crate::shapes::Aabb3;
+
+
+///3D triangle described by its corners
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Triangle3 {
+    pub a: Vec3,
+    pub b: Vec3,
+    pub c: Vec3,
+}
+
+impl Triangle3 {
+    pub fn new(a: Vec3, b: Vec3, c: Vec3) -> Triangle3 {
+        Triangle3 { a, b, c }
+    }
+
+    ///Returns the normal of this triangle with a magnitude of 1.
+    /// Points towards the side from which the corners appear counterclockwise.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::Triangle3;
+    /// let triangle = Triangle3::new(Vec3::ZERO, Vec3::RIGHT, Vec3::UP);
+    ///
+    /// assert_eq!(Vec3::FORWARD, triangle.normal());
+    /// ```
+    pub fn normal(&self) -> Vec3 {
+        (self.b - self.a).cross(self.c - self.a).normalized()
+    }
+
+    ///Returns the center of mass of this triangle.
+    pub fn centroid(&self) -> Vec3 {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    ///Returns the area of this triangle.
+    pub fn area(&self) -> f32 {
+        (self.b - self.a).cross(self.c - self.a).magnitude() * 0.5
+    }
+
+    ///Returns the smallest box containing this triangle.
+    pub fn aabb(&self) -> Aabb3 {
+        Aabb3::new(self.a.min(self.b).min(self.c), self.a.max(self.b).max(self.c))
+    }
+}
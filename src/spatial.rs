@@ -0,0 +1,9 @@
+mod orthtree;
+mod spatial_hash;
+mod kdtree;
+mod bvh;
+
+pub use self::orthtree::{ItemId, Region, Orthtree, Quadtree, Octree, Nearest};
+pub use self::spatial_hash::{GridVector, SpatialHash, SpatialHash2, SpatialHash3, Neighbors, Pairs};
+pub use self::kdtree::{KdTree, KdTree2, KdTree3};
+pub use self::bvh::{Bvh, RayHit};
@@ -0,0 +1,360 @@
+use crate::{Vec3, Vector};
+use crate::shapes::{Aabb3, Ray3, Triangle3};
+use crate::shapes::slabs;
+
+
+//nodes with at most this many triangles may become leaves
+const MAX_LEAF_SIZE: usize = 4;
+//deeper nodes always become leaves, which keeps the traversal stack bounded
+const MAX_DEPTH: usize = 48;
+const STACK_SIZE: usize = MAX_DEPTH + 2;
+const BINS: usize = 12;
+//cost of visiting a node relative to testing a triangle
+const TRAVERSAL_COST: f32 = 1.0;
+
+const EMPTY: Aabb3 = Aabb3 {
+    min: Vec3 { x: f32::INFINITY, y: f32::INFINITY, z: f32::INFINITY },
+    max: Vec3 { x: f32::NEG_INFINITY, y: f32::NEG_INFINITY, z: f32::NEG_INFINITY },
+};
+
+
+///Hit of a ray against a triangle of a Bvh
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct RayHit {
+    ///Index of the hit triangle in the slice the Bvh was built from.
+    pub triangle: usize,
+    ///Distance along the ray.
+    pub distance: f32,
+    pub point: Vec3,
+    ///Normal of the hit triangle with a magnitude of 1.
+    pub normal: Vec3,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    bounds: Aabb3,
+    //leaves: first entry in indices, inner nodes: index of the second child (the first one follows the node directly)
+    offset: u32,
+    //number of triangles, 0 for inner nodes
+    count: u32,
+}
+
+///Bounding volume hierarchy over a triangle mesh, built with the surface area heuristic.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec3;
+/// use gamevecs::shapes::{Ray3, Triangle3};
+/// use gamevecs::spatial::Bvh;
+/// let floor = [
+///     Triangle3::new(Vec3::new(-10.0, 0.0, -10.0), Vec3::new(10.0, 0.0, -10.0), Vec3::new(10.0, 0.0, 10.0)),
+///     Triangle3::new(Vec3::new(-10.0, 0.0, -10.0), Vec3::new(10.0, 0.0, 10.0), Vec3::new(-10.0, 0.0, 10.0)),
+/// ];
+/// let bvh = Bvh::new(&floor);
+///
+/// let hit = bvh.raycast(&Ray3::new(Vec3::new(1.0, 5.0, 2.0), Vec3::DOWN), 100.0).unwrap();
+///
+/// assert_eq!(5.0, hit.distance);
+/// assert_eq!(Vec3::new(1.0, 0.0, 2.0), hit.point);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bvh {
+    nodes: Vec<Node>,
+    triangles: Vec<Triangle3>,
+    indices: Vec<u32>,
+}
+
+impl Bvh {
+    ///Builds a hierarchy over the triangles.
+    pub fn new(triangles: &[Triangle3]) -> Bvh {
+        let bounds: Vec<Aabb3> = triangles.iter().map(|triangle| triangle.aabb()).collect();
+        let centroids: Vec<Vec3> = bounds.iter().map(|bounds| bounds.center()).collect();
+        let mut bvh = Bvh {
+            nodes: Vec::with_capacity(triangles.len() * 2),
+            triangles: triangles.to_vec(),
+            indices: (0..triangles.len() as u32).collect(),
+        };
+        if !triangles.is_empty() {
+            bvh.build(&bounds, &centroids, 0, triangles.len(), 0);
+        }
+
+        bvh
+    }
+
+    ///Returns the triangles of this hierarchy in their original order.
+    pub fn triangles(&self) -> &[Triangle3] {
+        &self.triangles
+    }
+
+    ///Returns the bounds of the whole mesh, or None if it has no triangles.
+    pub fn bounds(&self) -> Option<Aabb3> {
+        self.nodes.first().map(|node| node.bounds)
+    }
+
+    ///Replaces the triangles with moved versions of them and updates the bounds of the hierarchy.
+    /// Much cheaper than rebuilding, but the hierarchy gets worse the more the mesh deforms.
+    /// Panics if the number of triangles changed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::{Ray3, Triangle3};
+    /// use gamevecs::spatial::Bvh;
+    /// let mut triangle = Triangle3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0));
+    /// let mut bvh = Bvh::new(&[triangle]);
+    ///
+    /// //move the triangle away from the ray
+    /// triangle.a.z += 5.0;
+    /// triangle.b.z += 5.0;
+    /// triangle.c.z += 5.0;
+    /// bvh.refit(&[triangle]);
+    ///
+    /// let hit = bvh.raycast(&Ray3::new(Vec3::new(0.2, 0.2, -1.0), Vec3::FORWARD), 100.0).unwrap();
+    /// assert_eq!(6.0, hit.distance);
+    /// ```
+    pub fn refit(&mut self, triangles: &[Triangle3]) {
+        assert_eq!(self.triangles.len(), triangles.len(), "refitting can't change the number of triangles");
+        self.triangles.copy_from_slice(triangles);
+
+        //children are always stored after their parent
+        for index in (0..self.nodes.len()).rev() {
+            let node = self.nodes[index];
+            self.nodes[index].bounds = if node.count > 0 {
+                self.leaf_bounds(node.offset as usize, node.count as usize)
+            } else {
+                self.nodes[index + 1].bounds.union(&self.nodes[node.offset as usize].bounds)
+            };
+        }
+    }
+
+    ///Returns the closest hit of the ray that is at most max_distance away.
+    pub fn raycast(&self, ray: &Ray3, max_distance: f32) -> Option<RayHit> {
+        self.cast(ray, max_distance, false).map(|(distance, triangle)| RayHit {
+            triangle,
+            distance,
+            point: ray.at(distance),
+            normal: self.triangles[triangle].normal(),
+        })
+    }
+
+    ///Returns true if the ray hits any triangle at most max_distance away.
+    /// Faster than raycast, useful for line of sight and shadow checks.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::{Ray3, Triangle3};
+    /// use gamevecs::spatial::Bvh;
+    /// let wall = Triangle3::new(Vec3::new(-5.0, 0.0, 5.0), Vec3::new(5.0, 0.0, 5.0), Vec3::new(0.0, 5.0, 5.0));
+    /// let bvh = Bvh::new(&[wall]);
+    /// let ray = Ray3::new(Vec3::new(0.0, 1.0, 0.0), Vec3::FORWARD);
+    ///
+    /// assert!(bvh.raycast_any(&ray, 10.0));
+    /// assert!(!bvh.raycast_any(&ray, 4.0));
+    /// ```
+    pub fn raycast_any(&self, ray: &Ray3, max_distance: f32) -> bool {
+        self.cast(ray, max_distance, true).is_some()
+    }
+
+    ///Returns an iterator over the indices of all triangles whose bounds overlap the box.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::{Aabb3, Triangle3};
+    /// use gamevecs::spatial::Bvh;
+    /// let triangles = [
+    ///     Triangle3::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0)),
+    ///     Triangle3::new(Vec3::new(5.0, 0.0, 0.0), Vec3::new(6.0, 0.0, 0.0), Vec3::new(5.0, 1.0, 0.0)),
+    /// ];
+    /// let bvh = Bvh::new(&triangles);
+    ///
+    /// let found: Vec<usize> = bvh.query_aabb(Aabb3::new(Vec3::new(4.0, 0.0, -1.0), Vec3::new(7.0, 1.0, 1.0))).collect();
+    ///
+    /// assert_eq!(vec![1], found);
+    /// ```
+    pub fn query_aabb(&self, aabb: Aabb3) -> impl Iterator<Item = usize> + '_ {
+        let mut stack = [0u32; STACK_SIZE];
+        let mut stack_len = if self.nodes.is_empty() { 0 } else { 1 };
+        let mut leaf = 0..0;
+
+        std::iter::from_fn(move || loop {
+            for i in leaf.by_ref() {
+                let triangle = self.indices[i] as usize;
+                if self.triangles[triangle].aabb().intersects(&aabb) {
+                    return Some(triangle);
+                }
+            }
+
+            if stack_len == 0 {
+                return None;
+            }
+            stack_len -= 1;
+            let index = stack[stack_len] as usize;
+            let node = self.nodes[index];
+            if !node.bounds.intersects(&aabb) {
+                continue;
+            }
+            if node.count > 0 {
+                leaf = node.offset as usize..(node.offset + node.count) as usize;
+            } else {
+                stack[stack_len] = index as u32 + 1;
+                stack[stack_len + 1] = node.offset;
+                stack_len += 2;
+            }
+        })
+    }
+
+    //----------Internals----------
+
+    fn leaf_bounds(&self, start: usize, count: usize) -> Aabb3 {
+        self.indices[start..start + count].iter()
+            .fold(EMPTY, |bounds, index| bounds.union(&self.triangles[*index as usize].aabb()))
+    }
+
+    //Returns the distance and triangle of the closest hit, or of the first one found if any_hit is set.
+    fn cast(&self, ray: &Ray3, max_distance: f32, any_hit: bool) -> Option<(f32, usize)> {
+        if self.nodes.is_empty() {
+            return None;
+        }
+        let inverse = Vec3::ONE / ray.direction;
+        let entry = |index: u32| {
+            let (near, far) = slabs(ray.origin, inverse, &self.nodes[index as usize].bounds);
+            (near <= far && far >= 0.0).then_some(near.max(0.0))
+        };
+
+        let mut closest = None;
+        let mut limit = max_distance;
+        let mut stack = [0u32; STACK_SIZE];
+        let mut stack_len = 1;
+        while stack_len > 0 {
+            stack_len -= 1;
+            let index = stack[stack_len];
+            match entry(index) {
+                Some(distance) if distance <= limit => {}
+                _ => continue,
+            }
+
+            let node = self.nodes[index as usize];
+            if node.count > 0 {
+                for i in node.offset..node.offset + node.count {
+                    let triangle = self.indices[i as usize] as usize;
+                    match ray.intersect_triangle(&self.triangles[triangle]) {
+                        Some(distance) if distance <= limit => {
+                            closest = Some((distance, triangle));
+                            limit = distance;
+                            if any_hit {
+                                return closest;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                continue;
+            }
+
+            //push the farther child first, so the nearer one gets visited first
+            let first = index + 1;
+            let second = node.offset;
+            let first_distance = entry(first).unwrap_or(f32::INFINITY);
+            let second_distance = entry(second).unwrap_or(f32::INFINITY);
+            let (near, far) = if first_distance <= second_distance { (first, second) } else { (second, first) };
+            stack[stack_len] = far;
+            stack[stack_len + 1] = near;
+            stack_len += 2;
+        }
+
+        closest
+    }
+
+    //builds the node for the triangles indices[start..end] and returns its index
+    fn build(&mut self, bounds: &[Aabb3], centroids: &[Vec3], start: usize, end: usize, depth: usize) -> usize {
+        let index = self.nodes.len();
+        let count = end - start;
+        let node_bounds = self.indices[start..end].iter().fold(EMPTY, |total, i| total.union(&bounds[*i as usize]));
+        self.nodes.push(Node { bounds: node_bounds, offset: start as u32, count: count as u32 });
+
+        if count <= 1 || depth >= MAX_DEPTH {
+            return index;
+        }
+
+        let split = self.find_split(bounds, centroids, start, end, node_bounds.surface_area());
+        let middle = match split {
+            Some((axis, position)) => {
+                let mut middle = start;
+                for i in start..end {
+                    if centroids[self.indices[i] as usize].component(axis) < position {
+                        self.indices.swap(i, middle);
+                        middle += 1;
+                    }
+                }
+                middle
+            }
+            None if count <= MAX_LEAF_SIZE => return index,
+            None => start + count / 2,
+        };
+        //fall back to an even split if every triangle ended up on one side
+        let middle = if middle == start || middle == end { start + count / 2 } else { middle };
+
+        self.build(bounds, centroids, start, middle, depth + 1);
+        let second = self.build(bounds, centroids, middle, end, depth + 1);
+        self.nodes[index].offset = second as u32;
+        self.nodes[index].count = 0;
+
+        index
+    }
+
+    //Returns the axis and position of the cheapest split according to the surface area heuristic,
+    //or None if keeping the node as a leaf is cheaper.
+    fn find_split(&self, bounds: &[Aabb3], centroids: &[Vec3], start: usize, end: usize, area: f32) -> Option<(usize, f32)> {
+        let indices = &self.indices[start..end];
+        let centroid_bounds = indices.iter().fold(EMPTY, |total, i| {
+            let centroid = centroids[*i as usize];
+            total.union(&Aabb3::new(centroid, centroid))
+        });
+
+        let mut best: Option<(usize, f32)> = None;
+        let mut best_cost = f32::INFINITY;
+        for axis in 0..3 {
+            let min = centroid_bounds.min.component(axis);
+            let extent = centroid_bounds.max.component(axis) - min;
+            if extent <= 0.0 {
+                continue;
+            }
+
+            let mut bins = [(EMPTY, 0usize); BINS];
+            for i in indices {
+                let bin = (((centroids[*i as usize].component(axis) - min) / extent * BINS as f32) as usize).min(BINS - 1);
+                bins[bin].0 = bins[bin].0.union(&bounds[*i as usize]);
+                bins[bin].1 += 1;
+            }
+
+            //sweep from the right to know the cost of everything right of each split
+            let mut right_costs = [0.0; BINS];
+            let mut right = (EMPTY, 0);
+            for bin in (1..BINS).rev() {
+                right = (right.0.union(&bins[bin].0), right.1 + bins[bin].1);
+                right_costs[bin] = if right.1 > 0 { right.0.surface_area() * right.1 as f32 } else { 0.0 };
+            }
+            let mut left = (EMPTY, 0);
+            for bin in 1..BINS {
+                left = (left.0.union(&bins[bin - 1].0), left.1 + bins[bin - 1].1);
+                let left_cost = if left.1 > 0 { left.0.surface_area() * left.1 as f32 } else { 0.0 };
+                let cost = left_cost + right_costs[bin];
+                if cost < best_cost {
+                    best_cost = cost;
+                    best = Some((axis, min + extent * bin as f32 / BINS as f32));
+                }
+            }
+        }
+
+        //splitting has to beat testing every triangle of the node
+        let count = end - start;
+        let split_cost = TRAVERSAL_COST + best_cost / area;
+        if count <= MAX_LEAF_SIZE && (area <= 0.0 || split_cost >= count as f32) {
+            return None;
+        }
+
+        best
+    }
+}
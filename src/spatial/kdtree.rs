@@ -0,0 +1,228 @@
+use crate::{Vec2, Vec3, Vector};
+
+
+//a balanced tree over u32 indices is never deeper than this
+const STACK_SIZE: usize = 64;
+
+
+///k-d tree over a fixed set of points, built once and queried many times.
+/// Use the KdTree2 and KdTree3 aliases.
+///
+/// Queries return the index of the point in the slice the tree was built from, together with the point.
+#[derive(Debug, Clone)]
+pub struct KdTree<V: Vector> {
+    //points reordered so every subrange is a subtree with its splitting point in the middle
+    points: Vec<V>,
+    indices: Vec<u32>,
+    //splitting axis of the subtree whose middle is at the same position
+    axes: Vec<u8>,
+}
+
+///k-d tree over Vec2 points.
+pub type KdTree2 = KdTree<Vec2>;
+///k-d tree over Vec3 points.
+pub type KdTree3 = KdTree<Vec3>;
+
+impl<V: Vector> KdTree<V> {
+    ///Builds a tree from a set of points.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::spatial::KdTree3;
+    /// let vertices = [Vec3::new(0.0, 0.0, 0.0), Vec3::new(5.0, 0.0, 0.0), Vec3::new(0.0, 5.0, 0.0)];
+    /// let tree = KdTree3::new(&vertices);
+    ///
+    /// //snap a point to the nearest vertex
+    /// let (index, vertex) = tree.nearest(Vec3::new(4.0, 1.0, 0.0)).unwrap();
+    ///
+    /// assert_eq!(1, index);
+    /// assert_eq!(Vec3::new(5.0, 0.0, 0.0), vertex);
+    /// ```
+    pub fn new(points: &[V]) -> KdTree<V> {
+        let mut entries: Vec<(V, u32)> = points.iter().enumerate().map(|(i, point)| (*point, i as u32)).collect();
+        let mut axes = vec![0; points.len()];
+        build(&mut entries, &mut axes);
+
+        KdTree {
+            points: entries.iter().map(|entry| entry.0).collect(),
+            indices: entries.iter().map(|entry| entry.1).collect(),
+            axes,
+        }
+    }
+
+    ///Returns the number of points in this tree.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    ///Returns true if this tree contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    ///Returns the point nearest to the given one as (index, point), or None if the tree is empty.
+    pub fn nearest(&self, point: V) -> Option<(usize, V)> {
+        let mut best = (f32::INFINITY, usize::MAX);
+        self.nearest_in(0, self.points.len(), point, &mut best);
+
+        (best.1 != usize::MAX).then(|| self.item(best.1))
+    }
+
+    ///Returns the k points nearest to the given one as (index, point), nearest first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::spatial::KdTree2;
+    /// let points = [Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), Vec2::new(3.0, 0.0), Vec2::new(1.0, 0.0)];
+    /// let tree = KdTree2::new(&points);
+    ///
+    /// let nearest: Vec<usize> = tree.k_nearest(Vec2::new(2.5, 0.0), 3).iter().map(|(index, _)| *index).collect();
+    ///
+    /// assert_eq!(vec![2, 3, 0], nearest);
+    /// ```
+    pub fn k_nearest(&self, point: V, k: usize) -> Vec<(usize, V)> {
+        let mut best: Vec<(f32, usize)> = Vec::with_capacity(k + 1);
+        if k > 0 {
+            self.k_nearest_in(0, self.points.len(), point, k, &mut best);
+        }
+
+        best.iter().map(|(_, position)| self.item(*position)).collect()
+    }
+
+    ///Returns an iterator over all points within radius of the center as (index, point).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::spatial::KdTree2;
+    /// let points = [Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), Vec2::new(3.0, 0.0)];
+    /// let tree = KdTree2::new(&points);
+    ///
+    /// let mut found: Vec<usize> = tree.query_radius(Vec2::new(1.0, 0.0), 2.0).map(|(index, _)| index).collect();
+    /// found.sort();
+    ///
+    /// assert_eq!(vec![0, 2], found);
+    /// ```
+    pub fn query_radius(&self, center: V, radius: f32) -> impl Iterator<Item = (usize, V)> + '_ {
+        let mut stack = [(0u32, 0u32); STACK_SIZE];
+        stack[0] = (0, self.points.len() as u32);
+        let mut stack_len = 1;
+        let radius_squared = radius * radius;
+
+        std::iter::from_fn(move || {
+            while stack_len > 0 {
+                stack_len -= 1;
+                let (start, end) = (stack[stack_len].0 as usize, stack[stack_len].1 as usize);
+                if start >= end {
+                    continue;
+                }
+
+                let middle = (start + end) / 2;
+                let axis = self.axes[middle] as usize;
+                let difference = center.component(axis) - self.points[middle].component(axis);
+                if difference <= radius {
+                    stack[stack_len] = (start as u32, middle as u32);
+                    stack_len += 1;
+                }
+                if difference >= -radius {
+                    stack[stack_len] = (middle as u32 + 1, end as u32);
+                    stack_len += 1;
+                }
+
+                if self.points[middle].distance_to_squared(center) <= radius_squared {
+                    return Some(self.item(middle));
+                }
+            }
+            None
+        })
+    }
+
+    //----------Internals----------
+
+    fn item(&self, position: usize) -> (usize, V) {
+        (self.indices[position] as usize, self.points[position])
+    }
+
+    //returns the subtree on the side of the point first
+    fn split(&self, start: usize, end: usize, point: V) -> (f32, (usize, usize), (usize, usize)) {
+        let middle = (start + end) / 2;
+        let axis = self.axes[middle] as usize;
+        let difference = point.component(axis) - self.points[middle].component(axis);
+        if difference < 0.0 {
+            (difference, (start, middle), (middle + 1, end))
+        } else {
+            (difference, (middle + 1, end), (start, middle))
+        }
+    }
+
+    fn nearest_in(&self, start: usize, end: usize, point: V, best: &mut (f32, usize)) {
+        if start >= end {
+            return;
+        }
+
+        let middle = (start + end) / 2;
+        let distance = self.points[middle].distance_to_squared(point);
+        if distance < best.0 {
+            *best = (distance, middle);
+        }
+
+        let (difference, near, far) = self.split(start, end, point);
+        self.nearest_in(near.0, near.1, point, best);
+        //the other side can only contain a nearer point if the splitting plane is nearer
+        if difference * difference < best.0 {
+            self.nearest_in(far.0, far.1, point, best);
+        }
+    }
+
+    fn k_nearest_in(&self, start: usize, end: usize, point: V, k: usize, best: &mut Vec<(f32, usize)>) {
+        if start >= end {
+            return;
+        }
+
+        let middle = (start + end) / 2;
+        let distance = self.points[middle].distance_to_squared(point);
+        if best.len() < k || distance < best[best.len() - 1].0 {
+            let index = best.partition_point(|entry| entry.0 <= distance);
+            best.insert(index, (distance, middle));
+            best.truncate(k);
+        }
+
+        let (difference, near, far) = self.split(start, end, point);
+        self.k_nearest_in(near.0, near.1, point, k, best);
+        if best.len() < k || difference * difference < best[best.len() - 1].0 {
+            self.k_nearest_in(far.0, far.1, point, k, best);
+        }
+    }
+}
+
+//reorders the entries into a balanced tree, splitting along the widest axis
+fn build<V: Vector>(entries: &mut [(V, u32)], axes: &mut [u8]) {
+    if entries.len() <= 1 {
+        return;
+    }
+
+    let mut min = entries[0].0;
+    let mut max = entries[0].0;
+    for (point, _) in entries.iter() {
+        min = min.min(*point);
+        max = max.max(*point);
+    }
+    let extents = max - min;
+    let mut axis = 0;
+    for i in 1..V::DIMENSIONS {
+        if extents.component(i) > extents.component(axis) {
+            axis = i;
+        }
+    }
+
+    let middle = entries.len() / 2;
+    entries.select_nth_unstable_by(middle, |a, b| a.0.component(axis).total_cmp(&b.0.component(axis)));
+    axes[middle] = axis as u8;
+
+    let (left_entries, right_entries) = entries.split_at_mut(middle);
+    let (left_axes, right_axes) = axes.split_at_mut(middle);
+    build(left_entries, left_axes);
+    build(&mut right_entries[1..], &mut right_axes[1..]);
+}
@@ -0,0 +1,612 @@
+use crate::{Vec2, Vec3, Vector};
+use crate::shapes::{Aabb2, Aabb3};
+
+
+//leaves with more items than this are split
+const NODE_CAPACITY: usize = 8;
+const MAX_DEPTH: u32 = 16;
+//enough room for a depth first traversal of MAX_DEPTH levels with 8 children each
+const STACK_SIZE: usize = 7 * MAX_DEPTH as usize + 8;
+const NONE: u32 = u32::MAX;
+//items the nearest iterator finds per search of the tree
+const NEAREST_BATCH: usize = 16;
+
+
+///Bounding region that can be split into equally sized children.
+/// Implemented by Aabb2 (4 children) and Aabb3 (8 children).
+pub trait Region: Copy {
+    type Vector: Vector;
+    ///Number of children a region is split into.
+    const CHILDREN: usize;
+
+    ///Returns true if the point lies inside of this region or on its border.
+    fn contains(&self, point: Self::Vector) -> bool;
+    ///Returns true if this region overlaps another one.
+    fn intersects(&self, other: &Self) -> bool;
+    ///Returns the squared distance from this region to the point, or 0.0 if the point lies inside.
+    fn distance_squared_to(&self, point: Self::Vector) -> f32;
+    ///Returns the child region with the given index.
+    fn child(&self, index: usize) -> Self;
+    ///Returns the index of the child region the point belongs to.
+    fn child_index(&self, point: Self::Vector) -> usize;
+}
+
+impl Region for Aabb2 {
+    type Vector = Vec2;
+    const CHILDREN: usize = 4;
+
+    fn contains(&self, point: Vec2) -> bool {
+        Aabb2::contains(self, point)
+    }
+    fn intersects(&self, other: &Aabb2) -> bool {
+        Aabb2::intersects(self, other)
+    }
+    fn distance_squared_to(&self, point: Vec2) -> f32 {
+        Aabb2::distance_squared_to(self, point)
+    }
+    fn child(&self, index: usize) -> Aabb2 {
+        let center = self.center();
+        let (min_x, max_x) = if index & 1 == 0 { (self.min.x, center.x) } else { (center.x, self.max.x) };
+        let (min_y, max_y) = if index & 2 == 0 { (self.min.y, center.y) } else { (center.y, self.max.y) };
+        Aabb2::new(Vec2::new(min_x, min_y), Vec2::new(max_x, max_y))
+    }
+    fn child_index(&self, point: Vec2) -> usize {
+        let center = self.center();
+        (point.x >= center.x) as usize | ((point.y >= center.y) as usize) << 1
+    }
+}
+
+impl Region for Aabb3 {
+    type Vector = Vec3;
+    const CHILDREN: usize = 8;
+
+    fn contains(&self, point: Vec3) -> bool {
+        Aabb3::contains(self, point)
+    }
+    fn intersects(&self, other: &Aabb3) -> bool {
+        Aabb3::intersects(self, other)
+    }
+    fn distance_squared_to(&self, point: Vec3) -> f32 {
+        Aabb3::distance_squared_to(self, point)
+    }
+    fn child(&self, index: usize) -> Aabb3 {
+        let center = self.center();
+        let (min_x, max_x) = if index & 1 == 0 { (self.min.x, center.x) } else { (center.x, self.max.x) };
+        let (min_y, max_y) = if index & 2 == 0 { (self.min.y, center.y) } else { (center.y, self.max.y) };
+        let (min_z, max_z) = if index & 4 == 0 { (self.min.z, center.z) } else { (center.z, self.max.z) };
+        Aabb3::new(Vec3::new(min_x, min_y, min_z), Vec3::new(max_x, max_y, max_z))
+    }
+    fn child_index(&self, point: Vec3) -> usize {
+        let center = self.center();
+        (point.x >= center.x) as usize | ((point.y >= center.y) as usize) << 1 | ((point.z >= center.z) as usize) << 2
+    }
+}
+
+
+///Handle of an item stored in one of the spatial structures.
+/// Handles of removed items get reused by later insertions.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub struct ItemId(pub(crate) u32);
+
+#[derive(Debug, Clone)]
+struct Node<B> {
+    bounds: B,
+    parent: u32,
+    //the children are stored next to each other, starting at this index
+    first_child: u32,
+    depth: u32,
+    items: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<V, T> {
+    position: V,
+    value: T,
+    node: u32,
+}
+
+///Tree that recursively splits its bounds into equally sized children.
+/// Use the Quadtree and Octree aliases.
+///
+/// Items outside of the bounds of the tree are still stored, but aren't sped up by it.
+#[derive(Debug, Clone)]
+pub struct Orthtree<B: Region, T> {
+    nodes: Vec<Node<B>>,
+    free_nodes: Vec<u32>,
+    items: Vec<Option<Entry<B::Vector, T>>>,
+    free_items: Vec<u32>,
+    len: usize,
+}
+
+///Tree over Vec2 positions that splits its nodes into quarters.
+pub type Quadtree<T> = Orthtree<Aabb2, T>;
+///Tree over Vec3 positions that splits its nodes into eighths.
+pub type Octree<T> = Orthtree<Aabb3, T>;
+
+impl<B: Region, T> Orthtree<B, T> {
+    ///Creates an empty tree covering the given bounds.
+    pub fn new(bounds: B) -> Orthtree<B, T> {
+        Orthtree {
+            nodes: vec![Node { bounds, parent: NONE, first_child: NONE, depth: 0, items: Vec::new() }],
+            free_nodes: Vec::new(),
+            items: Vec::new(),
+            free_items: Vec::new(),
+            len: 0,
+        }
+    }
+
+    ///Returns the bounds covered by this tree.
+    pub fn bounds(&self) -> B {
+        self.nodes[0].bounds
+    }
+
+    ///Returns the number of items in this tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    ///Returns true if this tree contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///Removes all items from this tree.
+    pub fn clear(&mut self) {
+        let bounds = self.bounds();
+        *self = Orthtree::new(bounds);
+    }
+
+    ///Inserts an item at the given position and returns its handle.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::shapes::Aabb2;
+    /// use gamevecs::spatial::Quadtree;
+    /// let mut tree = Quadtree::new(Aabb2::new(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+    ///
+    /// let id = tree.insert(Vec2::new(10.0, 20.0), "player");
+    ///
+    /// assert_eq!(Some(&"player"), tree.get(id));
+    /// assert_eq!(Some(Vec2::new(10.0, 20.0)), tree.position(id));
+    /// ```
+    pub fn insert(&mut self, position: B::Vector, value: T) -> ItemId {
+        let entry = Some(Entry { position, value, node: NONE });
+        let id = match self.free_items.pop() {
+            Some(id) => { self.items[id as usize] = entry; id }
+            None => { self.items.push(entry); (self.items.len() - 1) as u32 }
+        };
+        self.len += 1;
+        self.place(id);
+
+        ItemId(id)
+    }
+
+    ///Removes an item and returns its value, or None if the handle is invalid.
+    pub fn remove(&mut self, id: ItemId) -> Option<T> {
+        let entry = self.items.get_mut(id.0 as usize)?.take()?;
+        self.free_items.push(id.0);
+        self.len -= 1;
+
+        self.detach(id.0, entry.node);
+        self.collapse(self.nodes[entry.node as usize].parent);
+
+        Some(entry.value)
+    }
+
+    ///Moves an item to a new position. Returns false if the handle is invalid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::shapes::Aabb2;
+    /// use gamevecs::spatial::Quadtree;
+    /// let mut tree = Quadtree::new(Aabb2::new(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+    /// let id = tree.insert(Vec2::new(10.0, 20.0), "player");
+    ///
+    /// tree.move_item(id, Vec2::new(90.0, 90.0));
+    ///
+    /// assert_eq!(1, tree.query_radius(Vec2::new(90.0, 90.0), 1.0).count());
+    /// ```
+    pub fn move_item(&mut self, id: ItemId, position: B::Vector) -> bool {
+        let Some(Some(entry)) = self.items.get_mut(id.0 as usize) else {
+            return false;
+        };
+        let node = entry.node;
+        entry.position = position;
+
+        //the item can stay in its leaf if it didn't leave it
+        let leaf = &self.nodes[node as usize];
+        if leaf.first_child == NONE && (node == 0 || leaf.bounds.contains(position)) {
+            return true;
+        }
+
+        self.detach(id.0, node);
+        self.place(id.0);
+        self.collapse(self.nodes[node as usize].parent);
+
+        true
+    }
+
+    ///Returns the value of an item.
+    pub fn get(&self, id: ItemId) -> Option<&T> {
+        self.entry(id).map(|entry| &entry.value)
+    }
+
+    ///Returns the value of an item as mutable.
+    pub fn get_mut(&mut self, id: ItemId) -> Option<&mut T> {
+        match self.items.get_mut(id.0 as usize) {
+            Some(Some(entry)) => Some(&mut entry.value),
+            _ => None,
+        }
+    }
+
+    ///Returns the position of an item.
+    pub fn position(&self, id: ItemId) -> Option<B::Vector> {
+        self.entry(id).map(|entry| entry.position)
+    }
+
+    ///Returns an iterator over all items as (handle, position, value).
+    pub fn iter(&self) -> impl Iterator<Item = (ItemId, B::Vector, &T)> + '_ {
+        self.items.iter().enumerate().filter_map(|(id, entry)| {
+            entry.as_ref().map(|entry| (ItemId(id as u32), entry.position, &entry.value))
+        })
+    }
+
+    ///Returns an iterator over all items within radius of the center as (handle, position, value).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::shapes::Aabb3;
+    /// use gamevecs::spatial::Octree;
+    /// let mut tree = Octree::new(Aabb3::new(Vec3::ZERO, Vec3::new(100.0, 100.0, 100.0)));
+    /// tree.insert(Vec3::new(10.0, 10.0, 10.0), 1);
+    /// tree.insert(Vec3::new(12.0, 10.0, 10.0), 2);
+    /// tree.insert(Vec3::new(50.0, 50.0, 50.0), 3);
+    ///
+    /// let mut found: Vec<i32> = tree.query_radius(Vec3::new(11.0, 10.0, 10.0), 2.0).map(|(_, _, value)| *value).collect();
+    /// found.sort();
+    ///
+    /// assert_eq!(vec![1, 2], found);
+    /// ```
+    pub fn query_radius(&self, center: B::Vector, radius: f32) -> impl Iterator<Item = (ItemId, B::Vector, &T)> + '_ {
+        let radius_squared = radius * radius;
+        Query::new(
+            self,
+            move |bounds: &B| bounds.distance_squared_to(center) <= radius_squared,
+            move |position: B::Vector| position.distance_to_squared(center) <= radius_squared)
+    }
+
+    ///Returns an iterator over all items inside of the region as (handle, position, value).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::shapes::Aabb2;
+    /// use gamevecs::spatial::Quadtree;
+    /// let mut tree = Quadtree::new(Aabb2::new(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+    /// tree.insert(Vec2::new(10.0, 10.0), 1);
+    /// tree.insert(Vec2::new(60.0, 10.0), 2);
+    ///
+    /// let area = Aabb2::new(Vec2::new(50.0, 0.0), Vec2::new(100.0, 50.0));
+    /// let found: Vec<i32> = tree.query_aabb(area).map(|(_, _, value)| *value).collect();
+    ///
+    /// assert_eq!(vec![2], found);
+    /// ```
+    pub fn query_aabb(&self, region: B) -> impl Iterator<Item = (ItemId, B::Vector, &T)> + '_ {
+        Query::new(
+            self,
+            move |bounds: &B| bounds.intersects(&region),
+            move |position: B::Vector| region.contains(position))
+    }
+
+    ///Returns an iterator over all items sorted by their distance to the point, nearest first.
+    /// Items are found in batches of 16 without allocating, each batch searches the tree again.
+    /// Taking k items costs about k / 16 searches, so only take as many items as needed.
+    pub fn nearest(&self, point: B::Vector) -> Nearest<'_, B, T> {
+        Nearest { tree: self, point, batch: [(0.0, NONE); NEAREST_BATCH], batch_len: 0, next_in_batch: 0, previous: None, exhausted: false }
+    }
+
+    ///Returns an iterator over the k items nearest to the point, nearest first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::shapes::Aabb2;
+    /// use gamevecs::spatial::Quadtree;
+    /// let mut tree = Quadtree::new(Aabb2::new(Vec2::ZERO, Vec2::new(100.0, 100.0)));
+    /// tree.insert(Vec2::new(10.0, 10.0), 1);
+    /// tree.insert(Vec2::new(60.0, 10.0), 2);
+    /// tree.insert(Vec2::new(30.0, 10.0), 3);
+    ///
+    /// let found: Vec<i32> = tree.k_nearest(Vec2::new(0.0, 10.0), 2).map(|(_, _, value)| *value).collect();
+    ///
+    /// assert_eq!(vec![1, 3], found);
+    /// ```
+    pub fn k_nearest(&self, point: B::Vector, k: usize) -> std::iter::Take<Nearest<'_, B, T>> {
+        self.nearest(point).take(k)
+    }
+
+    //----------Internals----------
+
+    fn entry(&self, id: ItemId) -> Option<&Entry<B::Vector, T>> {
+        self.items.get(id.0 as usize).and_then(|entry| entry.as_ref())
+    }
+
+    fn entry_mut(&mut self, id: u32) -> &mut Entry<B::Vector, T> {
+        self.items[id as usize].as_mut().expect("item ids in nodes are always valid")
+    }
+
+    //puts an item into the leaf containing it, splitting the leaf if it gets too full
+    fn place(&mut self, id: u32) {
+        let position = self.entry_mut(id).position;
+
+        let mut node = 0;
+        if self.nodes[0].bounds.contains(position) {
+            while self.nodes[node].first_child != NONE {
+                let index = self.nodes[node].bounds.child_index(position);
+                node = self.nodes[node].first_child as usize + index;
+            }
+        }
+
+        self.nodes[node].items.push(id);
+        self.entry_mut(id).node = node as u32;
+        self.split(node);
+    }
+
+    fn detach(&mut self, id: u32, node: u32) {
+        let items = &mut self.nodes[node as usize].items;
+        if let Some(index) = items.iter().position(|item| *item == id) {
+            items.swap_remove(index);
+        }
+    }
+
+    fn split(&mut self, node: usize) {
+        let Node { bounds, depth, first_child, .. } = self.nodes[node];
+        if first_child != NONE || depth >= MAX_DEPTH || self.nodes[node].items.len() <= NODE_CAPACITY {
+            return;
+        }
+
+        let first = match self.free_nodes.pop() {
+            Some(first) => first as usize,
+            None => {
+                self.nodes.reserve(B::CHILDREN);
+                let first = self.nodes.len();
+                for i in 0..B::CHILDREN {
+                    self.nodes.push(Node { bounds: bounds.child(i), parent: NONE, first_child: NONE, depth: 0, items: Vec::new() });
+                }
+                first
+            }
+        };
+        for i in 0..B::CHILDREN {
+            let child = &mut self.nodes[first + i];
+            child.bounds = bounds.child(i);
+            child.parent = node as u32;
+            child.first_child = NONE;
+            child.depth = depth + 1;
+            child.items.clear();
+        }
+        self.nodes[node].first_child = first as u32;
+
+        //items outside of the bounds of the root stay in the root
+        let items = std::mem::take(&mut self.nodes[node].items);
+        for id in items {
+            let position = self.entry_mut(id).position;
+            let target = if bounds.contains(position) { first + bounds.child_index(position) } else { node };
+            self.nodes[target].items.push(id);
+            self.entry_mut(id).node = target as u32;
+        }
+
+        for i in 0..B::CHILDREN {
+            self.split(first + i);
+        }
+    }
+
+    //merges the children of the node and its ancestors back while they fit into one leaf
+    fn collapse(&mut self, mut node: u32) {
+        while node != NONE {
+            let first = self.nodes[node as usize].first_child as usize;
+            let mut total = self.nodes[node as usize].items.len();
+            for child in &self.nodes[first..first + B::CHILDREN] {
+                if child.first_child != NONE {
+                    return;
+                }
+                total += child.items.len();
+            }
+            if total > NODE_CAPACITY {
+                return;
+            }
+
+            for i in 0..B::CHILDREN {
+                let items = std::mem::take(&mut self.nodes[first + i].items);
+                for id in items {
+                    self.entry_mut(id).node = node;
+                    self.nodes[node as usize].items.push(id);
+                }
+            }
+            self.nodes[node as usize].first_child = NONE;
+            self.free_nodes.push(first as u32);
+
+            node = self.nodes[node as usize].parent;
+        }
+    }
+}
+
+
+//depth first traversal that doesn't allocate
+struct Query<'a, B: Region, T, N, I> {
+    tree: &'a Orthtree<B, T>,
+    stack: [u32; STACK_SIZE],
+    stack_len: usize,
+    node: u32,
+    next_item: usize,
+    node_filter: N,
+    item_filter: I,
+}
+
+impl<'a, B: Region, T, N, I> Query<'a, B, T, N, I>
+where
+    N: Fn(&B) -> bool,
+    I: Fn(B::Vector) -> bool,
+{
+    fn new(tree: &'a Orthtree<B, T>, node_filter: N, item_filter: I) -> Query<'a, B, T, N, I> {
+        Query { tree, stack: [NONE; STACK_SIZE], stack_len: 0, node: 0, next_item: 0, node_filter, item_filter }
+    }
+}
+
+impl<'a, B: Region, T, N, I> Iterator for Query<'a, B, T, N, I>
+where
+    N: Fn(&B) -> bool,
+    I: Fn(B::Vector) -> bool,
+{
+    type Item = (ItemId, B::Vector, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tree = self.tree;
+        loop {
+            if self.node != NONE {
+                let node = &tree.nodes[self.node as usize];
+                while self.next_item < node.items.len() {
+                    let id = node.items[self.next_item];
+                    self.next_item += 1;
+                    let entry = tree.items[id as usize].as_ref().expect("item ids in nodes are always valid");
+                    if (self.item_filter)(entry.position) {
+                        return Some((ItemId(id), entry.position, &entry.value));
+                    }
+                }
+
+                if node.first_child != NONE {
+                    for child in node.first_child..node.first_child + B::CHILDREN as u32 {
+                        if (self.node_filter)(&tree.nodes[child as usize].bounds) {
+                            self.stack[self.stack_len] = child;
+                            self.stack_len += 1;
+                        }
+                    }
+                }
+                self.node = NONE;
+            }
+
+            if self.stack_len == 0 {
+                return None;
+            }
+            self.stack_len -= 1;
+            self.node = self.stack[self.stack_len];
+            self.next_item = 0;
+        }
+    }
+}
+
+
+///Iterator over the items of a tree sorted by their distance to a point.
+/// Returned by Orthtree::nearest.
+pub struct Nearest<'a, B: Region, T> {
+    tree: &'a Orthtree<B, T>,
+    point: B::Vector,
+    //the next items in order, found by the last search
+    batch: [(f32, u32); NEAREST_BATCH],
+    batch_len: usize,
+    next_in_batch: usize,
+    //distance and id of the last returned item
+    previous: Option<(f32, u32)>,
+    //set when the last search didn't fill the batch, so no items are left
+    exhausted: bool,
+}
+
+//orders items by distance first and id second, so items at the same distance are returned one by one
+fn is_before(a: (f32, u32), b: (f32, u32)) -> bool {
+    a.0 < b.0 || (a.0 == b.0 && a.1 < b.1)
+}
+
+impl<B: Region, T> Nearest<'_, B, T> {
+    //fills the batch with the nearest items after the last returned one
+    fn search(&mut self) {
+        let tree = self.tree;
+        self.batch_len = 0;
+        self.next_in_batch = 0;
+
+        let mut stack = [NONE; STACK_SIZE];
+        stack[0] = 0;
+        let mut stack_len = 1;
+        while stack_len > 0 {
+            stack_len -= 1;
+            let index = stack[stack_len];
+            let node = &tree.nodes[index as usize];
+            //the batch might have been filled with closer items since the node was pushed
+            let worst = self.worst();
+            if index != 0 && worst.is_some_and(|worst| node.bounds.distance_squared_to(self.point) > worst.0) {
+                continue;
+            }
+
+            for id in &node.items {
+                let entry = tree.items[*id as usize].as_ref().expect("item ids in nodes are always valid");
+                let key = (entry.position.distance_to_squared(self.point), *id);
+                let after_previous = self.previous.map_or(true, |previous| is_before(previous, key));
+                if after_previous && self.worst().map_or(true, |worst| is_before(key, worst)) {
+                    self.insert(key);
+                }
+            }
+
+            if node.first_child == NONE {
+                continue;
+            }
+
+            //push the nearest child last, so it gets searched first
+            let mut children = [(0.0f32, NONE); 8];
+            for (i, child) in children.iter_mut().enumerate().take(B::CHILDREN) {
+                let index = node.first_child + i as u32;
+                *child = (tree.nodes[index as usize].bounds.distance_squared_to(self.point), index);
+            }
+            children[..B::CHILDREN].sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+            let worst = self.worst();
+            for (distance, child) in &children[..B::CHILDREN] {
+                if worst.map_or(true, |worst| *distance <= worst.0) {
+                    stack[stack_len] = *child;
+                    stack_len += 1;
+                }
+            }
+        }
+        self.exhausted = self.batch_len < NEAREST_BATCH;
+    }
+
+    //the farthest item of a full batch, which new items have to beat
+    fn worst(&self) -> Option<(f32, u32)> {
+        if self.batch_len < NEAREST_BATCH {
+            return None;
+        }
+        Some(self.batch[NEAREST_BATCH - 1])
+    }
+
+    //sorts an item into the batch, dropping the farthest one if it is full
+    fn insert(&mut self, key: (f32, u32)) {
+        let mut position = self.batch_len.min(NEAREST_BATCH - 1);
+        self.batch_len = (self.batch_len + 1).min(NEAREST_BATCH);
+        while position > 0 && is_before(key, self.batch[position - 1]) {
+            self.batch[position] = self.batch[position - 1];
+            position -= 1;
+        }
+        self.batch[position] = key;
+    }
+}
+
+impl<'a, B: Region, T> Iterator for Nearest<'a, B, T> {
+    type Item = (ItemId, B::Vector, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_in_batch == self.batch_len {
+            if self.exhausted {
+                return None;
+            }
+            self.search();
+            if self.batch_len == 0 {
+                return None;
+            }
+        }
+
+        let key = self.batch[self.next_in_batch];
+        self.next_in_batch += 1;
+        self.previous = Some(key);
+        let entry = self.tree.items[key.1 as usize].as_ref().expect("item ids in nodes are always valid");
+        Some((ItemId(key.1), entry.position, &entry.value))
+    }
+}
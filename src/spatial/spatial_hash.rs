@@ -0,0 +1,364 @@
+use std::collections::HashMap;
+use std::collections::hash_map;
+
+use crate::{Vec2, Vec3, Vector};
+use crate::spatial::ItemId;
+
+
+///Vector that can be sorted into the cells of a uniform grid.
+/// Implemented by Vec2 and Vec3.
+pub trait GridVector: Vector {
+    ///Returns the coordinates of the grid cell containing this vector. Unused axes are 0.
+    fn cell(&self, cell_size: f32) -> [i32; 3];
+}
+
+impl GridVector for Vec2 {
+    fn cell(&self, cell_size: f32) -> [i32; 3] {
+        [(self.x / cell_size).floor() as i32, (self.y / cell_size).floor() as i32, 0]
+    }
+}
+
+impl GridVector for Vec3 {
+    fn cell(&self, cell_size: f32) -> [i32; 3] {
+        [(self.x / cell_size).floor() as i32, (self.y / cell_size).floor() as i32, (self.z / cell_size).floor() as i32]
+    }
+}
+
+//steps through the cells between min and max (inclusive), x first
+fn next_cell(cell: &mut [i32; 3], min: [i32; 3], max: [i32; 3]) -> bool {
+    for axis in 0..3 {
+        if cell[axis] < max[axis] {
+            cell[axis] += 1;
+            return true;
+        }
+        cell[axis] = min[axis];
+    }
+    false
+}
+
+fn add_cells(a: [i32; 3], b: [i32; 3]) -> [i32; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+
+#[derive(Debug, Clone)]
+struct Entry<V, T> {
+    position: V,
+    value: T,
+    cell: [i32; 3],
+}
+
+///Uniform grid that buckets items by the cell their position falls into.
+/// Use the SpatialHash2 and SpatialHash3 aliases.
+///
+/// Works best if the cell size is about the radius of the typical query.
+#[derive(Debug, Clone)]
+pub struct SpatialHash<V: GridVector, T> {
+    cell_size: f32,
+    cells: HashMap<[i32; 3], Vec<u32>>,
+    items: Vec<Option<Entry<V, T>>>,
+    free_items: Vec<u32>,
+    len: usize,
+}
+
+///Uniform grid over Vec2 positions.
+pub type SpatialHash2<T> = SpatialHash<Vec2, T>;
+///Uniform grid over Vec3 positions.
+pub type SpatialHash3<T> = SpatialHash<Vec3, T>;
+
+impl<V: GridVector, T> SpatialHash<V, T> {
+    ///Creates an empty grid. Panics if cell_size isn't positive.
+    pub fn new(cell_size: f32) -> SpatialHash<V, T> {
+        assert!(cell_size > 0.0, "the cell size has to be positive");
+        SpatialHash { cell_size, cells: HashMap::new(), items: Vec::new(), free_items: Vec::new(), len: 0 }
+    }
+
+    ///Returns the size of the cells of this grid.
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    ///Returns the number of items in this grid.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    ///Returns true if this grid contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    ///Removes all items from this grid.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.items.clear();
+        self.free_items.clear();
+        self.len = 0;
+    }
+
+    ///Inserts an item at the given position and returns its handle.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::spatial::SpatialHash2;
+    /// let mut grid = SpatialHash2::new(10.0);
+    ///
+    /// let id = grid.insert(Vec2::new(15.0, 25.0), "bullet");
+    ///
+    /// assert_eq!(Some(&"bullet"), grid.get(id));
+    /// ```
+    pub fn insert(&mut self, position: V, value: T) -> ItemId {
+        let cell = position.cell(self.cell_size);
+        let entry = Some(Entry { position, value, cell });
+        let id = match self.free_items.pop() {
+            Some(id) => { self.items[id as usize] = entry; id }
+            None => { self.items.push(entry); (self.items.len() - 1) as u32 }
+        };
+        self.cells.entry(cell).or_default().push(id);
+        self.len += 1;
+
+        ItemId(id)
+    }
+
+    ///Removes an item and returns its value, or None if the handle is invalid.
+    pub fn remove(&mut self, id: ItemId) -> Option<T> {
+        let entry = self.items.get_mut(id.0 as usize)?.take()?;
+        self.free_items.push(id.0);
+        self.len -= 1;
+        self.detach(id.0, entry.cell);
+
+        Some(entry.value)
+    }
+
+    ///Moves an item to a new position. Returns false if the handle is invalid.
+    pub fn move_item(&mut self, id: ItemId, position: V) -> bool {
+        let cell_size = self.cell_size;
+        let Some(Some(entry)) = self.items.get_mut(id.0 as usize) else {
+            return false;
+        };
+        let old_cell = entry.cell;
+        let new_cell = position.cell(cell_size);
+        entry.position = position;
+        entry.cell = new_cell;
+
+        if old_cell != new_cell {
+            self.detach(id.0, old_cell);
+            self.cells.entry(new_cell).or_default().push(id.0);
+        }
+
+        true
+    }
+
+    ///Returns the value of an item.
+    pub fn get(&self, id: ItemId) -> Option<&T> {
+        self.entry(id).map(|entry| &entry.value)
+    }
+
+    ///Returns the value of an item as mutable.
+    pub fn get_mut(&mut self, id: ItemId) -> Option<&mut T> {
+        match self.items.get_mut(id.0 as usize) {
+            Some(Some(entry)) => Some(&mut entry.value),
+            _ => None,
+        }
+    }
+
+    ///Returns the position of an item.
+    pub fn position(&self, id: ItemId) -> Option<V> {
+        self.entry(id).map(|entry| entry.position)
+    }
+
+    ///Returns an iterator over all items as (handle, position, value).
+    pub fn iter(&self) -> impl Iterator<Item = (ItemId, V, &T)> + '_ {
+        self.items.iter().enumerate().filter_map(|(id, entry)| {
+            entry.as_ref().map(|entry| (ItemId(id as u32), entry.position, &entry.value))
+        })
+    }
+
+    ///Returns an iterator over all items within radius of the center as (handle, position, value).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// use gamevecs::spatial::SpatialHash3;
+    /// let mut grid = SpatialHash3::new(5.0);
+    /// grid.insert(Vec3::new(1.0, 1.0, 1.0), 1);
+    /// grid.insert(Vec3::new(6.0, 1.0, 1.0), 2);
+    /// grid.insert(Vec3::new(20.0, 1.0, 1.0), 3);
+    ///
+    /// let mut found: Vec<i32> = grid.query_radius(Vec3::new(3.0, 1.0, 1.0), 4.0).map(|(_, _, value)| *value).collect();
+    /// found.sort();
+    ///
+    /// assert_eq!(vec![1, 2], found);
+    /// ```
+    pub fn query_radius(&self, center: V, radius: f32) -> Neighbors<'_, V, T> {
+        let offset = V::ONE * radius;
+        let min = (center - offset).cell(self.cell_size);
+        let max = (center + offset).cell(self.cell_size);
+        Neighbors {
+            hash: self,
+            center,
+            radius_squared: radius * radius,
+            min,
+            max,
+            cell: min,
+            bucket: self.bucket(min),
+            index: 0,
+        }
+    }
+
+    ///Returns an iterator over every pair of items that are at most radius apart.
+    /// Each pair is returned once, in no particular order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::spatial::SpatialHash2;
+    /// let mut grid = SpatialHash2::new(1.0);
+    /// let a = grid.insert(Vec2::new(0.0, 0.0), ());
+    /// let b = grid.insert(Vec2::new(0.5, 0.0), ());
+    /// grid.insert(Vec2::new(5.0, 0.0), ());
+    ///
+    /// let pairs: Vec<_> = grid.pairs(1.0).collect();
+    ///
+    /// assert_eq!(1, pairs.len());
+    /// assert!(pairs[0] == (a, b) || pairs[0] == (b, a));
+    /// ```
+    pub fn pairs(&self, radius: f32) -> Pairs<'_, V, T> {
+        let range = (radius / self.cell_size).ceil() as i32;
+        let range_z = if V::DIMENSIONS == 3 { range } else { 0 };
+        Pairs {
+            hash: self,
+            cells: self.cells.iter(),
+            radius_squared: radius * radius,
+            min_offset: [-range, -range, -range_z],
+            max_offset: [range, range, range_z],
+            cell: [0; 3],
+            bucket: &[],
+            //no cell yet, so the offsets start exhausted
+            offset: [range, range, range_z],
+            other: &[],
+            same_cell: false,
+            i: 0,
+            j: 0,
+        }
+    }
+
+    //----------Internals----------
+
+    fn entry(&self, id: ItemId) -> Option<&Entry<V, T>> {
+        self.items.get(id.0 as usize).and_then(|entry| entry.as_ref())
+    }
+
+    fn position_of(&self, id: u32) -> V {
+        self.items[id as usize].as_ref().expect("item ids in cells are always valid").position
+    }
+
+    fn bucket(&self, cell: [i32; 3]) -> &[u32] {
+        self.cells.get(&cell).map_or(&[], |bucket| bucket.as_slice())
+    }
+
+    fn detach(&mut self, id: u32, cell: [i32; 3]) {
+        if let Some(bucket) = self.cells.get_mut(&cell) {
+            if let Some(index) = bucket.iter().position(|item| *item == id) {
+                bucket.swap_remove(index);
+            }
+            //keep empty cells from piling up
+            if bucket.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+}
+
+
+///Iterator over the items near a point. Returned by SpatialHash::query_radius.
+pub struct Neighbors<'a, V: GridVector, T> {
+    hash: &'a SpatialHash<V, T>,
+    center: V,
+    radius_squared: f32,
+    min: [i32; 3],
+    max: [i32; 3],
+    cell: [i32; 3],
+    bucket: &'a [u32],
+    index: usize,
+}
+
+impl<'a, V: GridVector, T> Iterator for Neighbors<'a, V, T> {
+    type Item = (ItemId, V, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while self.index < self.bucket.len() {
+                let id = self.bucket[self.index];
+                self.index += 1;
+                let entry = self.hash.items[id as usize].as_ref().expect("item ids in cells are always valid");
+                if entry.position.distance_to_squared(self.center) <= self.radius_squared {
+                    return Some((ItemId(id), entry.position, &entry.value));
+                }
+            }
+
+            if !next_cell(&mut self.cell, self.min, self.max) {
+                return None;
+            }
+            self.bucket = self.hash.bucket(self.cell);
+            self.index = 0;
+        }
+    }
+}
+
+
+///Iterator over the pairs of close items. Returned by SpatialHash::pairs.
+pub struct Pairs<'a, V: GridVector, T> {
+    hash: &'a SpatialHash<V, T>,
+    cells: hash_map::Iter<'a, [i32; 3], Vec<u32>>,
+    radius_squared: f32,
+    min_offset: [i32; 3],
+    max_offset: [i32; 3],
+    cell: [i32; 3],
+    bucket: &'a [u32],
+    offset: [i32; 3],
+    other: &'a [u32],
+    same_cell: bool,
+    i: usize,
+    j: usize,
+}
+
+impl<V: GridVector, T> Iterator for Pairs<'_, V, T> {
+    type Item = (ItemId, ItemId);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while self.i < self.bucket.len() {
+                let a = self.bucket[self.i];
+                while self.j < self.other.len() {
+                    let b = self.other[self.j];
+                    self.j += 1;
+                    if self.hash.position_of(a).distance_to_squared(self.hash.position_of(b)) <= self.radius_squared {
+                        return Some((ItemId(a), ItemId(b)));
+                    }
+                }
+                self.i += 1;
+                self.j = if self.same_cell { self.i + 1 } else { 0 };
+            }
+
+            //only the offsets after (0, 0, 0) are visited, so every pair of cells is checked once
+            if next_cell(&mut self.offset, self.min_offset, self.max_offset) {
+                self.other = self.hash.bucket(add_cells(self.cell, self.offset));
+                self.same_cell = false;
+                self.i = 0;
+                self.j = 0;
+                continue;
+            }
+
+            let (cell, bucket) = self.cells.next()?;
+            self.cell = *cell;
+            self.bucket = bucket;
+            self.offset = [0; 3];
+            self.other = bucket;
+            self.same_cell = true;
+            self.i = 0;
+            self.j = 1;
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod agent;
+mod blend;
+
+pub use self::agent::{Agent, Wander};
+pub use self::blend::{blend_weighted, blend_priority};
@@ -0,0 +1,249 @@
+use crate::Vector;
+
+
+///Moving state of an AI agent, the input of all steering behaviors.
+/// Works with Vec2 and Vec3.
+///
+/// Every behavior returns a steering force no longer than max_force.
+/// Combine several of them with blend_weighted or blend_priority and apply the result with step.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::steering::Agent;
+/// let mut guard = Agent::new(Vec2::new(0.0, 0.0), Vec2::new(0.0, 0.0), 4.0, 10.0);
+/// let post = Vec2::new(20.0, 0.0);
+///
+/// for _ in 0..1200 {
+///     let force = guard.arrive(post, 5.0);
+///     guard.step(force, 1.0 / 60.0);
+/// }
+///
+/// assert!(guard.position.distance_to(post) < 0.1);
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Agent<V: Vector> {
+    pub position: V,
+    pub velocity: V,
+    pub max_speed: f32,
+    ///Maximum magnitude of the steering force, limits how quickly the agent turns and brakes.
+    pub max_force: f32,
+}
+
+impl<V: Vector> Agent<V> {
+    pub fn new(position: V, velocity: V, max_speed: f32, max_force: f32) -> Agent<V> {
+        Agent { position, velocity, max_speed, max_force }
+    }
+
+    ///Applies a steering force for dt, limited to max_force, and moves the agent with its new velocity limited to max_speed.
+    pub fn step(&mut self, force: V, dt: f32) {
+        self.velocity = (self.velocity + force.clamp_length(self.max_force) * dt).clamp_length(self.max_speed);
+        self.position += self.velocity * dt;
+    }
+
+    ///Returns the direction the agent is moving in with a magnitude of 1, or zero if it stands still.
+    pub fn heading(&self) -> V {
+        normalized_or_zero(self.velocity)
+    }
+
+    //----------Behaviors----------
+
+    ///Steers towards the target at full speed.
+    pub fn seek(&self, target: V) -> V {
+        self.steer_towards(normalized_or_zero(target - self.position) * self.max_speed)
+    }
+
+    ///Steers away from the target at full speed.
+    pub fn flee(&self, target: V) -> V {
+        self.steer_towards(normalized_or_zero(self.position - target) * self.max_speed)
+    }
+
+    ///Steers towards the target, slowing down inside of the slowing radius to stop on it.
+    pub fn arrive(&self, target: V, slowing_radius: f32) -> V {
+        let offset = target - self.position;
+        let distance = offset.magnitude();
+        if distance == 0.0 {
+            return self.steer_towards(V::ZERO);
+        }
+
+        let speed = if distance < slowing_radius { self.max_speed * distance / slowing_radius } else { self.max_speed };
+        self.steer_towards(offset * (speed / distance))
+    }
+
+    ///Steers towards where the target will be when this agent reaches it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::steering::Agent;
+    /// let hunter = Agent::new(Vec2::new(0.0, 0.0), Vec2::new(0.0, 0.0), 5.0, 5.0);
+    /// let prey = Agent::new(Vec2::new(10.0, 0.0), Vec2::new(0.0, 5.0), 5.0, 5.0);
+    ///
+    /// //leads the prey instead of chasing its current position
+    /// assert!(hunter.pursue(&prey).y > 0.0);
+    /// ```
+    pub fn pursue(&self, target: &Agent<V>) -> V {
+        self.seek(self.predict(target))
+    }
+
+    ///Steers away from where the target will be when it reaches this agent.
+    pub fn evade(&self, target: &Agent<V>) -> V {
+        self.flee(self.predict(target))
+    }
+
+    ///Steers towards a target that randomly drifts around a circle in front of the agent, for natural looking idle movement.
+    /// random_offset moves the target and should be a new random vector with a magnitude of up to 1 every call,
+    /// for example from random::in_unit_circle or random::in_unit_sphere.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::steering::{Agent, Wander};
+    /// let mut critter = Agent::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), 2.0, 4.0);
+    /// let mut wander = Wander::new(Vec2::new(1.0, 0.0), 3.0, 1.0, 0.3);
+    ///
+    /// let force = critter.wander(&mut wander, Vec2::new(0.0, 1.0));
+    /// critter.step(force, 0.1);
+    ///
+    /// assert!(critter.velocity.y > 0.0);
+    /// ```
+    pub fn wander(&self, wander: &mut Wander<V>, random_offset: V) -> V {
+        wander.target = normalized_or(wander.target + random_offset * wander.jitter, wander.target) * wander.radius;
+        let center = self.position + normalized_or(self.velocity, wander.target) * wander.distance;
+        self.seek(center + wander.target)
+    }
+
+    ///Steers around the obstacle, given as (center, radius), that is nearest in front of the agent.
+    /// Only obstacles within look_ahead along the current heading and within radius of its path are considered.
+    /// Returns zero if there is nothing to avoid.
+    pub fn avoid_obstacles(&self, obstacles: &[(V, f32)], radius: f32, look_ahead: f32) -> V {
+        let heading = self.heading();
+        if heading == V::ZERO {
+            return V::ZERO;
+        }
+
+        let mut nearest: Option<(f32, V, V)> = None;
+        for (center, obstacle_radius) in obstacles {
+            let along = (*center - self.position).dot(heading);
+            if along < 0.0 || along > look_ahead + obstacle_radius {
+                continue;
+            }
+            let closest = self.position + heading * along.min(look_ahead);
+            let clearance = radius + obstacle_radius;
+            if closest.distance_to_squared(*center) < clearance * clearance && nearest.map_or(true, |(distance, _, _)| along < distance) {
+                nearest = Some((along, closest, *center));
+            }
+        }
+
+        match nearest {
+            None => V::ZERO,
+            Some((_, closest, center)) => {
+                //head-on collisions have no side to turn to, so brake instead
+                let away = normalized_or(closest - center, -heading);
+                away * self.max_force
+            }
+        }
+    }
+
+    ///Steers along the path, given by its points in order, and arrives at its last point.
+    /// Aims look_ahead further along the path than the point nearest to the agent,
+    /// or straight back at the path when the agent strayed further than path_radius from it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// use gamevecs::steering::Agent;
+    /// let mut patrol = Agent::new(Vec2::new(0.0, 1.0), Vec2::new(0.0, 0.0), 3.0, 10.0);
+    /// let path = [Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), Vec2::new(10.0, 10.0)];
+    ///
+    /// for _ in 0..1200 {
+    ///     let force = patrol.follow_path(&path, 0.5, 1.0);
+    ///     patrol.step(force, 1.0 / 60.0);
+    /// }
+    ///
+    /// assert!(patrol.position.distance_to(Vec2::new(10.0, 10.0)) < 0.1);
+    /// ```
+    pub fn follow_path(&self, path: &[V], path_radius: f32, look_ahead: f32) -> V {
+        match path.len() {
+            0 => return V::ZERO,
+            1 => return self.arrive(path[0], look_ahead.max(path_radius)),
+            _ => {}
+        }
+
+        //nearest point on the path
+        let mut nearest = (f32::INFINITY, 0, 0.0);
+        for (i, segment) in path.windows(2).enumerate() {
+            let direction = segment[1] - segment[0];
+            let length_squared = direction.magnitude_squared();
+            let t = if length_squared > 0.0 { ((self.position - segment[0]).dot(direction) / length_squared).clamp(0.0, 1.0) } else { 0.0 };
+            let distance = self.position.distance_to_squared(segment[0] + direction * t);
+            if distance < nearest.0 {
+                nearest = (distance, i, t);
+            }
+        }
+
+        let (distance_squared, mut segment, t) = nearest;
+        if distance_squared > path_radius * path_radius {
+            return self.seek(path[segment].lerp(path[segment + 1], t));
+        }
+
+        //walk look_ahead further along the path
+        let mut remaining = look_ahead + path[segment].distance_to(path[segment + 1]) * t;
+        loop {
+            let length = path[segment].distance_to(path[segment + 1]);
+            if remaining <= length {
+                let target = path[segment].lerp(path[segment + 1], if length > 0.0 { remaining / length } else { 0.0 });
+                return self.seek(target);
+            }
+            if segment + 2 == path.len() {
+                return self.arrive(path[path.len() - 1], look_ahead.max(path_radius));
+            }
+            remaining -= length;
+            segment += 1;
+        }
+    }
+
+    //----------Internals----------
+
+    fn steer_towards(&self, desired_velocity: V) -> V {
+        (desired_velocity - self.velocity).clamp_length(self.max_force)
+    }
+
+    //position of the target after the time this agent needs to reach its current position
+    fn predict(&self, target: &Agent<V>) -> V {
+        let time = if self.max_speed > 0.0 { self.position.distance_to(target.position) / self.max_speed } else { 0.0 };
+        target.position + target.velocity * time
+    }
+}
+
+
+///State of the wander behavior of an agent.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Wander<V: Vector> {
+    ///Current target relative to the center of the wander circle.
+    pub target: V,
+    ///Distance of the wander circle in front of the agent.
+    pub distance: f32,
+    ///Radius of the wander circle. Larger radii allow sharper turns.
+    pub radius: f32,
+    ///How far the target can move around the circle per call.
+    pub jitter: f32,
+}
+
+impl<V: Vector> Wander<V> {
+    ///Creates the state, starting at the point of the circle in the given direction.
+    pub fn new(direction: V, distance: f32, radius: f32, jitter: f32) -> Wander<V> {
+        Wander { target: normalized_or_zero(direction) * radius, distance, radius, jitter }
+    }
+}
+
+fn normalized_or<V: Vector>(vector: V, fallback: V) -> V {
+    if vector.magnitude_squared() == 0.0 {
+        return fallback;
+    }
+    vector.normalized()
+}
+
+fn normalized_or_zero<V: Vector>(vector: V) -> V {
+    normalized_or(vector, V::ZERO)
+}
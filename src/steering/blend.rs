@@ -0,0 +1,50 @@
+use crate::Vector;
+
+
+///Combines steering forces, given as (force, weight), by their weighted sum limited to max_force.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::steering::blend_weighted;
+/// let seek = Vec2::new(4.0, 0.0);
+/// let separate = Vec2::new(0.0, 4.0);
+///
+/// assert_eq!(Vec2::new(2.0, 2.0), blend_weighted(&[(seek, 0.5), (separate, 0.5)], 10.0));
+/// ```
+pub fn blend_weighted<V: Vector>(forces: &[(V, f32)], max_force: f32) -> V {
+    let mut sum = V::ZERO;
+    for (force, weight) in forces {
+        sum += *force * *weight;
+    }
+
+    sum.clamp_length(max_force)
+}
+
+///Combines steering forces by priority, most important first. Every force only gets the part of max_force
+/// that the more important ones left over, so urgent behaviors like obstacle avoidance can't be drowned out.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::Vec2;
+/// use gamevecs::steering::blend_priority;
+/// let avoid = Vec2::new(0.0, 8.0);
+/// let seek = Vec2::new(8.0, 0.0);
+///
+/// assert_eq!(Vec2::new(2.0, 8.0), blend_priority(&[avoid, seek], 10.0));
+/// ```
+pub fn blend_priority<V: Vector>(forces: &[V], max_force: f32) -> V {
+    let mut sum = V::ZERO;
+    let mut remaining = max_force;
+    for force in forces {
+        if remaining <= 0.0 {
+            break;
+        }
+
+        let force = force.clamp_length(remaining);
+        remaining -= force.magnitude();
+        sum += force;
+    }
+
+    sum
+}
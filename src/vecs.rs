@@ -1,2 +1,6 @@
 pub mod vec2;
-pub mod vec3;
\ No newline at end of file
+pub mod vec3;
+pub mod vector;
+pub mod angle;
+pub mod dir;
+mod math;
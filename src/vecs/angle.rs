@@ -0,0 +1,264 @@
+use std::f32::consts::{PI, TAU};
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+use crate::vecs::math;
+
+
+///Angle in radians.
+/// Functions taking an impl Into<Radians> accept Radians, Degrees and plain f32 radians.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::{Vec2, Radians, Degrees};
+/// let vec = Vec2::new(1.0, 0.0);
+///
+/// //all of these rotate by a quarter turn
+/// let a = vec.rotate(Degrees(90.0));
+/// let b = vec.rotate(Radians(std::f32::consts::FRAC_PI_2));
+/// let c = vec.rotate(std::f32::consts::FRAC_PI_2);
+///
+/// assert_eq!(a, b);
+/// assert_eq!(b, c);
+/// ```
+#[derive(PartialEq, PartialOrd, Debug, Clone, Copy, Default)]
+pub struct Radians(pub f32);
+
+///Angle in degrees. Converts into Radians.
+#[derive(PartialEq, PartialOrd, Debug, Clone, Copy, Default)]
+pub struct Degrees(pub f32);
+
+impl Radians {
+    ///Short for Radians(0.0)
+    pub const ZERO: Radians = Radians(0.0);
+    ///Half a turn
+    pub const PI: Radians = Radians(PI);
+    ///A full turn
+    pub const TAU: Radians = Radians(TAU);
+
+
+    //----------Other functionality----------
+
+    pub fn to_degrees(&self) -> Degrees {
+        Degrees(self.0.to_degrees())
+    }
+
+    ///Returns this angle wrapped to the range (-PI, PI].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Radians;
+    /// use std::f32::consts::PI;
+    ///
+    /// assert!((Radians(1.5 * PI).wrapped().0 + 0.5 * PI).abs() < 1e-6);
+    /// assert_eq!(Radians(PI), Radians(-PI).wrapped());
+    /// ```
+    pub fn wrapped(&self) -> Radians {
+        let angle = self.0.rem_euclid(TAU);
+        Radians(if angle > PI { angle - TAU } else { angle })
+    }
+
+    ///Returns the shortest turn from this angle to another, in the range (-PI, PI].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::{Radians, Degrees};
+    /// //from 170 degrees to -170 degrees is a turn of 20 degrees, not -340
+    /// let difference = Radians::from(Degrees(170.0)).difference_to(Degrees(-170.0));
+    ///
+    /// assert!((difference.to_degrees().0 - 20.0).abs() < 1e-4);
+    /// ```
+    pub fn difference_to(&self, other: impl Into<Radians>) -> Radians {
+        (other.into() - *self).wrapped()
+    }
+
+    pub fn sin(&self) -> f32 {
+        math::sin(self.0)
+    }
+
+    pub fn cos(&self) -> f32 {
+        math::cos(self.0)
+    }
+
+    pub fn tan(&self) -> f32 {
+        math::tan(self.0)
+    }
+
+    ///Returns the sine and cosine of this angle.
+    pub fn sin_cos(&self) -> (f32, f32) {
+        (math::sin(self.0), math::cos(self.0))
+    }
+}
+
+impl Degrees {
+    ///Short for Degrees(0.0)
+    pub const ZERO: Degrees = Degrees(0.0);
+
+
+    //----------Other functionality----------
+
+    pub fn to_radians(&self) -> Radians {
+        Radians(self.0.to_radians())
+    }
+
+    ///Returns this angle wrapped to the range (-180, 180].
+    pub fn wrapped(&self) -> Degrees {
+        let angle = self.0.rem_euclid(360.0);
+        Degrees(if angle > 180.0 { angle - 360.0 } else { angle })
+    }
+
+    ///Returns the shortest turn from this angle to another, in the range (-180, 180].
+    pub fn difference_to(&self, other: Degrees) -> Degrees {
+        (other - *self).wrapped()
+    }
+}
+
+//----------Conversions----------
+impl From<Degrees> for Radians {
+    fn from(degrees: Degrees) -> Radians {
+        degrees.to_radians()
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(radians: Radians) -> Degrees {
+        radians.to_degrees()
+    }
+}
+
+//plain numbers have always been radians in this crate
+impl From<f32> for Radians {
+    fn from(radians: f32) -> Radians {
+        Radians(radians)
+    }
+}
+
+//----------Operator overloading----------
+impl Add for Radians {
+    type Output = Radians;
+    fn add(self, rhs: Self) -> Self::Output {
+        Radians(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign<Radians> for Radians {
+    fn add_assign(&mut self, rhs: Radians) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Radians {
+    type Output = Radians;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Radians(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign<Radians> for Radians {
+    fn sub_assign(&mut self, rhs: Radians) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Mul<f32> for Radians {
+    type Output = Radians;
+    fn mul(self, rhs: f32) -> Self::Output {
+        Radians(self.0 * rhs)
+    }
+}
+
+impl MulAssign<f32> for Radians {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.0 *= rhs;
+    }
+}
+
+impl Div<f32> for Radians {
+    type Output = Radians;
+    fn div(self, rhs: f32) -> Self::Output {
+        Radians(self.0 / rhs)
+    }
+}
+
+impl DivAssign<f32> for Radians {
+    fn div_assign(&mut self, rhs: f32) {
+        self.0 /= rhs;
+    }
+}
+
+impl Neg for Radians {
+    type Output = Radians;
+    fn neg(self) -> Self::Output {
+        Radians(-self.0)
+    }
+}
+impl Add for Degrees {
+    type Output = Degrees;
+    fn add(self, rhs: Self) -> Self::Output {
+        Degrees(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign<Degrees> for Degrees {
+    fn add_assign(&mut self, rhs: Degrees) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Degrees {
+    type Output = Degrees;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Degrees(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign<Degrees> for Degrees {
+    fn sub_assign(&mut self, rhs: Degrees) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Mul<f32> for Degrees {
+    type Output = Degrees;
+    fn mul(self, rhs: f32) -> Self::Output {
+        Degrees(self.0 * rhs)
+    }
+}
+
+impl MulAssign<f32> for Degrees {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.0 *= rhs;
+    }
+}
+
+impl Div<f32> for Degrees {
+    type Output = Degrees;
+    fn div(self, rhs: f32) -> Self::Output {
+        Degrees(self.0 / rhs)
+    }
+}
+
+impl DivAssign<f32> for Degrees {
+    fn div_assign(&mut self, rhs: f32) {
+        self.0 /= rhs;
+    }
+}
+
+impl Neg for Degrees {
+    type Output = Degrees;
+    fn neg(self) -> Self::Output {
+        Degrees(-self.0)
+    }
+}
+
+impl fmt::Display for Radians {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} rad", self.0)
+    }
+}
+
+impl fmt::Display for Degrees {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}°", self.0)
+    }
+}
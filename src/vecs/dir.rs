@@ -0,0 +1,204 @@
+use std::fmt;
+use std::ops::{Deref, Mul, Neg};
+
+use crate::{Vec2, Vec3, Radians};
+
+
+///2D direction with a magnitude of 1.
+/// Can only be created by normalizing a vector that has a direction, so functions taking a Dir2 can rely on it being normalized.
+/// Derefs to Vec2 for all read only vector functions.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::{Vec2, Dir2};
+///
+/// let dir = Dir2::new(Vec2::new(3.0, 4.0)).unwrap();
+///
+/// assert_eq!(Vec2::new(0.6, 0.8), *dir);
+/// assert_eq!(1.0, dir.magnitude());
+/// assert_eq!(None, Dir2::new(Vec2::ZERO));
+/// ```
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Dir2(Vec2);
+
+///3D direction with a magnitude of 1.
+/// Can only be created by normalizing a vector that has a direction, so functions taking a Dir3 can rely on it being normalized.
+/// Derefs to Vec3 for all read only vector functions.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Dir3(Vec3);
+
+impl Dir2 {
+    ///Direction of Vec2::DOWN
+    pub const DOWN: Dir2 = Dir2(Vec2::DOWN);
+    ///Direction of Vec2::UP
+    pub const UP: Dir2 = Dir2(Vec2::UP);
+    ///Direction of Vec2::RIGHT
+    pub const RIGHT: Dir2 = Dir2(Vec2::RIGHT);
+    ///Direction of Vec2::LEFT
+    pub const LEFT: Dir2 = Dir2(Vec2::LEFT);
+
+
+    ///Returns the direction of a vector, or None if its magnitude is 0 or it isn't finite.
+    pub fn new(vector: Vec2) -> Option<Dir2> {
+        //scaling by the largest component first keeps tiny and huge vectors away from subnormals and overflow
+        let scaled = vector / vector.x.abs().max(vector.y.abs());
+        let magnitude = scaled.magnitude();
+        //a zero, infinite or NaN vector gives a NaN magnitude here
+        if !magnitude.is_finite() {
+            return None;
+        }
+        Some(Dir2(scaled / magnitude))
+    }
+
+    ///Returns the direction at an angle counterclockwise from RIGHT.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::{Vec2, Dir2, Degrees};
+    ///
+    /// let dir = Dir2::from_angle(Degrees(90.0));
+    ///
+    /// //using the approximate equals function due to floating point inaccuracity
+    /// assert!(dir.equals(Vec2::UP, 1e-6));
+    /// ```
+    pub fn from_angle(angle: impl Into<Radians>) -> Dir2 {
+        let (sin, cos) = angle.into().sin_cos();
+        Dir2(Vec2::new(cos, sin))
+    }
+
+
+    //----------Other functionality----------
+
+    ///Returns this direction as a Vec2.
+    pub fn vector(&self) -> Vec2 {
+        self.0
+    }
+
+    ///Returns this direction rotated counterclockwise.
+    pub fn rotate(&self, angle: impl Into<Radians>) -> Dir2 {
+        //rotating keeps the magnitude, so only rounding errors have to be removed
+        Dir2(self.0.rotate(angle).normalized())
+    }
+}
+
+impl Dir3 {
+    ///Direction of Vec3::DOWN
+    pub const DOWN: Dir3 = Dir3(Vec3::DOWN);
+    ///Direction of Vec3::UP
+    pub const UP: Dir3 = Dir3(Vec3::UP);
+    ///Direction of Vec3::RIGHT
+    pub const RIGHT: Dir3 = Dir3(Vec3::RIGHT);
+    ///Direction of Vec3::LEFT
+    pub const LEFT: Dir3 = Dir3(Vec3::LEFT);
+    ///Direction of Vec3::FORWARD
+    pub const FORWARD: Dir3 = Dir3(Vec3::FORWARD);
+    ///Direction of Vec3::BACK
+    pub const BACK: Dir3 = Dir3(Vec3::BACK);
+
+
+    ///Returns the direction of a vector, or None if its magnitude is 0 or it isn't finite.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::{Vec3, Dir3};
+    ///
+    /// assert_eq!(Some(Dir3::UP), Dir3::new(Vec3::new(0.0, 5.0, 0.0)));
+    /// assert_eq!(None, Dir3::new(Vec3::new(f32::NAN, 1.0, 0.0)));
+    /// ```
+    pub fn new(vector: Vec3) -> Option<Dir3> {
+        //scaling by the largest component first keeps tiny and huge vectors away from subnormals and overflow
+        let scaled = vector / vector.x.abs().max(vector.y.abs()).max(vector.z.abs());
+        let magnitude = scaled.magnitude();
+        //a zero, infinite or NaN vector gives a NaN magnitude here
+        if !magnitude.is_finite() {
+            return None;
+        }
+        Some(Dir3(scaled / magnitude))
+    }
+
+    ///Returns the direction of spherical angles as used by Vec3::from_spherical.
+    pub fn from_spherical(polar: impl Into<Radians>, azimuth: impl Into<Radians>) -> Dir3 {
+        Dir3(Vec3::from_spherical(1.0, polar, azimuth))
+    }
+
+
+    //----------Other functionality----------
+
+    ///Returns this direction as a Vec3.
+    pub fn vector(&self) -> Vec3 {
+        self.0
+    }
+
+    ///Returns the direction perpendicular to this and another direction, or None if they are parallel.
+    pub fn cross(&self, other: Dir3) -> Option<Dir3> {
+        Dir3::new(self.0.cross(other.0))
+    }
+}
+
+//----------Conversions----------
+impl Deref for Dir2 {
+    type Target = Vec2;
+    fn deref(&self) -> &Vec2 {
+        &self.0
+    }
+}
+
+impl Deref for Dir3 {
+    type Target = Vec3;
+    fn deref(&self) -> &Vec3 {
+        &self.0
+    }
+}
+
+impl From<Dir2> for Vec2 {
+    fn from(dir: Dir2) -> Vec2 {
+        dir.0
+    }
+}
+
+impl From<Dir3> for Vec3 {
+    fn from(dir: Dir3) -> Vec3 {
+        dir.0
+    }
+}
+
+//----------Operator overloading----------
+impl Mul<f32> for Dir2 {
+    type Output = Vec2;
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.0 * rhs
+    }
+}
+
+impl Mul<f32> for Dir3 {
+    type Output = Vec3;
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.0 * rhs
+    }
+}
+
+impl Neg for Dir2 {
+    type Output = Dir2;
+    fn neg(self) -> Self::Output {
+        Dir2(-self.0)
+    }
+}
+
+impl Neg for Dir3 {
+    type Output = Dir3;
+    fn neg(self) -> Self::Output {
+        Dir3(-self.0)
+    }
+}
+
+impl fmt::Display for Dir2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for Dir3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
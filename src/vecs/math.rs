@@ -0,0 +1,57 @@
+//the float functions used by the vector types, software implementations with the libm feature so results are the same on every platform
+
+#[cfg(not(feature = "libm"))]
+mod backend {
+    pub fn sqrt(x: f32) -> f32 {
+        x.sqrt()
+    }
+
+    pub fn sin(x: f32) -> f32 {
+        x.sin()
+    }
+
+    pub fn cos(x: f32) -> f32 {
+        x.cos()
+    }
+
+    pub fn tan(x: f32) -> f32 {
+        x.tan()
+    }
+
+    pub fn acos(x: f32) -> f32 {
+        x.acos()
+    }
+
+    pub fn atan2(y: f32, x: f32) -> f32 {
+        y.atan2(x)
+    }
+}
+
+#[cfg(feature = "libm")]
+mod backend {
+    pub fn sqrt(x: f32) -> f32 {
+        libm::sqrtf(x)
+    }
+
+    pub fn sin(x: f32) -> f32 {
+        libm::sinf(x)
+    }
+
+    pub fn cos(x: f32) -> f32 {
+        libm::cosf(x)
+    }
+
+    pub fn tan(x: f32) -> f32 {
+        libm::tanf(x)
+    }
+
+    pub fn acos(x: f32) -> f32 {
+        libm::acosf(x)
+    }
+
+    pub fn atan2(y: f32, x: f32) -> f32 {
+        libm::atan2f(y, x)
+    }
+}
+
+pub(crate) use self::backend::{sqrt, sin, cos, tan, acos, atan2};
@@ -1,6 +1,9 @@
 use std::fmt;
 use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
 
+use crate::{Radians, Dir2};
+use crate::vecs::math;
+
 
 ///2D f32 Vector 
 #[derive(PartialEq, Debug, Clone, Copy)]
@@ -123,7 +126,7 @@ impl Vec2 {
     /// assert_eq!(5.0, distance);
     /// ```
     pub fn distance_to(&self, other: Vec2) -> f32 {
-        self.distance_to_squared(other).sqrt()
+        math::sqrt(self.distance_to_squared(other))
     } 
 
     ///Returns the squared distance from this vector to another.
@@ -162,7 +165,39 @@ impl Vec2 {
         self.x * other.x + self.y * other.y
     }
 
-    ///Returns the angle between this vector and another one.
+    ///Returns the component-wise minimum of this vector and another one.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// let vec1 = Vec2::new(1.0, 5.0);
+    /// let vec2 = Vec2::new(3.0, 2.0);
+    /// //get the minimum
+    /// let min = vec1.min(vec2);
+    /// 
+    /// assert_eq!(Vec2::new(1.0, 2.0), min);
+    /// ```
+    pub fn min(&self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    ///Returns the component-wise maximum of this vector and another one.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// let vec1 = Vec2::new(1.0, 5.0);
+    /// let vec2 = Vec2::new(3.0, 2.0);
+    /// //get the maximum
+    /// let max = vec1.max(vec2);
+    /// 
+    /// assert_eq!(Vec2::new(3.0, 5.0), max);
+    /// ```
+    pub fn max(&self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    ///Returns the unsigned angle between this vector and another one, between 0 and PI.
     /// 
     /// # Examples
     /// ```rust
@@ -185,9 +220,29 @@ impl Vec2 {
         
         let cos_theta = dot / magnitude_product;
         
-        cos_theta.min(1.0).max(-1.0).acos()
+        math::acos(cos_theta.clamp(-1.0, 1.0))
 
     }
+
+    ///Returns the signed angle that rotates this vector onto the direction of another, counterclockwise if positive.
+    /// Use angle_between for the unsigned angle.
+    /// The angle is in the range (-PI, PI] and 0 if one of the vectors is zero.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// let vec1 = Vec2::new(1.0, 0.0);
+    /// let vec2 = Vec2::new(0.0, -3.0);
+    /// //get the angle
+    /// let angle = vec1.angle_to(vec2);
+    /// 
+    /// //using an approximate comparison due to floating point inaccuracity
+    /// assert!((angle.to_degrees().0 + 90.0).abs() < 1e-4);
+    /// ```
+    pub fn angle_to(&self, other: Vec2) -> Radians {
+        let cross = self.x * other.y - self.y * other.x;
+        Radians(math::atan2(cross, self.dot(other))).wrapped()
+    }
     
     ///Returns the linear interpolation by t between this and another vector.
     /// 
@@ -208,7 +263,29 @@ impl Vec2 {
         }
     }
 
-    ///Adds the length in direction of the angle in radians to the vector and returns the result as a new Vec2.
+    ///Returns this vector shortened to the given maximum magnitude, or unchanged if it is already shorter.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// let vec1 = Vec2::new(3.0, 4.0);
+    /// 
+    /// //limit the length to 2.5
+    /// let clamped = vec1.clamp_length(2.5);
+    /// 
+    /// assert_eq!(Vec2::new(1.5, 2.0), clamped);
+    /// assert_eq!(Vec2::new(1.0, 1.0), Vec2::new(1.0, 1.0).clamp_length(2.5));
+    /// ```
+    pub fn clamp_length(&self, max: f32) -> Vec2 {
+        let magnitude_squared = self.magnitude_squared();
+        if magnitude_squared <= max * max {
+            return *self;
+        }
+
+        *self * (max / math::sqrt(magnitude_squared))
+    }
+
+    ///Adds the length in direction of the angle to the vector and returns the result as a new Vec2.
     /// 
     /// # Examples
     /// ```rust
@@ -221,10 +298,11 @@ impl Vec2 {
     /// //using the approximate equals function due to floating point inaccuracity
     /// assert!(new_vec.equals(Vec2::new(6.0, 8.0), 1e-4));
     /// ```
-    pub fn add_length_by_angle(&self, angle: f32, length: f32) -> Vec2 {
+    pub fn add_length_by_angle(&self, angle: impl Into<Radians>, length: f32) -> Vec2 {
+        let angle = angle.into().0;
         Vec2 {
-            x: self.x + angle.cos() * length,
-            y: self.y + angle.sin() * length,
+            x: self.x + math::cos(angle) * length,
+            y: self.y + math::sin(angle) * length,
         }
     }
 
@@ -248,6 +326,39 @@ impl Vec2 {
         Vec2 { x: onto_normalized.x * scalar, y: onto_normalized.y * scalar }
     }
 
+    ///Projects a vector onto a direction and returns the result as a new Vec2.
+    /// Like project, but the direction is already normalized.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::{Vec2, Dir2};
+    /// let vec1 = Vec2::new(3.0, 4.0);
+    /// 
+    /// //project
+    /// let projection = vec1.project_onto(Dir2::RIGHT);
+    /// 
+    /// assert_eq!(Vec2::new(3.0, 0.0), projection);
+    /// ```
+    pub fn project_onto(&self, direction: Dir2) -> Vec2 {
+        *direction * self.dot(*direction)
+    }
+
+    ///Reflects a vector off a surface with the given normal and returns the result as a new Vec2.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::{Vec2, Dir2};
+    /// let velocity = Vec2::new(2.0, -1.0);
+    /// 
+    /// //bounce off the floor
+    /// let bounced = velocity.reflect(Dir2::UP);
+    /// 
+    /// assert_eq!(Vec2::new(2.0, 1.0), bounced);
+    /// ```
+    pub fn reflect(&self, normal: Dir2) -> Vec2 {
+        *self - *normal * (2.0 * self.dot(*normal))
+    }
+
     ///Rotates a vector counterclockwise and returns the result as a new Vec2.
     /// 
     /// # Examples
@@ -261,14 +372,51 @@ impl Vec2 {
     /// //using the approximate equals function due to floating point inaccuracity
     /// assert!(rotated_vec.equals(Vec2::new(0.0, 1.0), 1e-4));
     /// ```
-    pub fn rotate(&self, angle: f32) -> Vec2 {
+    pub fn rotate(&self, angle: impl Into<Radians>) -> Vec2 {
+        let angle = angle.into().0;
         let mut new_vec = Vec2::new(0.0, 0.0);
-        new_vec.x = self.x * angle.cos() - self.y * angle.sin();
-        new_vec.y = self.x * angle.sin() + self.y * angle.cos();
+        let (sin, cos) = (math::sin(angle), math::cos(angle));
+        new_vec.x = self.x * cos - self.y * sin;
+        new_vec.y = self.x * sin + self.y * cos;
 
         new_vec
     }
 
+    ///Returns the polar coordinates (radius, angle) of this vector.
+    /// The angle is between -PI and PI, counterclockwise from RIGHT, like the angles of rotate and add_length_by_angle.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::{Vec2, Degrees};
+    /// let vec1 = Vec2::new(0.0, 2.0);
+    /// 
+    /// //convert to polar coordinates
+    /// let (radius, angle) = vec1.to_polar();
+    /// 
+    /// assert_eq!(2.0, radius);
+    /// assert_eq!(Degrees(90.0), angle.to_degrees());
+    /// ```
+    pub fn to_polar(&self) -> (f32, Radians) {
+        (self.magnitude(), Radians(math::atan2(self.y, self.x)))
+    }
+
+    ///Creates a vector from polar coordinates with the angle counterclockwise from RIGHT.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec2;
+    /// 
+    /// //convert from polar coordinates
+    /// let vec1 = Vec2::from_polar(2.0, 180.0f32.to_radians());
+    /// 
+    /// //using the approximate equals function due to floating point inaccuracity
+    /// assert!(vec1.equals(Vec2::new(-2.0, 0.0), 1e-4));
+    /// ```
+    pub fn from_polar(radius: f32, angle: impl Into<Radians>) -> Vec2 {
+        let angle = angle.into().0;
+        Vec2::new(math::cos(angle) * radius, math::sin(angle) * radius)
+    }
+
 }
 
 //----------Operator overloading----------
@@ -328,6 +476,34 @@ impl DivAssign<Vec2> for Vec2 {
     }
 }
 
+impl Mul<f32> for Vec2 {
+    type Output = Vec2;
+    fn mul(self, rhs: f32) -> Self::Output {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl MulAssign<f32> for Vec2 {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl Div<f32> for Vec2 {
+    type Output = Vec2;
+    fn div(self, rhs: f32) -> Self::Output {
+        Vec2::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl DivAssign<f32> for Vec2 {
+    fn div_assign(&mut self, rhs: f32) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
 impl Neg for Vec2 {
     type Output = Vec2;
     fn neg(self) -> Self::Output {
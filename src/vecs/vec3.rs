@@ -1,6 +1,9 @@
 use std::fmt;
 use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
 
+use crate::{Radians, Dir3};
+use crate::vecs::math;
+
 
 ///3D f32 Vector 
 #[derive(PartialEq, Debug, Clone, Copy)]
@@ -131,7 +134,7 @@ impl Vec3 {
     /// assert_eq!(15.0, distance);
     /// ```
     pub fn distance_to(&self, other: Vec3) -> f32 {
-        self.distance_to_squared(other).sqrt()
+        math::sqrt(self.distance_to_squared(other))
     } 
 
     ///Returns the squared distance from this vector to another.
@@ -190,7 +193,39 @@ impl Vec3 {
             self.x * other.y - self.y * other.x)
     }
 
-    ///Returns the angle between this vector and another one.
+    ///Returns the component-wise minimum of this vector and another one.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// let vec1 = Vec3::new(1.0, 5.0, 3.0);
+    /// let vec2 = Vec3::new(3.0, 2.0, 3.0);
+    /// //get the minimum
+    /// let min = vec1.min(vec2);
+    /// 
+    /// assert_eq!(Vec3::new(1.0, 2.0, 3.0), min);
+    /// ```
+    pub fn min(&self, other: Vec3) -> Vec3 {
+        Vec3::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+    }
+
+    ///Returns the component-wise maximum of this vector and another one.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// let vec1 = Vec3::new(1.0, 5.0, 3.0);
+    /// let vec2 = Vec3::new(3.0, 2.0, 3.0);
+    /// //get the maximum
+    /// let max = vec1.max(vec2);
+    /// 
+    /// assert_eq!(Vec3::new(3.0, 5.0, 3.0), max);
+    /// ```
+    pub fn max(&self, other: Vec3) -> Vec3 {
+        Vec3::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+    }
+
+    ///Returns the unsigned angle between this vector and another one, between 0 and PI.
     /// 
     /// # Examples
     /// ```rust
@@ -213,9 +248,31 @@ impl Vec3 {
         
         let cos_theta = dot / magnitude_product;
         
-        cos_theta.min(1.0).max(-1.0).acos()
+        math::acos(cos_theta.clamp(-1.0, 1.0))
 
     }
+
+    ///Returns the signed angle that rotates this vector onto the direction of another around an axis, like Vec2::angle_to.
+    /// The angle is positive if the cross product of the two vectors points along the axis, in the range (-PI, PI] and 0 if one of the vectors is zero.
+    /// Use angle_between for the unsigned angle.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::{Vec3, Dir3, Degrees};
+    /// //get the angle around the up axis
+    /// let angle = Vec3::FORWARD.angle_to(Vec3::RIGHT, Dir3::UP);
+    /// 
+    /// //using an approximate comparison due to floating point inaccuracity
+    /// assert!((angle.to_degrees().0 - 90.0).abs() < 1e-4);
+    /// assert!((Vec3::RIGHT.angle_to(Vec3::FORWARD, Dir3::UP).to_degrees().0 + 90.0).abs() < 1e-4);
+    /// ```
+    pub fn angle_to(&self, other: Vec3, axis: Dir3) -> Radians {
+        let angle = self.angle_between(other);
+        if self.cross(other).dot(*axis) < 0.0 {
+            return Radians(-angle);
+        }
+        Radians(angle)
+    }
     
     ///Returns the linear interpolation by t between this and another vector. T should be between 0 and 1.
     /// 
@@ -237,6 +294,28 @@ impl Vec3 {
         }
     }
 
+    ///Returns this vector shortened to the given maximum magnitude, or unchanged if it is already shorter.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// let vec1 = Vec3::new(0.0, 3.0, 4.0);
+    /// 
+    /// //limit the length to 2.5
+    /// let clamped = vec1.clamp_length(2.5);
+    /// 
+    /// assert_eq!(Vec3::new(0.0, 1.5, 2.0), clamped);
+    /// assert_eq!(Vec3::new(1.0, 1.0, 1.0), Vec3::new(1.0, 1.0, 1.0).clamp_length(2.5));
+    /// ```
+    pub fn clamp_length(&self, max: f32) -> Vec3 {
+        let magnitude_squared = self.magnitude_squared();
+        if magnitude_squared <= max * max {
+            return *self;
+        }
+
+        *self * (max / math::sqrt(magnitude_squared))
+    }
+
 
     ///Projects a vector onto another one and returns the result as a new Vec3.
     /// 
@@ -257,6 +336,131 @@ impl Vec3 {
         Vec3::new(onto_normalized.x * scalar, onto_normalized.y * scalar, onto_normalized.z * scalar)
     }
 
+    ///Projects a vector onto a direction and returns the result as a new Vec3.
+    /// Like project, but the direction is already normalized.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::{Vec3, Dir3};
+    /// let vec1 = Vec3::new(3.0, 4.0, 5.0);
+    /// 
+    /// //project
+    /// let projection = vec1.project_onto(Dir3::RIGHT);
+    /// 
+    /// assert_eq!(Vec3::new(3.0, 0.0, 0.0), projection);
+    /// ```
+    pub fn project_onto(&self, direction: Dir3) -> Vec3 {
+        *direction * self.dot(*direction)
+    }
+
+    ///Reflects a vector off a surface with the given normal and returns the result as a new Vec3.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::{Vec3, Dir3};
+    /// let velocity = Vec3::new(2.0, -1.0, 3.0);
+    /// 
+    /// //bounce off the floor
+    /// let bounced = velocity.reflect(Dir3::UP);
+    /// 
+    /// assert_eq!(Vec3::new(2.0, 1.0, 3.0), bounced);
+    /// ```
+    pub fn reflect(&self, normal: Dir3) -> Vec3 {
+        *self - *normal * (2.0 * self.dot(*normal))
+    }
+
+    ///Returns the spherical coordinates (radius, polar, azimuth) of this vector, with the angles in radians.
+    /// The polar angle goes from 0 at UP to PI at DOWN.
+    /// The azimuth is between -PI and PI and turns counterclockwise around UP (seen from above), from FORWARD towards RIGHT.
+    /// At the poles the azimuth is 0.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::{Vec3, Degrees};
+    /// let vec1 = Vec3::new(2.0, 0.0, 0.0);
+    /// 
+    /// //convert to spherical coordinates
+    /// let (radius, polar, azimuth) = vec1.to_spherical();
+    /// 
+    /// assert_eq!(2.0, radius);
+    /// assert_eq!(Degrees(90.0), polar.to_degrees());
+    /// assert_eq!(Degrees(90.0), azimuth.to_degrees());
+    /// ```
+    pub fn to_spherical(&self) -> (f32, Radians, Radians) {
+        let horizontal = math::sqrt(self.x * self.x + self.z * self.z);
+        (self.magnitude(), Radians(math::atan2(horizontal, self.y)), self.azimuth(horizontal))
+    }
+
+    ///Creates a vector from spherical coordinates (radius, polar, azimuth) as returned by to_spherical.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// 
+    /// //convert from spherical coordinates
+    /// let vec1 = Vec3::from_spherical(3.0, 90.0f32.to_radians(), 0.0);
+    /// let vec2 = Vec3::from_spherical(3.0, 0.0, 1.0);
+    /// 
+    /// //using the approximate equals function due to floating point inaccuracity
+    /// assert!(vec1.equals(Vec3::FORWARD * 3.0, 1e-4));
+    /// assert!(vec2.equals(Vec3::UP * 3.0, 1e-4));
+    /// ```
+    pub fn from_spherical(radius: f32, polar: impl Into<Radians>, azimuth: impl Into<Radians>) -> Vec3 {
+        let (polar, azimuth) = (polar.into().0, azimuth.into().0);
+        let horizontal = math::sin(polar) * radius;
+        Vec3::new(math::sin(azimuth) * horizontal, math::cos(polar) * radius, math::cos(azimuth) * horizontal)
+    }
+
+    ///Returns the cylindrical coordinates (radius, azimuth, height) of this vector around the UP axis.
+    /// The azimuth is measured like in to_spherical and the height is y.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::{Vec3, Degrees};
+    /// let vec1 = Vec3::new(0.0, 5.0, -2.0);
+    /// 
+    /// //convert to cylindrical coordinates
+    /// let (radius, azimuth, height) = vec1.to_cylindrical();
+    /// 
+    /// assert_eq!((2.0, Degrees(180.0), 5.0), (radius, azimuth.to_degrees(), height));
+    /// ```
+    pub fn to_cylindrical(&self) -> (f32, Radians, f32) {
+        let horizontal = math::sqrt(self.x * self.x + self.z * self.z);
+        (horizontal, self.azimuth(horizontal), self.y)
+    }
+
+    //on the vertical axis atan2 would return -PI for negative zeros, so the azimuth is 0 there
+    fn azimuth(&self, horizontal: f32) -> Radians {
+        if horizontal == 0.0 {
+            return Radians::ZERO;
+        }
+        Radians(math::atan2(self.x, self.z))
+    }
+
+    ///Creates a vector from cylindrical coordinates (radius, azimuth, height) as returned by to_cylindrical.
+    /// 
+    /// # Examples
+    /// ```rust
+    /// use gamevecs::Vec3;
+    /// 
+    /// //convert from cylindrical coordinates
+    /// let vec1 = Vec3::from_cylindrical(2.0, 90.0f32.to_radians(), 1.0);
+    /// 
+    /// //using the approximate equals function due to floating point inaccuracity
+    /// assert!(vec1.equals(Vec3::new(2.0, 1.0, 0.0), 1e-4));
+    /// ```
+    pub fn from_cylindrical(radius: f32, azimuth: impl Into<Radians>, height: f32) -> Vec3 {
+        let azimuth = azimuth.into().0;
+        Vec3::new(math::sin(azimuth) * radius, height, math::cos(azimuth) * radius)
+    }
+
+    //returns two vectors with a magnitude of 1 that are perpendicular to this normalized vector and each other
+    pub(crate) fn perpendiculars(&self) -> (Vec3, Vec3) {
+        let helper = if self.x.abs() < 0.9 { Vec3::new(1.0, 0.0, 0.0) } else { Vec3::new(0.0, 1.0, 0.0) };
+        let tangent = self.cross(helper).normalized();
+        (tangent, self.cross(tangent))
+    }
+
     
 }
 
@@ -322,6 +526,36 @@ impl DivAssign<Vec3> for Vec3 {
     }
 }
 
+impl Mul<f32> for Vec3 {
+    type Output = Vec3;
+    fn mul(self, rhs: f32) -> Self::Output {
+        Vec3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl MulAssign<f32> for Vec3 {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+impl Div<f32> for Vec3 {
+    type Output = Vec3;
+    fn div(self, rhs: f32) -> Self::Output {
+        Vec3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl DivAssign<f32> for Vec3 {
+    fn div_assign(&mut self, rhs: f32) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
 impl Neg for Vec3 {
     type Output = Vec3;
     fn neg(self) -> Self::Output {
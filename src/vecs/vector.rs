@@ -0,0 +1,158 @@
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+
+use crate::{Vec2, Vec3};
+
+
+///Functionality shared by Vec2 and Vec3.
+/// Used by the algorithms of this crate that work the same way in 2D and 3D.
+///
+/// # Examples
+/// ```rust
+/// use gamevecs::{Vec2, Vec3, Vector};
+///
+/// fn midpoint<V: Vector>(a: V, b: V) -> V {
+///     a.lerp(b, 0.5)
+/// }
+///
+/// assert_eq!(Vec2::new(1.0, 1.0), midpoint(Vec2::ZERO, Vec2::new(2.0, 2.0)));
+/// assert_eq!(Vec3::new(1.0, 1.0, 1.0), midpoint(Vec3::ZERO, Vec3::new(2.0, 2.0, 2.0)));
+/// ```
+pub trait Vector:
+    Copy + PartialEq + Debug +
+    Add<Output = Self> + AddAssign +
+    Sub<Output = Self> + SubAssign +
+    Mul<f32, Output = Self> + MulAssign<f32> +
+    Div<f32, Output = Self> + DivAssign<f32> +
+    Neg<Output = Self>
+{
+    ///Short for a vector with all components set to 0.0
+    const ZERO: Self;
+    ///Short for a vector with all components set to 1.0
+    const ONE: Self;
+    ///Number of components of this vector type.
+    const DIMENSIONS: usize;
+
+    ///Returns the component of the given axis (0 for x, 1 for y, 2 for z). Panics if the axis doesn't exist.
+    fn component(&self, axis: usize) -> f32;
+
+    ///Returns the length (distance to the origin) of this vector.
+    fn magnitude(&self) -> f32;
+    ///Returns the squared length of this vector.
+    fn magnitude_squared(&self) -> f32;
+    ///Returns true if this vector approximately equals another one.
+    fn equals(&self, other: Self, epsilon: f32) -> bool;
+    ///Returns this vector with a magnitude of 1.
+    fn normalized(&self) -> Self;
+    ///Returns the distance from this vector to another.
+    fn distance_to(&self, other: Self) -> f32;
+    ///Returns the squared distance from this vector to another.
+    fn distance_to_squared(&self, other: Self) -> f32;
+    ///Returns the dot product of this vector and another one.
+    fn dot(&self, other: Self) -> f32;
+    ///Returns the component-wise minimum of this vector and another one.
+    fn min(&self, other: Self) -> Self;
+    ///Returns the component-wise maximum of this vector and another one.
+    fn max(&self, other: Self) -> Self;
+    ///Returns the linear interpolation by t between this and another vector.
+    fn lerp(&self, other: Self, t: f32) -> Self;
+    ///Returns this vector shortened to the given maximum magnitude, or unchanged if it is already shorter.
+    fn clamp_length(&self, max: f32) -> Self;
+}
+
+impl Vector for Vec2 {
+    const ZERO: Vec2 = Vec2::ZERO;
+    const ONE: Vec2 = Vec2::ONE;
+    const DIMENSIONS: usize = 2;
+
+    fn component(&self, axis: usize) -> f32 {
+        match axis {
+            0 => self.x,
+            1 => self.y,
+            _ => panic!("Vec2 has no axis {}", axis),
+        }
+    }
+
+    fn magnitude(&self) -> f32 {
+        Vec2::magnitude(self)
+    }
+    fn magnitude_squared(&self) -> f32 {
+        Vec2::magnitude_squared(self)
+    }
+    fn equals(&self, other: Vec2, epsilon: f32) -> bool {
+        Vec2::equals(self, other, epsilon)
+    }
+    fn normalized(&self) -> Vec2 {
+        Vec2::normalized(self)
+    }
+    fn distance_to(&self, other: Vec2) -> f32 {
+        Vec2::distance_to(self, other)
+    }
+    fn distance_to_squared(&self, other: Vec2) -> f32 {
+        Vec2::distance_to_squared(self, other)
+    }
+    fn dot(&self, other: Vec2) -> f32 {
+        Vec2::dot(self, other)
+    }
+    fn min(&self, other: Vec2) -> Vec2 {
+        Vec2::min(self, other)
+    }
+    fn max(&self, other: Vec2) -> Vec2 {
+        Vec2::max(self, other)
+    }
+    fn lerp(&self, other: Vec2, t: f32) -> Vec2 {
+        Vec2::lerp(self, other, t)
+    }
+    fn clamp_length(&self, max: f32) -> Vec2 {
+        Vec2::clamp_length(self, max)
+    }
+}
+
+impl Vector for Vec3 {
+    const ZERO: Vec3 = Vec3::ZERO;
+    const ONE: Vec3 = Vec3::ONE;
+    const DIMENSIONS: usize = 3;
+
+    fn component(&self, axis: usize) -> f32 {
+        match axis {
+            0 => self.x,
+            1 => self.y,
+            2 => self.z,
+            _ => panic!("Vec3 has no axis {}", axis),
+        }
+    }
+
+    fn magnitude(&self) -> f32 {
+        Vec3::magnitude(self)
+    }
+    fn magnitude_squared(&self) -> f32 {
+        Vec3::magnitude_squared(self)
+    }
+    fn equals(&self, other: Vec3, epsilon: f32) -> bool {
+        Vec3::equals(self, other, epsilon)
+    }
+    fn normalized(&self) -> Vec3 {
+        Vec3::normalized(self)
+    }
+    fn distance_to(&self, other: Vec3) -> f32 {
+        Vec3::distance_to(self, other)
+    }
+    fn distance_to_squared(&self, other: Vec3) -> f32 {
+        Vec3::distance_to_squared(self, other)
+    }
+    fn dot(&self, other: Vec3) -> f32 {
+        Vec3::dot(self, other)
+    }
+    fn min(&self, other: Vec3) -> Vec3 {
+        Vec3::min(self, other)
+    }
+    fn max(&self, other: Vec3) -> Vec3 {
+        Vec3::max(self, other)
+    }
+    fn lerp(&self, other: Vec3, t: f32) -> Vec3 {
+        Vec3::lerp(self, other, t)
+    }
+    fn clamp_length(&self, max: f32) -> Vec3 {
+        Vec3::clamp_length(self, max)
+    }
+}